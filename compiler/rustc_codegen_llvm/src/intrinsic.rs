@@ -10,7 +10,7 @@
 use rustc_codegen_ssa::base::{compare_simd_types, wants_msvc_seh};
 use rustc_codegen_ssa::common::span_invalid_monomorphization_error;
 use rustc_codegen_ssa::common::{IntPredicate, TypeKind};
-use rustc_codegen_ssa::mir::operand::OperandRef;
+use rustc_codegen_ssa::mir::operand::{OperandRef, OperandValue};
 use rustc_codegen_ssa::mir::place::PlaceRef;
 use rustc_codegen_ssa::traits::*;
 use rustc_hir as hir;
@@ -51,6 +51,8 @@ fn get_simple_intrinsic<'ll>(
         sym::log2f64 => "llvm.log2.f64",
         sym::fmaf32 => "llvm.fma.f32",
         sym::fmaf64 => "llvm.fma.f64",
+        sym::fmuladdf32 => "llvm.fmuladd.f32",
+        sym::fmuladdf64 => "llvm.fmuladd.f64",
         sym::fabsf32 => "llvm.fabs.f32",
         sym::fabsf64 => "llvm.fabs.f64",
         sym::minnumf32 => "llvm.minnum.f32",
@@ -71,6 +73,8 @@ fn get_simple_intrinsic<'ll>(
         sym::nearbyintf64 => "llvm.nearbyint.f64",
         sym::roundf32 => "llvm.round.f32",
         sym::roundf64 => "llvm.round.f64",
+        sym::round_ties_even_f32 => "llvm.roundeven.f32",
+        sym::round_ties_even_f64 => "llvm.roundeven.f64",
         sym::ptr_mask => "llvm.ptrmask",
         _ => return None,
     };
@@ -118,6 +122,27 @@ fn codegen_intrinsic_call(
             }
             sym::unlikely => self
                 .call_intrinsic("llvm.expect.i1", &[args[0].immediate(), self.const_bool(false)]),
+            sym::is_val_statically_known => {
+                let intrinsic_type = args[0].layout.immediate_llvm_type(self.cx);
+                let llvm_name = match self.type_kind(intrinsic_type) {
+                    TypeKind::Integer => match self.int_width(intrinsic_type) {
+                        width @ (1 | 8 | 16 | 32 | 64 | 128) => {
+                            Some(format!("llvm.is.constant.i{}", width))
+                        }
+                        _ => None,
+                    },
+                    TypeKind::Float => Some("llvm.is.constant.f32".to_owned()),
+                    TypeKind::Double => Some("llvm.is.constant.f64".to_owned()),
+                    _ => None,
+                };
+                match (llvm_name, &args[0].val) {
+                    (Some(llvm_name), OperandValue::Immediate(val)) => {
+                        self.call_intrinsic(&llvm_name, &[*val])
+                    }
+                    // Anything that is not a single scalar is conservatively never known.
+                    _ => self.const_bool(false),
+                }
+            }
             kw::Try => {
                 try_intrinsic(
                     self,
@@ -297,6 +322,42 @@ fn codegen_intrinsic_call(
                 }
             }
 
+            sym::carrying_mul_add => {
+                let ty = arg_tys[0];
+                match int_type_width_signed(ty, self) {
+                    Some((width, false)) => {
+                        // Widen to twice the bit width, where `a * b + c + d` cannot overflow,
+                        // and split the result back into its low and high halves.
+                        let wide_ty = self.type_ix(width * 2);
+                        let narrow_ty = self.type_ix(width);
+                        let wide_args: Vec<_> =
+                            args.iter().map(|arg| self.zext(arg.immediate(), wide_ty)).collect();
+                        let product = self.mul(wide_args[0], wide_args[1]);
+                        let sum = self.add(product, wide_args[2]);
+                        let wide = self.add(sum, wide_args[3]);
+                        let lo = self.trunc(wide, narrow_ty);
+                        let shift = self.const_uint(wide_ty, width);
+                        let hi = self.lshr(wide, shift);
+                        let hi = self.trunc(hi, narrow_ty);
+                        OperandValue::Immediate(lo).store(self, result.project_field(self, 0));
+                        OperandValue::Immediate(hi).store(self, result.project_field(self, 1));
+                        return;
+                    }
+                    _ => {
+                        span_invalid_monomorphization_error(
+                            tcx.sess,
+                            span,
+                            &format!(
+                                "invalid monomorphization of `{}` intrinsic: \
+                                      expected basic unsigned integer type, found `{}`",
+                                name, ty
+                            ),
+                        );
+                        return;
+                    }
+                }
+            }
+
             sym::raw_eq => {
                 use abi::Abi::*;
                 let tp_ty = substs.type_at(0);
@@ -1548,6 +1609,112 @@ fn non_ptr(t: Ty<'_>) -> Ty<'_> {
         return Ok(v);
     }
 
+    if name == sym::simd_masked_load || name == sym::simd_masked_store {
+        // simd_masked_load(mask: <N x i{M}>, pointer: *const T, values: <N x T>) -> <N x T>
+        // simd_masked_store(mask: <N x i{M}>, pointer: *mut T, values: <N x T>) -> ()
+        // * N: number of elements in the input vectors
+        // * T: type of the element to load or store
+        // * M: any integer width is supported, will be truncated to i1
+        // Lane `i` is loaded from / stored to `pointer.add(i)`; lanes that are masked off
+        // are not accessed.
+
+        // The mask and the values must be simd vector types of the same length
+        require_simd!(in_ty, "first");
+        require_simd!(arg_tys[2], "third");
+        let (values_len, values_elem) = arg_tys[2].simd_size_and_type(bx.tcx());
+        require!(
+            in_len == values_len,
+            "expected {} argument with length {} (same as input type `{}`), \
+             found `{}` with length {}",
+            "third",
+            in_len,
+            in_ty,
+            arg_tys[2],
+            values_len
+        );
+        if name == sym::simd_masked_load {
+            require!(
+                ret_ty == arg_tys[2],
+                "expected return type `{}`, found `{}`",
+                arg_tys[2],
+                ret_ty
+            );
+        }
+
+        // The second argument must be a pointer to the element type of the values
+        let (expected_mutbl, mutbl_str) = if name == sym::simd_masked_load {
+            (hir::Mutability::Not, "const")
+        } else {
+            (hir::Mutability::Mut, "mut")
+        };
+        match arg_tys[1].kind() {
+            ty::RawPtr(p) if p.ty == values_elem && p.mutbl == expected_mutbl => (),
+            _ => {
+                require!(
+                    false,
+                    "expected second argument `{}` to be `*{} {}` \
+                     (pointer to the element type of the third argument `{}`)",
+                    arg_tys[1],
+                    mutbl_str,
+                    values_elem,
+                    arg_tys[2]
+                );
+            }
+        }
+
+        // The element type of the mask must be a signed integer type of any width:
+        match in_elem.kind() {
+            ty::Int(_) => (),
+            _ => {
+                require!(
+                    false,
+                    "expected element type `{}` of first argument `{}` \
+                     to be a signed integer type",
+                    in_elem,
+                    in_ty
+                );
+            }
+        }
+
+        // Alignment of T, must be a constant integer value:
+        let alignment_ty = bx.type_i32();
+        let alignment = bx.const_i32(bx.align_of(values_elem).bytes() as i32);
+
+        // Truncate the mask vector to a vector of i1s:
+        let (mask, mask_ty) = {
+            let i1 = bx.type_i1();
+            let i1xn = bx.type_vector(i1, in_len);
+            (bx.trunc(args[0].immediate(), i1xn), i1xn)
+        };
+
+        // Type of the vector of elements, and of a pointer to it:
+        let llvm_elem_vec_ty = llvm_vector_ty(bx, values_elem, in_len, 0);
+        let llvm_elem_vec_str = llvm_vector_str(values_elem, in_len, 0, bx);
+        let llvm_pointer_ty = bx.type_ptr_to(llvm_elem_vec_ty);
+        let ptr = bx.pointercast(args[1].immediate(), llvm_pointer_ty);
+
+        let v = if name == sym::simd_masked_load {
+            let llvm_intrinsic =
+                format!("llvm.masked.load.{}.p0{}", llvm_elem_vec_str, llvm_elem_vec_str);
+            let fn_ty = bx.type_func(
+                &[llvm_pointer_ty, alignment_ty, mask_ty, llvm_elem_vec_ty],
+                llvm_elem_vec_ty,
+            );
+            let f = bx.declare_cfn(&llvm_intrinsic, llvm::UnnamedAddr::No, fn_ty);
+            bx.call(fn_ty, f, &[ptr, alignment, mask, args[2].immediate()], None)
+        } else {
+            let llvm_intrinsic =
+                format!("llvm.masked.store.{}.p0{}", llvm_elem_vec_str, llvm_elem_vec_str);
+            let fn_ty = bx.type_func(
+                &[llvm_elem_vec_ty, llvm_pointer_ty, alignment_ty, mask_ty],
+                bx.type_void(),
+            );
+            let f = bx.declare_cfn(&llvm_intrinsic, llvm::UnnamedAddr::No, fn_ty);
+            bx.call(fn_ty, f, &[args[2].immediate(), ptr, alignment, mask], None)
+        };
+        return Ok(v);
+    }
+
     macro_rules! arith_red {
         ($name:ident : $integer_reduce:ident, $float_reduce:ident, $ordered:expr, $op:ident,
          $identity:expr) => {
@@ -1996,6 +2163,47 @@ macro_rules! arith_unary {
         return Ok(v);
     }
 
+    if name == sym::simd_ctlz || name == sym::simd_cttz || name == sym::simd_ctpop {
+        let ptr_bits = bx.tcx().data_layout.pointer_size.bits() as _;
+        let (elem_width, elem_ty) = match *in_elem.kind() {
+            ty::Int(i) => (i.bit_width().unwrap_or(ptr_bits), bx.cx.type_int_from_ty(i)),
+            ty::Uint(i) => (i.bit_width().unwrap_or(ptr_bits), bx.cx.type_uint_from_ty(i)),
+            _ => {
+                return_error!(
+                    "expected element type `{}` of vector type `{}` \
+                     to be a signed or unsigned integer type",
+                    arg_tys[0].simd_size_and_type(bx.tcx()).1,
+                    arg_tys[0]
+                );
+            }
+        };
+        let llvm_intrinsic = &format!(
+            "llvm.{}.v{}i{}",
+            match name {
+                sym::simd_ctlz => "ctlz",
+                sym::simd_cttz => "cttz",
+                _ => "ctpop",
+            },
+            in_len,
+            elem_width
+        );
+        let vec_ty = bx.cx.type_vector(elem_ty, in_len as u64);
+        let arg = args[0].immediate();
+
+        let v = if name == sym::simd_ctpop {
+            let fn_ty = bx.type_func(&[vec_ty], vec_ty);
+            let f = bx.declare_cfn(llvm_intrinsic, llvm::UnnamedAddr::No, fn_ty);
+            bx.call(fn_ty, f, &[arg], None)
+        } else {
+            // The second argument says whether a zero input is poison; it must be `false` so
+            // that zero lanes count all of their bits, like the scalar methods.
+            let fn_ty = bx.type_func(&[vec_ty, bx.type_i1()], vec_ty);
+            let f = bx.declare_cfn(llvm_intrinsic, llvm::UnnamedAddr::No, fn_ty);
+            bx.call(fn_ty, f, &[arg, bx.const_bool(false)], None)
+        };
+        return Ok(v);
+    }
+
     span_bug!(span, "unknown SIMD intrinsic");
 }
 
@@ -118,7 +118,8 @@ pub fn compare_simd_types<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
             let cmp = bx.fcmp(cmp, lhs, rhs);
             return bx.sext(cmp, ret_ty);
         }
-        ty::Uint(_) => false,
+        // Pointers compare by address, like the scalar comparison operators.
+        ty::Uint(_) | ty::RawPtr(_) => false,
         ty::Int(_) => true,
         _ => bug!("compare_simd_types: invalid SIMD type"),
     };
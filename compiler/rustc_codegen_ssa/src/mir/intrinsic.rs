@@ -1,6 +1,7 @@
 use super::operand::{OperandRef, OperandValue};
 use super::place::PlaceRef;
 use super::FunctionCx;
+use crate::base;
 use crate::common::{span_invalid_monomorphization_error, IntPredicate};
 use crate::glue;
 use crate::meth;
@@ -82,6 +83,7 @@ pub fn codegen_intrinsic_call(
                 return;
             }
 
+            sym::ub_checks => bx.const_bool(bx.tcx().sess.opts.debug_assertions),
             sym::va_start => bx.va_start(args[0].immediate()),
             sym::va_end => bx.va_end(args[0].immediate()),
             sym::size_of_val => {
@@ -116,6 +118,48 @@ pub fn codegen_intrinsic_call(
                 };
                 value
             }
+            sym::select_unpredictable => {
+                // The "unpredictable" part is only a hint, so this is codegened as a plain
+                // select. Values that are passed by reference are selected by address.
+                let cond = args[0].immediate();
+                match (args[1].val, args[2].val) {
+                    (OperandValue::Immediate(t), OperandValue::Immediate(f)) => {
+                        bx.select(cond, t, f)
+                    }
+                    (OperandValue::Pair(t0, t1), OperandValue::Pair(f0, f1)) => {
+                        let a = bx.select(cond, t0, f0);
+                        let b = bx.select(cond, t1, f1);
+                        OperandValue::Pair(a, b).store(bx, result);
+                        return;
+                    }
+                    (OperandValue::Ref(t, None, t_align), OperandValue::Ref(f, None, f_align)) => {
+                        let ptr = bx.select(cond, t, f);
+                        OperandValue::Ref(ptr, None, t_align.min(f_align)).store(bx, result);
+                        return;
+                    }
+                    _ => span_bug!(span, "mismatched operands for `select_unpredictable`"),
+                }
+            }
+            sym::ptr_metadata => {
+                if let OperandValue::Pair(_, meta) = args[0].val {
+                    meta
+                } else {
+                    // Thin pointers have `()` as their metadata, so there is nothing to return.
+                    return;
+                }
+            }
+            sym::aggregate_raw_ptr => {
+                let data = args[0].immediate();
+                if args[1].layout.is_zst() {
+                    // Thin pointers are just their data pointer.
+                    bx.pointercast(data, llret_ty)
+                } else {
+                    let data_ty = bx.scalar_pair_element_backend_type(result.layout, 0, true);
+                    let data = bx.pointercast(data, data_ty);
+                    OperandValue::Pair(data, args[1].immediate()).store(bx, result);
+                    return;
+                }
+            }
             sym::pref_align_of
             | sym::needs_drop
             | sym::type_id
@@ -200,6 +244,36 @@ pub fn codegen_intrinsic_call(
                 );
                 return;
             }
+            sym::read_via_copy => {
+                let src = args[0].deref(bx.cx());
+                bx.load_operand(src).val.store(bx, result);
+                return;
+            }
+            sym::write_via_move => {
+                let dst = args[0].deref(bx.cx());
+                args[1].val.store(bx, dst);
+                return;
+            }
+            sym::typed_swap_nonoverlapping => {
+                let a = args[0].deref(bx.cx());
+                let b = args[1].deref(bx.cx());
+                if bx.cx().is_backend_immediate(a.layout)
+                    || bx.cx().is_backend_scalar_pair(a.layout)
+                {
+                    // Both values fit in registers, so load them before storing either.
+                    let a_val = bx.load_operand(a).val;
+                    let b_val = bx.load_operand(b).val;
+                    b_val.store(bx, a);
+                    a_val.store(bx, b);
+                } else {
+                    let tmp = PlaceRef::alloca(bx, a.layout);
+                    let flags = MemFlags::empty();
+                    base::memcpy_ty(bx, tmp.llval, tmp.align, a.llval, a.align, a.layout, flags);
+                    base::memcpy_ty(bx, a.llval, a.align, b.llval, b.align, a.layout, flags);
+                    base::memcpy_ty(bx, b.llval, b.align, tmp.llval, tmp.align, a.layout, flags);
+                }
+                return;
+            }
             sym::volatile_store => {
                 let dst = args[0].deref(bx.cx());
                 args[1].val.volatile_store(bx, dst);
@@ -5,12 +5,14 @@
 use rustc_hir as hir;
 use rustc_middle::mir::AssertKind;
 use rustc_middle::ty::{layout::LayoutError, query::TyCtxtAt, ConstInt};
+use rustc_session::config::ErrorOutputType;
 use rustc_span::{Span, Symbol};
+use serde::Serialize;
 
 use super::InterpCx;
 use crate::interpret::{
-    struct_error, ErrorHandled, FrameInfo, InterpError, InterpErrorInfo, Machine, MachineStopType,
-    UnsupportedOpInfo,
+    struct_error, AllocId, ErrorHandled, FrameInfo, InterpError, InterpErrorInfo, Machine,
+    MachineStopType, Pointer, UndefinedBehaviorInfo, UnsupportedOpInfo,
 };
 
 /// The CTFE machine has some custom error kinds.
@@ -196,6 +198,12 @@ fn struct_generic(
                 }
                 flush_last_line(last_frame, times);
             }
+            // Tools reading JSON diagnostics get the details of UB errors in structured form.
+            if let ErrorOutputType::Json { .. } = tcx.sess.opts.error_format
+                && let Some(report) = self.ub_report(tcx)
+            {
+                err.set_json_data(serde_json::to_string(&report).unwrap());
+            }
             // Let the caller attach any additional information it wants.
             decorate(err);
         };
@@ -247,4 +255,159 @@ fn struct_generic(
             ErrorHandled::Reported(err.emit())
         }
     }
+
+    /// Describes this error in machine-readable form, if it is undefined behavior.
+    fn ub_report(&self, tcx: TyCtxtAt<'tcx>) -> Option<UbReport> {
+        use UndefinedBehaviorInfo::*;
+
+        let InterpError::UndefinedBehavior(ub) = &self.error else { return None };
+        let source_map = tcx.sess.source_map();
+        let mut report = UbReport {
+            kind: ub_kind(ub),
+            message: ub.to_string(),
+            allocations: Vec::new(),
+            pointer: None,
+            backtrace: self
+                .stacktrace
+                .iter()
+                .map(|frame| {
+                    let lo = source_map.lookup_char_pos(frame.span.lo());
+                    FrameReport {
+                        function: frame.instance.to_string(),
+                        file: source_map.filename_for_diagnostics(&lo.file.name).to_string(),
+                        line: lo.line,
+                        column: lo.col.to_usize() + 1,
+                    }
+                })
+                .collect(),
+        };
+        match *ub {
+            UnterminatedCString(ptr) | InvalidFunctionPointer(ptr) | InvalidVTablePointer(ptr) => {
+                report.pointer = Some(PointerReport::new(ptr, None));
+                report.allocations.extend(ptr.provenance.map(AllocReport::new));
+            }
+            PointerUseAfterFree(alloc_id)
+            | WriteToReadOnly(alloc_id)
+            | DerefFunctionPointer(alloc_id)
+            | DerefVTablePointer(alloc_id) => report.allocations.push(AllocReport::new(alloc_id)),
+            PointerOutOfBounds { alloc_id, alloc_size, ptr_offset, ptr_size, msg: _ } => {
+                let alloc =
+                    AllocReport { size: Some(alloc_size.bytes()), ..AllocReport::new(alloc_id) };
+                report.allocations.push(alloc);
+                report.pointer = Some(PointerReport {
+                    provenance: Some(alloc_id.0.get()),
+                    // The offset was turned into a signed machine integer for display, undo that.
+                    offset: tcx.data_layout.pointer_size.truncate(ptr_offset as u128) as u64,
+                    access_size: Some(ptr_size.bytes()),
+                });
+            }
+            DanglingIntPointer(addr, _) => {
+                report.pointer =
+                    Some(PointerReport { provenance: None, offset: addr, access_size: None });
+            }
+            InvalidUninitBytes(Some((alloc_id, ref access))) => {
+                report.allocations.push(AllocReport::new(alloc_id));
+                report.pointer = Some(PointerReport::new(
+                    Pointer::new(Some(alloc_id), access.access.start),
+                    Some(access.access.size.bytes()),
+                ));
+            }
+            _ => {}
+        }
+        Some(report)
+    }
+}
+
+/// The JSON representation of a UB error, see `ConstEvalErr::ub_report`.
+#[derive(Serialize)]
+struct UbReport {
+    /// The name of the `UndefinedBehaviorInfo` variant, e.g. `"PointerOutOfBounds"`.
+    kind: &'static str,
+    /// The human-readable description of the error.
+    message: String,
+    /// The allocations involved in the error.
+    allocations: Vec<AllocReport>,
+    /// The pointer that was used, for errors caused by a bad pointer.
+    pointer: Option<PointerReport>,
+    /// The frames that were executing, innermost first.
+    backtrace: Vec<FrameReport>,
+}
+
+#[derive(Serialize)]
+struct AllocReport {
+    alloc_id: u64,
+    /// The size of the allocation, when the error depends on it.
+    size: Option<u64>,
+}
+
+impl AllocReport {
+    fn new(alloc_id: AllocId) -> Self {
+        AllocReport { alloc_id: alloc_id.0.get(), size: None }
+    }
+}
+
+#[derive(Serialize)]
+struct PointerReport {
+    /// The allocation the pointer points into, `None` if it has no provenance.
+    provenance: Option<u64>,
+    /// The offset into that allocation, or the address if there is no provenance.
+    offset: u64,
+    /// The number of bytes that were accessed through the pointer, if known.
+    access_size: Option<u64>,
+}
+
+impl PointerReport {
+    fn new(ptr: Pointer<Option<AllocId>>, access_size: Option<u64>) -> Self {
+        let (provenance, offset) = ptr.into_parts();
+        PointerReport {
+            provenance: provenance.map(|alloc_id| alloc_id.0.get()),
+            offset: offset.bytes(),
+            access_size,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct FrameReport {
+    function: String,
+    file: String,
+    /// 1-based.
+    line: usize,
+    /// 1-based.
+    column: usize,
+}
+
+fn ub_kind(ub: &UndefinedBehaviorInfo) -> &'static str {
+    use UndefinedBehaviorInfo::*;
+    match ub {
+        Ub(_) => "Ub",
+        Unreachable => "Unreachable",
+        BoundsCheckFailed { .. } => "BoundsCheckFailed",
+        DivisionByZero => "DivisionByZero",
+        RemainderByZero => "RemainderByZero",
+        DivisionOverflow => "DivisionOverflow",
+        RemainderOverflow => "RemainderOverflow",
+        PointerArithOverflow => "PointerArithOverflow",
+        InvalidMeta(_) => "InvalidMeta",
+        UnterminatedCString(_) => "UnterminatedCString",
+        PointerUseAfterFree(_) => "PointerUseAfterFree",
+        PointerOutOfBounds { .. } => "PointerOutOfBounds",
+        DanglingIntPointer(..) => "DanglingIntPointer",
+        AlignmentCheckFailed { .. } => "AlignmentCheckFailed",
+        WriteToReadOnly(_) => "WriteToReadOnly",
+        DerefFunctionPointer(_) => "DerefFunctionPointer",
+        DerefVTablePointer(_) => "DerefVTablePointer",
+        ValidationFailure { .. } => "ValidationFailure",
+        InvalidBool(_) => "InvalidBool",
+        InvalidChar(_) => "InvalidChar",
+        InvalidTag(_) => "InvalidTag",
+        InvalidFunctionPointer(_) => "InvalidFunctionPointer",
+        InvalidVTablePointer(_) => "InvalidVTablePointer",
+        InvalidVTableTrait { .. } => "InvalidVTableTrait",
+        InvalidStr(_) => "InvalidStr",
+        InvalidUninitBytes(_) => "InvalidUninitBytes",
+        DeadLocal => "DeadLocal",
+        ScalarSizeMismatch(_) => "ScalarSizeMismatch",
+        UninhabitedEnumVariantWritten => "UninhabitedEnumVariantWritten",
+    }
 }
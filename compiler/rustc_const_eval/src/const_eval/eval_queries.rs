@@ -104,6 +104,7 @@ pub(super) fn mk_eval_cx<'mir, 'tcx>(
             tcx.const_eval_limit(),
             can_access_statics,
             /*check_alignment:*/ false,
+            /*strict_provenance:*/ false,
         ),
     )
 }
@@ -307,9 +308,10 @@ pub fn eval_to_allocation_raw_provider<'tcx>(
         // Statics (and promoteds inside statics) may access other statics, because unlike consts
         // they do not have to behave "as if" they were evaluated at runtime.
         CompileTimeInterpreter::new(
-            tcx.const_eval_limit(),
+            tcx.item_const_eval_limit(def.did),
             /*can_access_statics:*/ is_static,
             /*check_alignment:*/ tcx.sess.opts.unstable_opts.extra_const_ub_checks,
+            /*strict_provenance:*/ tcx.sess.opts.unstable_opts.const_eval_strict_provenance,
         ),
     );
 
@@ -5,20 +5,25 @@
 use std::collections::hash_map::Entry;
 use std::hash::Hash;
 
-use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use std::fmt;
 
 use rustc_ast::Mutability;
+use rustc_hir as hir;
 use rustc_hir::def_id::DefId;
 use rustc_middle::mir::AssertMessage;
+use rustc_session::lint::builtin::{
+    CONST_EVAL_LIMIT_EXCEEDED, CONST_EVAL_STRICT_PROVENANCE, LONG_RUNNING_CONST_EVAL,
+};
 use rustc_session::Limit;
 use rustc_span::symbol::{sym, Symbol};
+use rustc_span::Span;
 use rustc_target::abi::{Align, Size};
 use rustc_target::spec::abi::Abi as CallAbi;
 
 use crate::interpret::{
-    self, compile_time_machine, AllocId, ConstAllocation, Frame, ImmTy, InterpCx, InterpResult,
-    OpTy, PlaceTy, Pointer, Scalar, StackPopUnwind,
+    self, compile_time_machine, AllocId, AllocRange, ConstAllocation, Frame, ImmTy, InterpCx,
+    InterpResult, MemoryKind, OpTy, PlaceTy, Pointer, ProvenanceViolation, Scalar, StackPopUnwind,
 };
 
 use super::error::*;
@@ -67,16 +72,149 @@ fn hook_special_const_fn(
         }
         Ok(None)
     }
+
+    /// Emits the `long_running_const_eval` lint at the current location, with the full const-eval
+    /// backtrace. Evaluation continues afterwards, unless the lint is denied.
+    fn lint_long_running(&self) -> InterpResult<'tcx> {
+        let stacktrace = self.generate_stacktrace();
+        let lint_root =
+            stacktrace.iter().find_map(|frame| frame.lint_root).unwrap_or(hir::CRATE_HIR_ID);
+        let span = self.cur_span();
+        let steps = self.machine.steps_taken;
+        self.tcx.struct_span_lint_hir(LONG_RUNNING_CONST_EVAL, lint_root, span, |lint| {
+            let mut lint = lint.build(format!(
+                "constant evaluation has been running for {steps} steps and is not done yet"
+            ));
+            if stacktrace.len() > 1 {
+                for frame in &stacktrace {
+                    lint.span_label(frame.span, frame.to_string());
+                }
+            }
+            lint.note(
+                "if this evaluation is just slow, you can allow this lint; \
+                if it never finishes, check the backtrace for an infinite loop",
+            );
+            lint.emit();
+        });
+
+        let (level, _) = self.tcx.lint_level_at_node(LONG_RUNNING_CONST_EVAL, lint_root);
+        if level.is_error() {
+            // The lint was emitted as an error, there is no point in continuing.
+            let guar = self.tcx.sess.delay_span_bug(span, "denied lint should have errored");
+            throw_inval!(AlreadyReported(guar));
+        }
+        Ok(())
+    }
+
+    /// Emits the `const_eval_limit_exceeded` lint on the item being evaluated. The limit is soft:
+    /// unless the lint is denied, evaluation continues until it hits the hard limit of
+    /// `HARD_STEP_LIMIT_FACTOR` times the item's limit.
+    fn lint_step_limit_exceeded(&self) -> InterpResult<'tcx> {
+        let stacktrace = self.generate_stacktrace();
+        // The outermost frame is the item whose evaluation was requested, so that the lint can be
+        // allowed on it.
+        let lint_root =
+            stacktrace.last().and_then(|frame| frame.lint_root).unwrap_or(hir::CRATE_HIR_ID);
+        let item = self.stack().first().expect("step limit reached without a frame").instance;
+        let item_span = self.tcx.def_span(item.def_id());
+        let span = self.cur_span();
+        let steps = self.machine.steps_taken;
+        self.tcx.struct_span_lint_hir(CONST_EVAL_LIMIT_EXCEEDED, lint_root, item_span, |lint| {
+            let mut lint =
+                lint.build(format!("constant evaluation exceeded the limit of {steps} steps"));
+            lint.span_label(span, "the evaluation was here when the limit was reached");
+            lint.help(
+                "if this evaluation is expected to finish, raise the limit for this item with \
+                `#[const_eval_limit = \"N\"]` or allow this lint",
+            );
+            lint.emit();
+        });
+
+        let (level, _) = self.tcx.lint_level_at_node(CONST_EVAL_LIMIT_EXCEEDED, lint_root);
+        if level.is_error() {
+            // The lint was emitted as an error, there is no point in continuing.
+            let guar = self.tcx.sess.delay_span_bug(span, "denied lint should have errored");
+            throw_inval!(AlreadyReported(guar));
+        }
+        Ok(())
+    }
+
+    /// Emits the `const_eval_strict_provenance` lint at the current location, unless it was
+    /// already emitted there during this evaluation.
+    fn lint_strict_provenance_violation(&mut self, violation: ProvenanceViolation) {
+        let span = self.cur_span();
+        // The violation might happen in a loop, only report it once.
+        if !self.machine.provenance_violations.insert(span) {
+            return;
+        }
+        let msg = match violation {
+            ProvenanceViolation::IntToPtrCast => {
+                "casting an integer to a pointer creates a pointer without provenance"
+            }
+            ProvenanceViolation::IntToPtrTransmute => {
+                "transmuting an integer to a pointer creates a pointer without provenance"
+            }
+        };
+        let stacktrace = self.generate_stacktrace();
+        let lint_root =
+            stacktrace.iter().find_map(|frame| frame.lint_root).unwrap_or(hir::CRATE_HIR_ID);
+        self.tcx.struct_span_lint_hir(CONST_EVAL_STRICT_PROVENANCE, lint_root, span, |lint| {
+            let mut lint = lint.build(msg);
+            if stacktrace.len() > 1 {
+                for frame in &stacktrace {
+                    lint.span_label(frame.span, frame.to_string());
+                }
+            }
+            lint.note(
+                "strict provenance requires every pointer to be derived from another pointer",
+            );
+            lint.help(
+                "use `ptr::invalid` for a pointer that is never dereferenced, or derive it from \
+                an existing pointer with `with_addr`",
+            );
+            lint.emit();
+        });
+    }
 }
 
+/// The number of evaluated terminators at which we start emitting the `long_running_const_eval`
+/// lint if the step limit is disabled. Otherwise, we start at three quarters of the limit, so that
+/// the lint fires before the limit is reached, but not below `LONG_RUNNING_MIN`.
+const LONG_RUNNING_START: u64 = 1 << 20;
+
+/// The smallest number of evaluated terminators at which we emit the `long_running_const_eval`
+/// lint. Evaluations that are shorter than this finish quickly, even if their limit is low.
+const LONG_RUNNING_MIN: u64 = 1 << 16;
+
+/// Once the `const_eval_limit_exceeded` lint fired, evaluation may continue for this many times the
+/// item's limit before it is aborted. This ensures that evaluation terminates even if the lint is
+/// allowed or capped with `--cap-lints`.
+const HARD_STEP_LIMIT_FACTOR: usize = 16;
+
+/// The number of evaluated terminators between two memory clean-ups, see
+/// `remove_unreachable_dead_allocs` and `deduplicate_allocation_bytes`.
+const MEMORY_CLEANUP_INTERVAL: u64 = 1 << 16;
+
 /// Extra machine state for CTFE, and the Machine instance
+#[derive(Clone)]
 pub struct CompileTimeInterpreter<'mir, 'tcx> {
-    /// For now, the number of terminators that can be evaluated before we throw a resource
-    /// exhaustion error.
+    /// The number of terminators that can be evaluated before we emit the
+    /// `const_eval_limit_exceeded` lint.
     ///
     /// Setting this to `0` disables the limit and allows the interpreter to run forever.
     pub(super) steps_remaining: usize,
 
+    /// The number of terminators that can be evaluated after the lint fired, before evaluation
+    /// is aborted regardless of the lint level.
+    pub(super) hard_steps_remaining: usize,
+
+    /// The number of terminators evaluated so far, used for the `long_running_const_eval` lint.
+    pub(super) steps_taken: u64,
+
+    /// The value of `steps_taken` at which we emit the `long_running_const_eval` lint next. It
+    /// doubles every time the lint is emitted.
+    pub(super) next_long_running_lint: u64,
+
     /// The virtual call stack.
     pub(super) stack: Vec<Frame<'mir, 'tcx, AllocId, ()>>,
 
@@ -90,6 +228,17 @@ pub struct CompileTimeInterpreter<'mir, 'tcx> {
 
     /// Whether to check alignment during evaluation.
     pub(super) check_alignment: bool,
+
+    /// Whether to warn about code that does not follow strict provenance, see
+    /// `-Zconst-eval-strict-provenance`.
+    pub(super) strict_provenance: bool,
+
+    /// The locations at which `const_eval_strict_provenance` was already emitted.
+    pub(super) provenance_violations: FxHashSet<Span>,
+
+    /// The number of bytes currently allocated by this evaluation, checked against
+    /// `-Zconst-eval-memory-limit`.
+    pub(super) allocated_bytes: u64,
 }
 
 impl<'mir, 'tcx> CompileTimeInterpreter<'mir, 'tcx> {
@@ -97,12 +246,22 @@ pub(crate) fn new(
         const_eval_limit: Limit,
         can_access_statics: bool,
         check_alignment: bool,
+        strict_provenance: bool,
     ) -> Self {
         CompileTimeInterpreter {
             steps_remaining: const_eval_limit.0,
+            hard_steps_remaining: const_eval_limit.0.saturating_mul(HARD_STEP_LIMIT_FACTOR - 1),
+            steps_taken: 0,
+            next_long_running_lint: match const_eval_limit.0 {
+                0 => LONG_RUNNING_START,
+                limit => (limit as u64 / 4 * 3).max(LONG_RUNNING_MIN),
+            },
             stack: Vec::new(),
             can_access_statics,
             check_alignment,
+            strict_provenance,
+            provenance_violations: FxHashSet::default(),
+            allocated_bytes: 0,
         }
     }
 }
@@ -190,40 +349,6 @@ fn may_leak(self) -> bool {
     }
 }
 
-impl<'mir, 'tcx: 'mir> CompileTimeEvalContext<'mir, 'tcx> {
-    /// See documentation on the `ptr_guaranteed_cmp` intrinsic.
-    fn guaranteed_cmp(&mut self, a: Scalar, b: Scalar) -> InterpResult<'tcx, u8> {
-        Ok(match (a, b) {
-            // Comparisons between integers are always known.
-            (Scalar::Int { .. }, Scalar::Int { .. }) => {
-                if a == b {
-                    1
-                } else {
-                    0
-                }
-            }
-            // Comparisons of abstract pointers with null pointers are known if the pointer
-            // is in bounds, because if they are in bounds, the pointer can't be null.
-            // Inequality with integers other than null can never be known for sure.
-            (Scalar::Int(int), ptr @ Scalar::Ptr(..))
-            | (ptr @ Scalar::Ptr(..), Scalar::Int(int))
-                if int.is_null() && !self.scalar_may_be_null(ptr)? =>
-            {
-                0
-            }
-            // Equality with integers can never be known for sure.
-            (Scalar::Int { .. }, Scalar::Ptr(..)) | (Scalar::Ptr(..), Scalar::Int { .. }) => 2,
-            // FIXME: return a `1` for when both sides are the same pointer, *except* that
-            // some things (like functions and vtables) do not have stable addresses
-            // so we need to be careful around them (see e.g. #73722).
-            // FIXME: return `0` for at least some comparisons where we can reliably
-            // determine the result of runtime inequality tests at compile-time.
-            // Examples include comparison of addresses in different static items.
-            (Scalar::Ptr(..), Scalar::Ptr(..)) => 2,
-        })
-    }
-}
-
 impl<'mir, 'tcx> interpret::Machine<'mir, 'tcx> for CompileTimeInterpreter<'mir, 'tcx> {
     compile_time_machine!(<'mir, 'tcx>);
 
@@ -330,12 +455,6 @@ fn call_intrinsic(
             throw_unsup_format!("intrinsic `{intrinsic_name}` is not supported at compile-time");
         };
         match intrinsic_name {
-            sym::ptr_guaranteed_cmp => {
-                let a = ecx.read_scalar(&args[0])?;
-                let b = ecx.read_scalar(&args[1])?;
-                let cmp = ecx.guaranteed_cmp(a, b)?;
-                ecx.write_scalar(Scalar::from_u8(cmp), dest)?;
-            }
             sym::const_allocate => {
                 let size = ecx.read_scalar(&args[0])?.to_machine_usize(ecx)?;
                 let align = ecx.read_scalar(&args[1])?.to_machine_usize(ecx)?;
@@ -429,14 +548,35 @@ fn binary_ptr_op(
     }
 
     fn before_terminator(ecx: &mut InterpCx<'mir, 'tcx, Self>) -> InterpResult<'tcx> {
+        ecx.machine.steps_taken += 1;
+        // Exponential backoff, so that this does not get too noisy for very long evaluations.
+        if ecx.machine.steps_taken == ecx.machine.next_long_running_lint {
+            ecx.machine.next_long_running_lint *= 2;
+            ecx.lint_long_running()?;
+        }
+        if ecx.machine.steps_taken % MEMORY_CLEANUP_INTERVAL == 0 {
+            // The stack holds all the pointers CTFE has.
+            ecx.remove_unreachable_dead_allocs(std::iter::empty());
+            ecx.deduplicate_allocation_bytes();
+        }
+
         // The step limit has already been hit in a previous call to `before_terminator`.
         if ecx.machine.steps_remaining == 0 {
+            // A limit of `0` disables both limits.
+            if ecx.machine.hard_steps_remaining == 0 {
+                return Ok(());
+            }
+            ecx.machine.hard_steps_remaining -= 1;
+            if ecx.machine.hard_steps_remaining == 0 {
+                throw_exhaust!(StepLimitReached)
+            }
             return Ok(());
         }
 
         ecx.machine.steps_remaining -= 1;
         if ecx.machine.steps_remaining == 0 {
-            throw_exhaust!(StepLimitReached)
+            // The limit is soft: unless the lint is denied, we keep going until the hard limit.
+            ecx.lint_step_limit_exceeded()?;
         }
 
         Ok(())
@@ -451,6 +591,33 @@ fn expose_ptr(
         throw_unsup_format!("exposing pointers is not possible at compile-time")
     }
 
+    fn handle_catch_unwind(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        _try_fn: &OpTy<'tcx>,
+        _data: &OpTy<'tcx>,
+        _catch_fn: &OpTy<'tcx>,
+        _dest: &PlaceTy<'tcx>,
+        _ret: mir::BasicBlock,
+    ) -> InterpResult<'tcx> {
+        // This is only reachable with -Zunleash-the-miri-inside-of-you.
+        throw_unsup_format!("panics cannot be caught in const contexts")
+    }
+
+    fn strict_provenance_violation(
+        ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        violation: ProvenanceViolation,
+    ) -> InterpResult<'tcx> {
+        if !ecx.machine.strict_provenance {
+            return Ok(());
+        }
+        // Only point at code in the current crate: the standard library creates such pointers on
+        // purpose, e.g. in `ptr::invalid`.
+        if ecx.frame().instance.def_id().is_local() {
+            ecx.lint_strict_provenance_violation(violation);
+        }
+        Ok(())
+    }
+
     #[inline(always)]
     fn init_frame_extra(
         ecx: &mut InterpCx<'mir, 'tcx, Self>,
@@ -517,6 +684,41 @@ fn before_access_global(
             }
         }
     }
+
+    fn before_memory_allocation(
+        ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        size: Size,
+        _align: Align,
+        _kind: MemoryKind<Self::MemoryKind>,
+    ) -> InterpResult<'tcx> {
+        let allocated = ecx.machine.allocated_bytes.saturating_add(size.bytes());
+        if let Some(limit) = ecx.tcx.sess.opts.unstable_opts.const_eval_memory_limit
+            && allocated > limit
+        {
+            // The bottom frame, if any, is the body of the item being evaluated.
+            let item = ecx.machine.stack.first().map(|frame| frame.instance.def_id());
+            throw_exhaust!(MemoryLimitReached {
+                item: item.map(|def_id| ecx.tcx.def_path_str(def_id)),
+                size: size.bytes(),
+                limit,
+            })
+        }
+        ecx.machine.allocated_bytes = allocated;
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn before_memory_deallocation(
+        _tcx: TyCtxt<'tcx>,
+        machine: &mut Self,
+        _alloc_extra: &mut Self::AllocExtra,
+        _prov: (AllocId, Self::ProvenanceExtra),
+        range: AllocRange,
+    ) -> InterpResult<'tcx> {
+        // Not every allocation goes through `before_memory_allocation`, so this can't be exact.
+        machine.allocated_bytes = machine.allocated_bytes.saturating_sub(range.size.bytes());
+        Ok(())
+    }
 }
 
 // Please do not add any code below the above `Machine` trait impl. I (oli-obk) plan more cleanups
@@ -13,6 +13,7 @@
 
 use super::{
     util::ensure_monomorphic_enough, FnVal, ImmTy, Immediate, InterpCx, Machine, OpTy, PlaceTy,
+    ProvenanceViolation,
 };
 
 impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
@@ -214,6 +215,9 @@ pub fn pointer_from_exposed_address_cast(
         let scalar = src.to_scalar();
         let addr = self.cast_from_int_like(scalar, src.layout, self.tcx.types.usize)?;
         let addr = addr.to_machine_usize(self)?;
+        if addr != 0 {
+            M::strict_provenance_violation(self, ProvenanceViolation::IntToPtrCast)?;
+        }
 
         // Then turn address into pointer.
         let ptr = M::ptr_from_addr_cast(&self, addr)?;
@@ -250,6 +250,23 @@ pub fn current_span(&self) -> Span {
     }
 }
 
+impl<'mir, 'tcx, Prov: Provenance, Extra: Clone> Clone for Frame<'mir, 'tcx, Prov, Extra> {
+    /// The clone does not enter a `tracing` span; only the original frame exits its span when
+    /// popped.
+    fn clone(&self) -> Self {
+        Frame {
+            body: self.body,
+            instance: self.instance,
+            extra: self.extra.clone(),
+            return_to_block: self.return_to_block,
+            return_place: self.return_place.clone(),
+            locals: self.locals.clone(),
+            tracing_span: SpanGuard::new(),
+            loc: self.loc,
+        }
+    }
+}
+
 impl<'tcx> fmt::Display for FrameInfo<'tcx> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         ty::tls::with(|tcx| {
@@ -625,10 +642,10 @@ pub(super) fn size_and_align_of(
                 }
                 Ok(Some((size, align)))
             }
-            ty::Dynamic(..) => {
+            ty::Dynamic(data, ..) => {
                 let vtable = metadata.unwrap_meta().to_pointer(self)?;
                 // Read size and align from vtable (already checks size).
-                Ok(Some(self.get_vtable_size_and_align(vtable)?))
+                Ok(Some(self.get_vtable_size_and_align(vtable, Some(*data))?))
             }
 
             ty::Slice(_) | ty::Str => {
@@ -949,6 +966,37 @@ pub fn generate_stacktrace(&self) -> Vec<FrameInfo<'tcx>> {
     }
 }
 
+/// A copy of the machine state and memory of an `InterpCx`, see [`InterpCx::snapshot`].
+pub struct InterpSnapshot<'mir, 'tcx, M: Machine<'mir, 'tcx>> {
+    machine: M,
+    memory: Memory<'mir, 'tcx, M>,
+}
+
+impl<'mir, 'tcx: 'mir, M> InterpCx<'mir, 'tcx, M>
+where
+    M: Machine<'mir, 'tcx> + Clone,
+    M::MemoryMap: Clone,
+{
+    /// Takes a snapshot of the full interpreter state: the machine (and thus the stack) and the
+    /// memory. Use [`InterpCx::restore`] to go back to that state later, e.g. to explore both
+    /// sides of a branch without evaluating everything up to it twice.
+    ///
+    /// Allocations are copied on write, so taking a snapshot does not duplicate their bytes.
+    pub fn snapshot(&self) -> InterpSnapshot<'mir, 'tcx, M> {
+        InterpSnapshot { machine: self.machine.clone(), memory: self.memory.clone() }
+    }
+
+    /// Resets the interpreter to the state it was in when `snapshot` was taken. The same
+    /// snapshot can be restored any number of times.
+    ///
+    /// Allocation IDs are never reused, so pointers created after the snapshot was taken
+    /// dangle after restoring it.
+    pub fn restore(&mut self, snapshot: &InterpSnapshot<'mir, 'tcx, M>) {
+        self.machine = snapshot.machine.clone();
+        self.memory = snapshot.memory.clone();
+    }
+}
+
 #[doc(hidden)]
 /// Helper struct for the `dump_place` function.
 pub struct PlacePrinter<'a, 'mir, 'tcx, M: Machine<'mir, 'tcx>> {
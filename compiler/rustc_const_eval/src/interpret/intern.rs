@@ -20,6 +20,7 @@
 use rustc_hir as hir;
 use rustc_middle::mir::interpret::InterpResult;
 use rustc_middle::ty::{self, layout::TyAndLayout, Ty};
+use rustc_target::abi::Size;
 
 use rustc_ast::Mutability;
 
@@ -405,7 +406,7 @@ pub fn intern_const_alloc_recursive<
     debug!(?todo);
     debug!("dead_alloc_map: {:#?}", ecx.memory.dead_alloc_map);
     while let Some(alloc_id) = todo.pop() {
-        if let Some((_, mut alloc)) = ecx.memory.alloc_map.remove(&alloc_id) {
+        if let Some((kind, mut alloc)) = ecx.memory.alloc_map.remove(&alloc_id) {
             // We can't call the `intern_shallow` method here, as its logic is tailored to safe
             // references and a `leftover_allocations` set (where we only have a todo-list here).
             // So we hand-roll the interning logic here again.
@@ -428,14 +429,29 @@ pub fn intern_const_alloc_recursive<
                     alloc.mutability = Mutability::Not;
                 }
                 InternKind::Constant => {
-                    // If it's a constant, we should not have any "leftovers" as everything
-                    // is tracked by const-checking.
-                    // FIXME: downgrade this to a warning? It rejects some legitimate consts,
-                    // such as `const CONST_RAW: *const Vec<i32> = &Vec::new() as *const _;`.
-                    ecx.tcx
-                        .sess
-                        .span_err(ecx.tcx.span, "untyped pointers are not allowed in constant");
-                    // For better errors later, mark the allocation as immutable.
+                    if kind == MemoryKind::Machine(const_eval::MemoryKind::Heap) {
+                        // `const_allocate` memory is usually only reachable through raw pointers.
+                        // Like memory behind references, it becomes part of the final value, but
+                        // without a type to validate against we require it to be fully
+                        // initialized.
+                        let range_init =
+                            alloc.init_mask().is_range_initialized(Size::ZERO, alloc.size());
+                        if range_init.is_err() {
+                            ecx.tcx.sess.span_err(
+                                ecx.tcx.span,
+                                "encountered uninitialized heap memory in final constant",
+                            );
+                        }
+                    } else {
+                        // If it's a constant, we should not have any other "leftovers" as
+                        // everything is tracked by const-checking.
+                        // FIXME: downgrade this to a warning? It rejects some legitimate consts,
+                        // such as `const CONST_RAW: *const Vec<i32> = &Vec::new() as *const _;`.
+                        ecx.tcx
+                            .sess
+                            .span_err(ecx.tcx.span, "untyped pointers are not allowed in constant");
+                    }
+                    // Constants are never mutable, and for errors this makes later errors better.
                     alloc.mutability = Mutability::Not;
                 }
             }
@@ -4,6 +4,7 @@
 
 use std::convert::TryFrom;
 
+use rustc_apfloat::{Float, Round};
 use rustc_hir::def_id::DefId;
 use rustc_middle::mir::{
     self,
@@ -11,15 +12,16 @@
     BinOp, NonDivergingIntrinsic,
 };
 use rustc_middle::ty;
-use rustc_middle::ty::layout::LayoutOf as _;
+use rustc_middle::ty::layout::{FnAbiOf as _, LayoutOf as _, TyAndLayout};
 use rustc_middle::ty::subst::SubstsRef;
-use rustc_middle::ty::{Ty, TyCtxt};
-use rustc_span::symbol::{sym, Symbol};
-use rustc_target::abi::{Abi, Align, Primitive, Size};
+use rustc_middle::ty::{FloatTy, Ty, TyCtxt};
+use rustc_span::symbol::{kw, sym, Symbol};
+use rustc_target::abi::{Abi, Align, Endian, HasDataLayout, Primitive, Size};
+use rustc_target::spec::abi::Abi as CallAbi;
 
 use super::{
-    util::ensure_monomorphic_enough, CheckInAllocMsg, ImmTy, InterpCx, Machine, OpTy, PlaceTy,
-    Pointer,
+    util::ensure_monomorphic_enough, AllocKind, CheckInAllocMsg, ImmTy, Immediate, InterpCx,
+    MPlaceTy, Machine, OpTy, PlaceTy, Pointer, Provenance, ProvenanceViolation, StackPopUnwind,
 };
 
 mod caller_location;
@@ -42,6 +44,76 @@ fn numeric_intrinsic<Prov>(name: Symbol, bits: u128, kind: Primitive) -> Scalar<
     Scalar::from_uint(bits_out, size)
 }
 
+/// Converts an element of a SIMD mask to a `bool`. Mask elements are integers that must have
+/// either all bits or no bits set; anything else is UB.
+fn simd_element_to_bool<'tcx, Prov: Provenance>(
+    elem: ImmTy<'tcx, Prov>,
+) -> InterpResult<'tcx, bool> {
+    let val = elem.to_scalar().to_int(elem.layout.size)?;
+    Ok(match val {
+        0 => false,
+        -1 => true,
+        _ => throw_ub_format!("each element of a SIMD mask must be all-0-bits or all-1-bits"),
+    })
+}
+
+/// Returns the bit that represents lane `idx` of a vector with `vec_len` lanes in the integer
+/// bitmask used by `simd_bitmask` and `simd_select_bitmask`. The first lane corresponds to the
+/// least significant bit on little-endian targets and to the most significant used bit on
+/// big-endian targets, matching what the codegen backends produce.
+fn simd_bitmask_index(idx: u64, vec_len: u64, endian: Endian) -> u32 {
+    assert!(idx < vec_len);
+    let bit = match endian {
+        Endian::Little => idx,
+        Endian::Big => vec_len - 1 - idx,
+    };
+    u32::try_from(bit).unwrap()
+}
+
+/// Returns the rounding mode used by one of the float rounding intrinsics. `rint` and `nearbyint`
+/// round according to the current rounding mode, which is always round-to-nearest-even in Rust.
+fn float_rounding_mode(name: Symbol) -> Round {
+    match name {
+        sym::floorf32 | sym::floorf64 => Round::TowardNegative,
+        sym::ceilf32 | sym::ceilf64 => Round::TowardPositive,
+        sym::truncf32 | sym::truncf64 => Round::TowardZero,
+        sym::roundf32 | sym::roundf64 => Round::NearestTiesToAway,
+        sym::round_ties_even_f32
+        | sym::round_ties_even_f64
+        | sym::rintf32
+        | sym::rintf64
+        | sym::nearbyintf32
+        | sym::nearbyintf64 => Round::NearestTiesToEven,
+        _ => bug!("not a float rounding intrinsic: {}", name),
+    }
+}
+
+/// Computes `a * b + c + d` for unsigned integers of the given size, returning the low and
+/// high halves of the result. This can never overflow twice the bit width.
+fn carrying_mul_add(a: u128, b: u128, c: u128, d: u128, size: Size) -> (u128, u128) {
+    if size.bits() <= 64 {
+        // Everything fits into a `u128`.
+        let wide = a * b + c + d;
+        return (size.truncate(wide), wide >> size.bits());
+    }
+    assert_eq!(size.bits(), 128);
+    // Schoolbook multiplication on 64-bit limbs.
+    const MASK: u128 = u64::MAX as u128;
+    let (a_lo, a_hi) = (a & MASK, a >> 64);
+    let (b_lo, b_hi) = (b & MASK, b >> 64);
+    let ll = a_lo * b_lo;
+    let lh = a_lo * b_hi;
+    let hl = a_hi * b_lo;
+    let hh = a_hi * b_hi;
+    let mid = (ll >> 64) + (lh & MASK) + (hl & MASK);
+    let lo = (ll & MASK) | (mid << 64);
+    let hi = hh + (lh >> 64) + (hl >> 64) + (mid >> 64);
+    // Add both addends, propagating the carries into the high half.
+    let (lo, carry_c) = lo.overflowing_add(c);
+    let (lo, carry_d) = lo.overflowing_add(d);
+    (lo, hi + u128::from(carry_c) + u128::from(carry_d))
+}
+
 /// The logic for all nullary intrinsics is implemented here. These intrinsics don't get evaluated
 /// inside an `InterpCx` and instead have their value computed directly from rustc internal info.
 pub(crate) fn eval_nullary_intrinsic<'tcx>(
@@ -118,6 +190,11 @@ pub fn emulate_intrinsic(
         dest: &PlaceTy<'tcx, M::Provenance>,
         ret: Option<mir::BasicBlock>,
     ) -> InterpResult<'tcx, bool> {
+        // Give the machine a chance to take over.
+        if M::before_emulate_intrinsic(self, instance, args, dest, ret)? {
+            return Ok(true);
+        }
+
         let substs = instance.substs;
         let intrinsic_name = self.tcx.item_name(instance.def_id());
 
@@ -143,9 +220,10 @@ pub fn emulate_intrinsic(
                 // Avoid `deref_operand` -- this is not a deref, the ptr does not have to be
                 // dereferenceable!
                 let place = self.ref_to_mplace(&self.read_immediate(&args[0])?)?;
-                let (size, align) = self
-                    .size_and_align_of_mplace(&place)?
-                    .ok_or_else(|| err_unsup_format!("`extern type` does not have known layout"))?;
+                let (size, align) = match self.size_and_align_of_mplace(&place)? {
+                    Some(size_and_align) => size_and_align,
+                    None => M::extern_type_size_and_align(self, &place)?,
+                };
 
                 let result = match intrinsic_name {
                     sym::min_align_of_val => align.bytes(),
@@ -229,6 +307,22 @@ pub fn emulate_intrinsic(
                 )?;
                 self.write_scalar(val, dest)?;
             }
+            sym::carrying_mul_add => {
+                let layout = self.layout_of(substs.type_at(0))?;
+                if layout.abi.is_signed() {
+                    throw_unsup_format!(
+                        "`carrying_mul_add` is only supported on unsigned integers"
+                    );
+                }
+                let size = layout.size;
+                let a = self.read_scalar(&args[0])?.to_bits(size)?;
+                let b = self.read_scalar(&args[1])?.to_bits(size)?;
+                let c = self.read_scalar(&args[2])?.to_bits(size)?;
+                let d = self.read_scalar(&args[3])?.to_bits(size)?;
+                let (lo, hi) = carrying_mul_add(a, b, c, d, size);
+                self.write_scalar(Scalar::from_uint(lo, size), &self.place_field(dest, 0)?)?;
+                self.write_scalar(Scalar::from_uint(hi, size), &self.place_field(dest, 1)?)?;
+            }
             sym::discriminant_value => {
                 let place = self.deref_operand(&args[0])?;
                 let discr_val = self.read_discriminant(&place.into())?.0;
@@ -416,6 +510,14 @@ pub fn emulate_intrinsic(
             }
 
             sym::transmute => {
+                if args[0].layout.ty.is_integral()
+                    && dest.layout.ty.is_any_ptr()
+                    && let Ok(imm) = self.read_immediate_raw(&args[0])?
+                    && let Immediate::Scalar(Scalar::Int(int)) = *imm
+                    && !int.is_null()
+                {
+                    M::strict_provenance_violation(self, ProvenanceViolation::IntToPtrTransmute)?;
+                }
                 self.copy_op(&args[0], dest, /*allow_transmute*/ true)?;
             }
             sym::assert_inhabited | sym::assert_zero_valid | sym::assert_uninit_valid => {
@@ -436,6 +538,8 @@ pub fn emulate_intrinsic(
                     )?;
                 }
 
+                // These checks are queries, so hot generic code does not redo them for every
+                // execution of the intrinsic.
                 if intrinsic_name == sym::assert_zero_valid {
                     let should_panic = !self.tcx.permits_zero_init(layout);
 
@@ -502,6 +606,363 @@ pub fn emulate_intrinsic(
                     /*allow_transmute*/ false,
                 )?;
             }
+            sym::simd_add
+            | sym::simd_sub
+            | sym::simd_mul
+            | sym::simd_div
+            | sym::simd_rem
+            | sym::simd_and
+            | sym::simd_or
+            | sym::simd_xor => {
+                let (left, left_len) = self.operand_to_simd(&args[0])?;
+                let (right, right_len) = self.operand_to_simd(&args[1])?;
+                let (dest, dest_len) = self.place_to_simd(dest)?;
+                assert_eq!(dest_len, left_len, "Return vector length must match input length");
+                assert_eq!(dest_len, right_len, "Return vector length must match input length");
+
+                let bin_op = match intrinsic_name {
+                    sym::simd_add => BinOp::Add,
+                    sym::simd_sub => BinOp::Sub,
+                    sym::simd_mul => BinOp::Mul,
+                    sym::simd_div => BinOp::Div,
+                    sym::simd_rem => BinOp::Rem,
+                    sym::simd_and => BinOp::BitAnd,
+                    sym::simd_or => BinOp::BitOr,
+                    sym::simd_xor => BinOp::BitXor,
+                    _ => bug!(),
+                };
+
+                for i in 0..dest_len {
+                    let left = self.read_immediate(&self.mplace_index(&left, i)?.into())?;
+                    let right = self.read_immediate(&self.mplace_index(&right, i)?.into())?;
+                    let dest = self.mplace_index(&dest, i)?;
+                    // Like the non-SIMD arithmetic operators, these wrap on overflow. Division by
+                    // zero and `MIN / -1` are UB, which `overflowing_binary_op` already reports.
+                    let (val, _overflowed, ty) =
+                        self.overflowing_binary_op(bin_op, &left, &right)?;
+                    assert_eq!(ty, dest.layout.ty, "type mismatch for result of {:?}", bin_op);
+                    self.write_scalar(val, &dest.into())?;
+                }
+            }
+            sym::simd_ctlz | sym::simd_cttz | sym::simd_ctpop => {
+                let (input, input_len) = self.operand_to_simd(&args[0])?;
+                let (dest, dest_len) = self.place_to_simd(dest)?;
+                assert_eq!(dest_len, input_len, "Return vector length must match input length");
+
+                let scalar_name = match intrinsic_name {
+                    sym::simd_ctlz => sym::ctlz,
+                    sym::simd_cttz => sym::cttz,
+                    sym::simd_ctpop => sym::ctpop,
+                    _ => bug!(),
+                };
+                for i in 0..dest_len {
+                    let elem = self.mplace_index(&input, i)?;
+                    let kind = match elem.layout.abi {
+                        Abi::Scalar(scalar) => scalar.primitive(),
+                        _ => span_bug!(
+                            self.cur_span(),
+                            "{} called on invalid element type {:?}",
+                            intrinsic_name,
+                            elem.layout.ty
+                        ),
+                    };
+                    let bits = self.read_scalar(&elem.into())?.to_bits(elem.layout.size)?;
+                    let dest = self.mplace_index(&dest, i)?;
+                    self.write_scalar(numeric_intrinsic(scalar_name, bits, kind), &dest.into())?;
+                }
+            }
+            _ if intrinsic_name.as_str().starts_with("simd_shuffle") => {
+                // This also covers the older `simd_shuffleN` form, where the number of output
+                // lanes is part of the name; it is equal to the length of the index array.
+                let (left, left_len) = self.operand_to_simd(&args[0])?;
+                let (right, right_len) = self.operand_to_simd(&args[1])?;
+                let (dest, dest_len) = self.place_to_simd(dest)?;
+                // The indices are passed as a plain array, not as a SIMD type.
+                let index = &args[2];
+                let index_len = index.len(self)?;
+                assert_eq!(left_len, right_len, "Input vector lengths must match");
+                assert_eq!(index_len, dest_len, "Return vector length must match index length");
+
+                for i in 0..dest_len {
+                    let src_index =
+                        u64::from(self.read_scalar(&self.operand_index(index, i)?)?.to_u32()?);
+                    let val = if src_index < left_len {
+                        self.mplace_index(&left, src_index)?
+                    } else if src_index - left_len < right_len {
+                        self.mplace_index(&right, src_index - left_len)?
+                    } else {
+                        throw_ub_format!(
+                            "`{}` index {} is out-of-bounds for 2 vectors with {} lanes each",
+                            intrinsic_name,
+                            src_index,
+                            left_len
+                        );
+                    };
+                    let place = self.mplace_index(&dest, i)?;
+                    self.copy_op(&val.into(), &place.into(), /*allow_transmute*/ false)?;
+                }
+            }
+            sym::simd_reduce_and
+            | sym::simd_reduce_or
+            | sym::simd_reduce_xor
+            | sym::simd_reduce_any
+            | sym::simd_reduce_all
+            | sym::simd_reduce_max
+            | sym::simd_reduce_min
+            | sym::simd_reduce_max_nanless
+            | sym::simd_reduce_min_nanless
+            | sym::simd_reduce_add_unordered
+            | sym::simd_reduce_mul_unordered => {
+                let (input, input_len) = self.operand_to_simd(&args[0])?;
+                let bool_layout = self.layout_of(self.tcx.types.bool)?;
+                let to_bool = |elem| {
+                    simd_element_to_bool(elem)
+                        .map(|b| ImmTy::from_scalar(Scalar::from_bool(b), bool_layout))
+                };
+                let is_bool_reduction =
+                    matches!(intrinsic_name, sym::simd_reduce_any | sym::simd_reduce_all);
+
+                // Initialize with the first lane, then fold in the rest.
+                // Unordered reductions are free to pick any order, so we just go left to right.
+                let mut res = self.read_immediate(&self.mplace_index(&input, 0)?.into())?;
+                if is_bool_reduction {
+                    res = to_bool(res)?;
+                }
+                for i in 1..input_len {
+                    let mut elem = self.read_immediate(&self.mplace_index(&input, i)?.into())?;
+                    if is_bool_reduction {
+                        elem = to_bool(elem)?;
+                    }
+                    res = match intrinsic_name {
+                        sym::simd_reduce_and | sym::simd_reduce_all => {
+                            self.binary_op(BinOp::BitAnd, &res, &elem)?
+                        }
+                        sym::simd_reduce_or | sym::simd_reduce_any => {
+                            self.binary_op(BinOp::BitOr, &res, &elem)?
+                        }
+                        sym::simd_reduce_xor => self.binary_op(BinOp::BitXor, &res, &elem)?,
+                        sym::simd_reduce_add_unordered => {
+                            self.binary_op(BinOp::Add, &res, &elem)?
+                        }
+                        sym::simd_reduce_mul_unordered => {
+                            self.binary_op(BinOp::Mul, &res, &elem)?
+                        }
+                        sym::simd_reduce_max
+                        | sym::simd_reduce_min
+                        | sym::simd_reduce_max_nanless
+                        | sym::simd_reduce_min_nanless => {
+                            let is_max = matches!(
+                                intrinsic_name,
+                                sym::simd_reduce_max | sym::simd_reduce_max_nanless
+                            );
+                            let val = self.min_max_op(is_max, &res, &elem)?;
+                            ImmTy::from_scalar(val, res.layout)
+                        }
+                        _ => bug!(),
+                    };
+                }
+                self.write_immediate(*res, dest)?;
+            }
+            sym::simd_reduce_add_ordered | sym::simd_reduce_mul_ordered => {
+                let (input, input_len) = self.operand_to_simd(&args[0])?;
+                let bin_op = if intrinsic_name == sym::simd_reduce_add_ordered {
+                    BinOp::Add
+                } else {
+                    BinOp::Mul
+                };
+
+                // Fold the lanes into the initial value, strictly left to right.
+                let mut res = self.read_immediate(&args[1])?;
+                for i in 0..input_len {
+                    let elem = self.read_immediate(&self.mplace_index(&input, i)?.into())?;
+                    res = self.binary_op(bin_op, &res, &elem)?;
+                }
+                self.write_immediate(*res, dest)?;
+            }
+            sym::simd_select => {
+                let (mask, mask_len) = self.operand_to_simd(&args[0])?;
+                let (yes, yes_len) = self.operand_to_simd(&args[1])?;
+                let (no, no_len) = self.operand_to_simd(&args[2])?;
+                let (dest, dest_len) = self.place_to_simd(dest)?;
+                assert_eq!(dest_len, mask_len, "Return vector length must match mask length");
+                assert_eq!(dest_len, yes_len, "Return vector length must match input length");
+                assert_eq!(dest_len, no_len, "Return vector length must match input length");
+
+                for i in 0..dest_len {
+                    let mask = self.read_immediate(&self.mplace_index(&mask, i)?.into())?;
+                    let val = if simd_element_to_bool(mask)? {
+                        self.mplace_index(&yes, i)?
+                    } else {
+                        self.mplace_index(&no, i)?
+                    };
+                    let place = self.mplace_index(&dest, i)?;
+                    self.copy_op(&val.into(), &place.into(), /*allow_transmute*/ false)?;
+                }
+            }
+            sym::simd_gather => {
+                let (passthru, passthru_len) = self.operand_to_simd(&args[0])?;
+                let (ptrs, ptrs_len) = self.operand_to_simd(&args[1])?;
+                let (mask, mask_len) = self.operand_to_simd(&args[2])?;
+                let (dest, dest_len) = self.place_to_simd(dest)?;
+                assert_eq!(dest_len, passthru_len, "Return vector length must match input length");
+                assert_eq!(dest_len, ptrs_len, "Return vector length must match pointer length");
+                assert_eq!(dest_len, mask_len, "Return vector length must match mask length");
+
+                for i in 0..dest_len {
+                    let mask = self.read_immediate(&self.mplace_index(&mask, i)?.into())?;
+                    let place = self.mplace_index(&dest, i)?;
+                    // Lanes that are masked off do not access memory at all.
+                    let val = if simd_element_to_bool(mask)? {
+                        let ptr = self.read_pointer(&self.mplace_index(&ptrs, i)?.into())?;
+                        self.simd_lane_mplace(ptr, place.layout)?
+                    } else {
+                        self.mplace_index(&passthru, i)?
+                    };
+                    self.copy_op(&val.into(), &place.into(), /*allow_transmute*/ false)?;
+                }
+            }
+            sym::simd_scatter => {
+                let (values, values_len) = self.operand_to_simd(&args[0])?;
+                let (ptrs, ptrs_len) = self.operand_to_simd(&args[1])?;
+                let (mask, mask_len) = self.operand_to_simd(&args[2])?;
+                assert_eq!(values_len, ptrs_len, "Pointer vector length must match input length");
+                assert_eq!(values_len, mask_len, "Mask length must match input length");
+
+                // Lanes are written in order, so later lanes win if pointers overlap.
+                for i in 0..values_len {
+                    let mask = self.read_immediate(&self.mplace_index(&mask, i)?.into())?;
+                    if simd_element_to_bool(mask)? {
+                        let val = self.mplace_index(&values, i)?;
+                        let ptr = self.read_pointer(&self.mplace_index(&ptrs, i)?.into())?;
+                        let place = self.simd_lane_mplace(ptr, val.layout)?;
+                        self.copy_op(&val.into(), &place.into(), /*allow_transmute*/ false)?;
+                    }
+                }
+            }
+            sym::simd_masked_load => {
+                let (mask, mask_len) = self.operand_to_simd(&args[0])?;
+                let ptr = self.read_pointer(&args[1])?;
+                let (default, default_len) = self.operand_to_simd(&args[2])?;
+                let (dest, dest_len) = self.place_to_simd(dest)?;
+                assert_eq!(dest_len, mask_len, "Return vector length must match mask length");
+                assert_eq!(dest_len, default_len, "Return vector length must match input length");
+
+                for i in 0..dest_len {
+                    let mask = self.read_immediate(&self.mplace_index(&mask, i)?.into())?;
+                    let place = self.mplace_index(&dest, i)?;
+                    let val = if simd_element_to_bool(mask)? {
+                        // The lanes are stored contiguously starting at `ptr`.
+                        let ptr = ptr.wrapping_offset(place.layout.size * i, self);
+                        self.simd_lane_mplace(ptr, place.layout)?
+                    } else {
+                        self.mplace_index(&default, i)?
+                    };
+                    self.copy_op(&val.into(), &place.into(), /*allow_transmute*/ false)?;
+                }
+            }
+            sym::simd_masked_store => {
+                let (mask, mask_len) = self.operand_to_simd(&args[0])?;
+                let ptr = self.read_pointer(&args[1])?;
+                let (values, values_len) = self.operand_to_simd(&args[2])?;
+                assert_eq!(values_len, mask_len, "Mask length must match input length");
+
+                for i in 0..values_len {
+                    let mask = self.read_immediate(&self.mplace_index(&mask, i)?.into())?;
+                    if simd_element_to_bool(mask)? {
+                        let val = self.mplace_index(&values, i)?;
+                        let ptr = ptr.wrapping_offset(val.layout.size * i, self);
+                        let place = self.simd_lane_mplace(ptr, val.layout)?;
+                        self.copy_op(&val.into(), &place.into(), /*allow_transmute*/ false)?;
+                    }
+                }
+            }
+            sym::simd_select_bitmask => {
+                let mask = &args[0];
+                let (yes, yes_len) = self.operand_to_simd(&args[1])?;
+                let (no, no_len) = self.operand_to_simd(&args[2])?;
+                let (dest, dest_len) = self.place_to_simd(dest)?;
+                assert_eq!(dest_len, yes_len, "Return vector length must match input length");
+                assert_eq!(dest_len, no_len, "Return vector length must match input length");
+                // The mask is an integer with (at least) one bit per lane.
+                let bitmask_len = dest_len.max(8);
+                assert!(mask.layout.ty.is_integral());
+                assert!(bitmask_len <= 64);
+                assert_eq!(bitmask_len, mask.layout.size.bits());
+
+                let endian = self.data_layout().endian;
+                let mask =
+                    u64::try_from(self.read_scalar(mask)?.to_bits(mask.layout.size)?).unwrap();
+                for i in 0..dest_len {
+                    let bit = 1u64 << simd_bitmask_index(i, dest_len, endian);
+                    let val = if mask & bit != 0 {
+                        self.mplace_index(&yes, i)?
+                    } else {
+                        self.mplace_index(&no, i)?
+                    };
+                    let place = self.mplace_index(&dest, i)?;
+                    self.copy_op(&val.into(), &place.into(), /*allow_transmute*/ false)?;
+                }
+                // If the mask is "padded", make sure that the padding is all-zero.
+                let used_bits = (0..dest_len)
+                    .map(|i| 1u64 << simd_bitmask_index(i, dest_len, endian))
+                    .fold(0, |acc, bit| acc | bit);
+                if mask & !used_bits != 0 {
+                    throw_ub_format!(
+                        "a SIMD bitmask less than 8 bits long must be filled with 0s for the remaining bits"
+                    );
+                }
+            }
+            sym::simd_bitmask => {
+                let (input, input_len) = self.operand_to_simd(&args[0])?;
+                let bitmask_len = input_len.max(8);
+                assert!(dest.layout.ty.is_integral());
+                assert!(bitmask_len <= 64);
+                assert_eq!(bitmask_len, dest.layout.size.bits());
+
+                let endian = self.data_layout().endian;
+                let mut res = 0u64;
+                for i in 0..input_len {
+                    let elem = self.read_immediate(&self.mplace_index(&input, i)?.into())?;
+                    if simd_element_to_bool(elem)? {
+                        res |= 1u64 << simd_bitmask_index(i, input_len, endian);
+                    }
+                }
+                self.write_scalar(Scalar::from_uint(res, dest.layout.size), dest)?;
+            }
+            sym::ub_checks => {
+                let ub_checks = M::ub_checks(self)?;
+                self.write_scalar(Scalar::from_bool(ub_checks), dest)?;
+            }
+            sym::is_val_statically_known => {
+                let known = M::is_val_statically_known(self, &args[0])?;
+                self.write_scalar(Scalar::from_bool(known), dest)?;
+            }
+            sym::ptr_mask => {
+                let ptr = self.read_pointer(&args[0])?;
+                let mask = self.read_scalar(&args[1])?.to_machine_usize(self)?;
+                let masked = self.ptr_mask(ptr, mask)?;
+                self.write_pointer(masked, dest)?;
+            }
+            sym::read_via_copy => {
+                // This behaves exactly like the place expression `*ptr`: the pointer has to be
+                // aligned and dereferenceable, and `copy_op` validates the value if the machine
+                // enforces validity. Any aliasing checks happen via the machine's memory hooks.
+                let place = self.deref_operand(&args[0])?;
+                self.copy_op(&place.into(), dest, /*allow_transmute*/ false)?;
+            }
+            sym::write_via_move => {
+                // Same as above, but for `*ptr = value`.
+                let place = self.deref_operand(&args[0])?;
+                self.copy_op(&args[1], &place.into(), /*allow_transmute*/ false)?;
+            }
+            sym::typed_swap_nonoverlapping => {
+                self.typed_swap_nonoverlapping_intrinsic(&args[0], &args[1])?;
+            }
+            sym::select_unpredictable => {
+                let cond = self.read_scalar(&args[0])?.to_bool()?;
+                let val = if cond { &args[1] } else { &args[2] };
+                self.copy_op(val, dest, /*allow_transmute*/ false)?;
+            }
             sym::likely | sym::unlikely | sym::black_box => {
                 // These just return their argument
                 self.copy_op(&args[0], dest, /*allow_transmute*/ false)?;
@@ -511,17 +972,167 @@ pub fn emulate_intrinsic(
                 self.write_scalar(result, dest)?;
             }
 
+            sym::sqrtf32 => {
+                let x = f32::from_bits(self.read_scalar(&args[0])?.to_u32()?);
+                // IEEE 754 requires `sqrt` to be correctly rounded, so the host computes the same
+                // result as any target. Only the NaN payload may differ, so it is canonicalized.
+                let res = if x.is_nan() || x < 0.0 { f32::NAN } else { x.sqrt() };
+                self.write_scalar(Scalar::from_u32(res.to_bits()), dest)?;
+            }
+            sym::sqrtf64 => {
+                let x = f64::from_bits(self.read_scalar(&args[0])?.to_u64()?);
+                // See above.
+                let res = if x.is_nan() || x < 0.0 { f64::NAN } else { x.sqrt() };
+                self.write_scalar(Scalar::from_u64(res.to_bits()), dest)?;
+            }
+
+            sym::floorf32
+            | sym::ceilf32
+            | sym::truncf32
+            | sym::roundf32
+            | sym::round_ties_even_f32
+            | sym::rintf32
+            | sym::nearbyintf32 => {
+                let x = self.read_scalar(&args[0])?.to_f32()?;
+                let res = x.round_to_integral(float_rounding_mode(intrinsic_name)).value;
+                self.write_scalar(Scalar::from_f32(res), dest)?;
+            }
+            sym::floorf64
+            | sym::ceilf64
+            | sym::truncf64
+            | sym::roundf64
+            | sym::round_ties_even_f64
+            | sym::rintf64
+            | sym::nearbyintf64 => {
+                let x = self.read_scalar(&args[0])?.to_f64()?;
+                let res = x.round_to_integral(float_rounding_mode(intrinsic_name)).value;
+                self.write_scalar(Scalar::from_f64(res), dest)?;
+            }
+            sym::fmaf32 | sym::fmuladdf32 => {
+                let a = self.read_scalar(&args[0])?.to_f32()?;
+                let b = self.read_scalar(&args[1])?.to_f32()?;
+                let c = self.read_scalar(&args[2])?.to_f32()?;
+                // `fmuladd` may or may not be fused at runtime. We always fuse it, which keeps
+                // the result deterministic and makes it agree with `fma`.
+                let res = a.mul_add(b, c).value;
+                self.write_scalar(Scalar::from_f32(res), dest)?;
+            }
+            sym::fmaf64 | sym::fmuladdf64 => {
+                let a = self.read_scalar(&args[0])?.to_f64()?;
+                let b = self.read_scalar(&args[1])?.to_f64()?;
+                let c = self.read_scalar(&args[2])?.to_f64()?;
+                // See above for why this is always fused.
+                let res = a.mul_add(b, c).value;
+                self.write_scalar(Scalar::from_f64(res), dest)?;
+            }
+            sym::fabsf32 => {
+                let x = self.read_scalar(&args[0])?.to_f32()?;
+                self.write_scalar(Scalar::from_f32(x.abs()), dest)?;
+            }
+            sym::fabsf64 => {
+                let x = self.read_scalar(&args[0])?.to_f64()?;
+                self.write_scalar(Scalar::from_f64(x.abs()), dest)?;
+            }
+            sym::copysignf32 | sym::minnumf32 | sym::maxnumf32 => {
+                let a = self.read_scalar(&args[0])?.to_f32()?;
+                let b = self.read_scalar(&args[1])?.to_f32()?;
+                // `min` and `max` implement IEEE minNum and maxNum: if only one input is NaN,
+                // the other one is returned.
+                let res = match intrinsic_name {
+                    sym::copysignf32 => a.copy_sign(b),
+                    sym::minnumf32 => a.min(b),
+                    sym::maxnumf32 => a.max(b),
+                    _ => bug!(),
+                };
+                self.write_scalar(Scalar::from_f32(res), dest)?;
+            }
+            sym::copysignf64 | sym::minnumf64 | sym::maxnumf64 => {
+                let a = self.read_scalar(&args[0])?.to_f64()?;
+                let b = self.read_scalar(&args[1])?.to_f64()?;
+                let res = match intrinsic_name {
+                    sym::copysignf64 => a.copy_sign(b),
+                    sym::minnumf64 => a.min(b),
+                    sym::maxnumf64 => a.max(b),
+                    _ => bug!(),
+                };
+                self.write_scalar(Scalar::from_f64(res), dest)?;
+            }
+            sym::float_to_int_unchecked => {
+                let val = self.read_scalar(&args[0])?;
+                let res = match args[0].layout.ty.kind() {
+                    ty::Float(FloatTy::F32) => {
+                        self.float_to_int_unchecked(val.to_f32()?, dest.layout.ty)?
+                    }
+                    ty::Float(FloatTy::F64) => {
+                        self.float_to_int_unchecked(val.to_f64()?, dest.layout.ty)?
+                    }
+                    _ => span_bug!(
+                        self.cur_span(),
+                        "`float_to_int_unchecked` called with non-float input type {:?}",
+                        args[0].layout.ty
+                    ),
+                };
+                self.write_scalar(res, dest)?;
+            }
+            sym::ptr_metadata => {
+                let ptr = self.read_immediate(&args[0])?;
+                match *ptr {
+                    Immediate::ScalarPair(_data, meta) => self.write_scalar(meta, dest)?,
+                    // Thin pointers have `()` as their metadata, so there is nothing to write.
+                    Immediate::Scalar(_) => assert!(dest.layout.is_zst()),
+                    Immediate::Uninit => bug!("`read_immediate` never returns `Uninit`"),
+                }
+            }
+            sym::aggregate_raw_ptr => {
+                let data = self.read_pointer(&args[0])?;
+                let data = Scalar::from_maybe_pointer(data, self);
+                let meta = &args[1];
+                match dest.layout.abi {
+                    Abi::Scalar(_) if meta.layout.is_zst() => self.write_scalar(data, dest)?,
+                    Abi::ScalarPair(..) if !meta.layout.is_zst() => {
+                        let meta = self.read_scalar(meta)?;
+                        self.write_immediate(Immediate::ScalarPair(data, meta), dest)?;
+                    }
+                    _ => span_bug!(
+                        self.cur_span(),
+                        "invalid `aggregate_raw_ptr` of {:?} from metadata {:?}",
+                        dest.layout.ty,
+                        meta.layout.ty,
+                    ),
+                }
+            }
             sym::vtable_size => {
                 let ptr = self.read_pointer(&args[0])?;
-                let (size, _align) = self.get_vtable_size_and_align(ptr)?;
+                let (size, _align) = self.get_vtable_size_and_align(ptr, None)?;
                 self.write_scalar(Scalar::from_machine_usize(size.bytes(), self), dest)?;
             }
             sym::vtable_align => {
                 let ptr = self.read_pointer(&args[0])?;
-                let (_size, align) = self.get_vtable_size_and_align(ptr)?;
+                let (_size, align) = self.get_vtable_size_and_align(ptr, None)?;
                 self.write_scalar(Scalar::from_machine_usize(align.bytes(), self), dest)?;
             }
 
+            sym::breakpoint => M::breakpoint(self)?,
+
+            sym::ptr_guaranteed_cmp => {
+                let a = self.read_scalar(&args[0])?;
+                let b = self.read_scalar(&args[1])?;
+                let cmp = self.guaranteed_cmp(a, b)?;
+                self.write_scalar(Scalar::from_u8(cmp), dest)?;
+            }
+
+            kw::Try => {
+                M::handle_catch_unwind(self, &args[0], &args[1], &args[2], dest, ret)?;
+                // The machine already arranged for execution to continue at `ret`.
+                return Ok(true);
+            }
+
+            _ if intrinsic_name.as_str().starts_with("atomic_") => {
+                if !M::emulate_atomic_intrinsic(self, intrinsic_name, args, dest)? {
+                    self.atomic_intrinsic(intrinsic_name, args, dest)?;
+                }
+            }
+
             _ => return Ok(false),
         }
 
@@ -556,6 +1167,237 @@ pub(super) fn emulate_nondiverging_intrinsic(
         }
     }
 
+    /// Converts `f` to the integer type `dest_ty`, rounding towards zero. It is UB if `f` is NaN,
+    /// infinite, or out of range for `dest_ty` after rounding.
+    fn float_to_int_unchecked<F: Float>(
+        &self,
+        f: F,
+        dest_ty: Ty<'tcx>,
+    ) -> InterpResult<'tcx, Scalar<M::Provenance>> {
+        let size = self.layout_of(dest_ty)?.size;
+        // Round first so that the conversion below is exact for all valid inputs; any status flag
+        // raised by it then means that `f` cannot be represented in the target type.
+        let truncated = f.round_to_integral(Round::TowardZero).value;
+        let res = match dest_ty.kind() {
+            ty::Int(_) => {
+                let res = truncated.to_i128(size.bits_usize());
+                res.status.is_empty().then(|| Scalar::from_int(res.value, size))
+            }
+            ty::Uint(_) => {
+                let res = truncated.to_u128(size.bits_usize());
+                res.status.is_empty().then(|| Scalar::from_uint(res.value, size))
+            }
+            _ => span_bug!(
+                self.cur_span(),
+                "`float_to_int_unchecked` called with non-int output type {:?}",
+                dest_ty
+            ),
+        };
+        match res {
+            Some(res) => Ok(res),
+            None => throw_ub_format!(
+                "`float_to_int_unchecked` intrinsic called on {} which cannot be represented in \
+                 target type `{:?}`",
+                f,
+                dest_ty
+            ),
+        }
+    }
+
+    /// Returns the larger (if `is_max`) or smaller of `l` and `r`, which must be integers or
+    /// floats of the same type. For floats, this follows IEEE 754 `maxNum`/`minNum`: if one of the
+    /// inputs is NaN, the other one is returned.
+    pub fn min_max_op(
+        &self,
+        is_max: bool,
+        l: &ImmTy<'tcx, M::Provenance>,
+        r: &ImmTy<'tcx, M::Provenance>,
+    ) -> InterpResult<'tcx, Scalar<M::Provenance>> {
+        assert_eq!(l.layout.ty, r.layout.ty);
+        Ok(match l.layout.ty.kind() {
+            ty::Float(FloatTy::F32) => {
+                let (l, r) = (l.to_scalar().to_f32()?, r.to_scalar().to_f32()?);
+                Scalar::from_f32(if is_max { l.max(r) } else { l.min(r) })
+            }
+            ty::Float(FloatTy::F64) => {
+                let (l, r) = (l.to_scalar().to_f64()?, r.to_scalar().to_f64()?);
+                Scalar::from_f64(if is_max { l.max(r) } else { l.min(r) })
+            }
+            _ => {
+                let cmp_op = if is_max { BinOp::Ge } else { BinOp::Le };
+                let keep_left = self.binary_op(cmp_op, l, r)?.to_scalar().to_bool()?;
+                if keep_left {
+                    l.to_scalar()
+                } else {
+                    r.to_scalar()
+                }
+            }
+        })
+    }
+
+    /// Returns the place of one active lane of a SIMD memory intrinsic (`simd_gather`,
+    /// `simd_masked_load`, ...). Like the hardware instructions, these require every accessed
+    /// element to be in bounds and aligned, even on machines that do not check alignment
+    /// otherwise.
+    fn simd_lane_mplace(
+        &self,
+        ptr: Pointer<Option<M::Provenance>>,
+        layout: TyAndLayout<'tcx>,
+    ) -> InterpResult<'tcx, MPlaceTy<'tcx, M::Provenance>> {
+        self.check_ptr_access_align(
+            ptr,
+            layout.size,
+            layout.align.abi,
+            CheckInAllocMsg::MemoryAccessTest,
+        )?;
+        Ok(MPlaceTy::from_aligned_ptr(ptr, layout))
+    }
+
+    /// Compares two pointers for the `ptr_guaranteed_cmp` intrinsic: returns `1` if they are
+    /// guaranteed to be equal at runtime, `0` if they are guaranteed to be unequal, and `2` if
+    /// that depends on where allocations end up.
+    fn guaranteed_cmp(
+        &self,
+        a: Scalar<M::Provenance>,
+        b: Scalar<M::Provenance>,
+    ) -> InterpResult<'tcx, u8> {
+        Ok(match (a, b) {
+            // Comparisons between integers are always known.
+            (Scalar::Int { .. }, Scalar::Int { .. }) => u8::from(a == b),
+            // Comparisons of abstract pointers with null pointers are known if the pointer
+            // is in bounds, because if they are in bounds, the pointer can't be null.
+            // Inequality with integers other than null can never be known for sure.
+            (Scalar::Int(int), ptr @ Scalar::Ptr(..))
+            | (ptr @ Scalar::Ptr(..), Scalar::Int(int))
+                if int.is_null() && !self.scalar_may_be_null(ptr)? =>
+            {
+                0
+            }
+            // Equality with integers can never be known for sure.
+            (Scalar::Int { .. }, Scalar::Ptr(..)) | (Scalar::Ptr(..), Scalar::Int { .. }) => 2,
+            (Scalar::Ptr(a, _), Scalar::Ptr(b, _)) => {
+                match (self.ptr_try_get_alloc_id(a.into()), self.ptr_try_get_alloc_id(b.into())) {
+                    // Different offsets strictly inside the same allocation are never equal,
+                    // even if the allocation gets duplicated at runtime (as happens for the
+                    // memory of constants): the copies do not overlap.
+                    (Ok((a_id, a_offset, _)), Ok((b_id, b_offset, _)))
+                        if a_id == b_id && a_offset != b_offset =>
+                    {
+                        let (size, _align, kind) = self.get_alloc_info(a_id);
+                        if matches!(kind, AllocKind::LiveData) && a_offset < size && b_offset < size
+                        {
+                            0
+                        } else {
+                            2
+                        }
+                    }
+                    // FIXME: return a `1` for when both sides are the same pointer, *except* that
+                    // some things (like functions and vtables) do not have stable addresses
+                    // so we need to be careful around them (see e.g. #73722).
+                    // Pointers with different provenance depend on the runtime layout of
+                    // allocations.
+                    _ => 2,
+                }
+            }
+        })
+    }
+
+    /// Calls `try_fn(data)` for the `catch_unwind` intrinsic, writing `0` to `dest` and continuing
+    /// at `ret` once it returns. `unwind` says what happens when `try_fn` unwinds; machines that
+    /// catch the panic have to call `catch_fn` themselves when that frame is popped.
+    pub fn call_catch_unwind_try_fn(
+        &mut self,
+        try_fn: &OpTy<'tcx, M::Provenance>,
+        data: &OpTy<'tcx, M::Provenance>,
+        dest: &PlaceTy<'tcx, M::Provenance>,
+        ret: mir::BasicBlock,
+        unwind: StackPopUnwind,
+    ) -> InterpResult<'tcx> {
+        let ty::FnPtr(sig) = *try_fn.layout.ty.kind() else {
+            span_bug!(self.cur_span(), "`try` called with non-fn-ptr {}", try_fn.layout.ty)
+        };
+        let fn_val = self.get_ptr_fn(self.read_pointer(try_fn)?)?;
+        let fn_abi = self.fn_abi_of_fn_ptr(sig, ty::List::empty())?;
+
+        // We return `0` unless a panic gets caught, in which case the machine overwrites this.
+        self.write_scalar(Scalar::from_i32(0), dest)?;
+        let unit = MPlaceTy::fake_alloc_zst(self.layout_of(self.tcx.types.unit)?);
+        self.eval_fn_call(
+            fn_val,
+            (CallAbi::Rust, &fn_abi),
+            &[data.clone()],
+            /*with_caller_location*/ false,
+            &unit.into(),
+            Some(ret),
+            unwind,
+        )
+    }
+
+    /// Performs an `atomic_*` intrinsic with plain sequential semantics: since the interpreter
+    /// only runs a single thread, the orderings in the name make no difference. Machines that
+    /// model concurrency can take over via `Machine::emulate_atomic_intrinsic`.
+    pub fn atomic_intrinsic(
+        &mut self,
+        intrinsic_name: Symbol,
+        args: &[OpTy<'tcx, M::Provenance>],
+        dest: &PlaceTy<'tcx, M::Provenance>,
+    ) -> InterpResult<'tcx> {
+        let name = intrinsic_name.as_str();
+        // The name is `atomic_<op>_<ordering>`, with a second ordering for `cxchg`.
+        let op = name["atomic_".len()..].split('_').next().unwrap();
+        match op {
+            // Without other threads, fences do nothing.
+            "fence" | "singlethreadfence" => {}
+            "load" => {
+                let place = self.deref_operand(&args[0])?;
+                self.copy_op(&place.into(), dest, /*allow_transmute*/ false)?;
+            }
+            "store" => {
+                let place = self.deref_operand(&args[0])?;
+                self.copy_op(&args[1], &place.into(), /*allow_transmute*/ false)?;
+            }
+            "cxchg" | "cxchgweak" => {
+                // The weak version is allowed to fail spuriously, but we never do.
+                let place = self.deref_operand(&args[0])?;
+                let old = self.read_immediate(&place.into())?;
+                let expected = self.read_immediate(&args[1])?;
+                let eq = self.binary_op(BinOp::Eq, &old, &expected)?.to_scalar().to_bool()?;
+                if eq {
+                    self.copy_op(&args[2], &place.into(), /*allow_transmute*/ false)?;
+                }
+                let old_dest = self.place_field(dest, 0)?;
+                self.write_immediate(*old, &old_dest)?;
+                let eq_dest = self.place_field(dest, 1)?;
+                self.write_scalar(Scalar::from_bool(eq), &eq_dest)?;
+            }
+            _ => {
+                let place = self.deref_operand(&args[0])?;
+                let old = self.read_immediate(&place.into())?;
+                let rhs = self.read_immediate(&args[1])?;
+                let new = match op {
+                    "xchg" => *rhs,
+                    "xadd" => *self.binary_op(BinOp::Add, &old, &rhs)?,
+                    "xsub" => *self.binary_op(BinOp::Sub, &old, &rhs)?,
+                    "and" => *self.binary_op(BinOp::BitAnd, &old, &rhs)?,
+                    "or" => *self.binary_op(BinOp::BitOr, &old, &rhs)?,
+                    "xor" => *self.binary_op(BinOp::BitXor, &old, &rhs)?,
+                    "nand" => {
+                        let and = self.binary_op(BinOp::BitAnd, &old, &rhs)?;
+                        *self.unary_op(mir::UnOp::Not, &and)?
+                    }
+                    // The signedness of the operands decides between signed and unsigned
+                    // comparison, so `max` and `umax` only differ in the types they accept.
+                    "max" | "umax" => self.min_max_op(true, &old, &rhs)?.into(),
+                    "min" | "umin" => self.min_max_op(false, &old, &rhs)?.into(),
+                    _ => throw_unsup_format!("unimplemented atomic intrinsic: `{intrinsic_name}`"),
+                };
+                self.write_immediate(new, &place.into())?;
+                self.write_immediate(*old, dest)?;
+            }
+        }
+        Ok(())
+    }
+
     pub fn exact_div(
         &mut self,
         a: &ImmTy<'tcx, M::Provenance>,
@@ -617,6 +1459,36 @@ pub fn saturating_arith(
         })
     }
 
+    /// Clears the address bits of `ptr` that are not set in `mask`, preserving its provenance.
+    ///
+    /// If the machine does not know the absolute address of an allocation, only the address bits
+    /// below the allocation's alignment are known, so only those may be cleared.
+    pub fn ptr_mask(
+        &self,
+        ptr: Pointer<Option<M::Provenance>>,
+        mask: u64,
+    ) -> InterpResult<'tcx, Pointer<Option<M::Provenance>>> {
+        let cleared_bits = !mask & self.machine_usize_max();
+        let addr = match self.ptr_try_get_alloc_id(ptr) {
+            Ok((alloc_id, offset, _)) if !M::Provenance::OFFSET_IS_ADDR => {
+                let (_size, align, _kind) = self.get_alloc_info(alloc_id);
+                if cleared_bits >= align.bytes() {
+                    throw_unsup_format!(
+                        "`ptr_mask` can only clear address bits below the alignment of the \
+                         allocation ({} bytes) at compile-time",
+                        align.bytes()
+                    );
+                }
+                offset.bytes()
+            }
+            Ok(_) => ptr.addr().bytes(),
+            Err(addr) => addr,
+        };
+        // Subtract the cleared bits (with wrapping arithmetic), which keeps the provenance intact.
+        let delta = (addr & cleared_bits).wrapping_neg() & self.machine_usize_max();
+        Ok(ptr.wrapping_offset(Size::from_bytes(delta), self))
+    }
+
     /// Offsets a pointer by some multiple of its type, returning an error if the pointer leaves its
     /// allocation. For integer pointers, we consider each of them their own tiny allocation of size
     /// 0, so offset-by-0 (and only 0) is okay -- except that null cannot be offset by _any_ value.
@@ -676,6 +1548,26 @@ pub(crate) fn copy_intrinsic(
         self.mem_copy(src, align, dst, align, size, nonoverlapping)
     }
 
+    pub(crate) fn typed_swap_nonoverlapping_intrinsic(
+        &mut self,
+        left: &OpTy<'tcx, <M as Machine<'mir, 'tcx>>::Provenance>,
+        right: &OpTy<'tcx, <M as Machine<'mir, 'tcx>>::Provenance>,
+    ) -> InterpResult<'tcx> {
+        let left = self.deref_operand(left)?;
+        let right = self.deref_operand(right)?;
+        assert_eq!(left.layout, right.layout);
+        assert!(!left.layout.is_unsized());
+
+        // This is a typed swap, so both values have to be valid before we move them around.
+        if M::enforce_validity(self) {
+            self.validate_operand(&left.into())?;
+            self.validate_operand(&right.into())?;
+        }
+
+        // Swap the bytes in place; this also checks that the two places do not overlap.
+        self.mem_swap_nonoverlapping(left.ptr, right.ptr, left.align, left.layout.size)
+    }
+
     pub(crate) fn write_bytes_intrinsic(
         &mut self,
         dst: &OpTy<'tcx, <M as Machine<'mir, 'tcx>>::Provenance>,
@@ -695,8 +1587,12 @@ pub(crate) fn write_bytes_intrinsic(
             .checked_mul(count, self)
             .ok_or_else(|| err_ub_format!("overflow computing total size of `write_bytes`"))?;
 
-        let bytes = std::iter::repeat(byte).take(len.bytes_usize());
-        self.write_bytes_ptr(dst, bytes)
+        // Fill the whole range at once instead of going byte-by-byte.
+        let Some(mut alloc_ref) = self.get_ptr_alloc_mut(dst, len, Align::ONE)? else {
+            // zero-sized access
+            return Ok(());
+        };
+        alloc_ref.write_repeated_byte(byte)
     }
 
     pub(crate) fn raw_eq_intrinsic(
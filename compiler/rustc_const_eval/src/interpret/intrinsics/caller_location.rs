@@ -113,7 +113,8 @@ pub(crate) fn alloc_caller_location(
         location
     }
 
-    pub(crate) fn location_triple_for_span(&self, span: Span) -> (Symbol, u32, u32) {
+    /// The default implementation of `Machine::caller_location`.
+    pub fn location_triple_for_span(&self, span: Span) -> (Symbol, u32, u32) {
         let topmost = span.ctxt().outer_expn().expansion_cause().unwrap_or(span);
         let caller = self.tcx.sess.source_map().lookup_char_pos(topmost.lo());
         (
@@ -124,7 +125,7 @@ pub(crate) fn location_triple_for_span(&self, span: Span) -> (Symbol, u32, u32)
     }
 
     pub fn alloc_caller_location_for_span(&mut self, span: Span) -> MPlaceTy<'tcx, M::Provenance> {
-        let (file, line, column) = self.location_triple_for_span(span);
+        let (file, line, column) = M::caller_location(self, span);
         self.alloc_caller_location(file, line, column)
     }
 }
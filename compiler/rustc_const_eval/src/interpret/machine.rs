@@ -10,12 +10,13 @@
 use rustc_middle::mir;
 use rustc_middle::ty::{self, Ty, TyCtxt};
 use rustc_span::def_id::DefId;
-use rustc_target::abi::Size;
+use rustc_span::{Span, Symbol};
+use rustc_target::abi::{Align, Size};
 use rustc_target::spec::abi::Abi as CallAbi;
 
 use super::{
     AllocId, AllocRange, Allocation, ConstAllocation, Frame, ImmTy, InterpCx, InterpResult,
-    MemoryKind, OpTy, Operand, PlaceTy, Pointer, Provenance, Scalar, StackPopUnwind,
+    MPlaceTy, MemoryKind, OpTy, Operand, PlaceTy, Pointer, Provenance, Scalar, StackPopUnwind,
 };
 
 /// Data returned by Machine::stack_pop,
@@ -33,6 +34,15 @@ pub enum StackPopJump {
     NoJump,
 }
 
+/// Operations that strict provenance does not allow, see `Machine::strict_provenance_violation`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ProvenanceViolation {
+    /// An integer was cast to a pointer with `as`.
+    IntToPtrCast,
+    /// An integer was transmuted to a pointer.
+    IntToPtrTransmute,
+}
+
 /// Whether this kind of memory is allowed to leak
 pub trait MayLeak: Copy {
     fn may_leak(self) -> bool;
@@ -141,6 +151,21 @@ fn enforce_abi(_ecx: &InterpCx<'mir, 'tcx, Self>) -> bool {
     /// Whether CheckedBinOp MIR statements should actually check for overflow.
     fn checked_binop_checks_overflow(_ecx: &InterpCx<'mir, 'tcx, Self>) -> bool;
 
+    /// Determines the result of the `ub_checks` intrinsic, i.e. whether library UB checks that
+    /// are normally tied to `debug_assertions` should be performed.
+    fn ub_checks(ecx: &InterpCx<'mir, 'tcx, Self>) -> InterpResult<'tcx, bool>;
+
+    /// Determines the result of the `is_val_statically_known` intrinsic for `val`. Any answer is
+    /// sound, since the intrinsic must not affect program behavior; by default we never claim to
+    /// know the value.
+    #[inline(always)]
+    fn is_val_statically_known(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        _val: &OpTy<'tcx, Self::Provenance>,
+    ) -> InterpResult<'tcx, bool> {
+        Ok(false)
+    }
+
     /// Entry point for obtaining the MIR of anything that should get evaluated.
     /// So not just functions and shims, but also const/static initializers, anonymous
     /// constants, ...
@@ -194,6 +219,96 @@ fn call_intrinsic(
         unwind: StackPopUnwind,
     ) -> InterpResult<'tcx>;
 
+    /// Called before an intrinsic call is handed to `call_intrinsic`, with the intrinsic's name and
+    /// its argument operands (scalar arguments can be read with `InterpCx::read_immediate`).
+    /// This does not affect evaluation; it exists so that profilers and tracing machines can
+    /// attribute evaluation time to individual intrinsics.
+    #[inline(always)]
+    fn before_intrinsic(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        _intrinsic_name: Symbol,
+        _args: &[OpTy<'tcx, Self::Provenance>],
+    ) -> InterpResult<'tcx> {
+        Ok(())
+    }
+
+    /// Called after `call_intrinsic` returned, with the same name and arguments as
+    /// `before_intrinsic`. This also happens if the intrinsic failed; in that case `destination`
+    /// may not hold a result, and the intrinsic's error is reported instead of any error returned
+    /// here. Intrinsics that call a function, like `try`, only push that function's frame, so
+    /// this hook runs before its body is evaluated.
+    #[inline(always)]
+    fn after_intrinsic(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        _intrinsic_name: Symbol,
+        _args: &[OpTy<'tcx, Self::Provenance>],
+        _destination: &PlaceTy<'tcx, Self::Provenance>,
+    ) -> InterpResult<'tcx> {
+        Ok(())
+    }
+
+    /// Called by `emulate_intrinsic` before it looks at the intrinsic, so that machines can
+    /// override or observe individual intrinsics while still relying on the shared emulation
+    /// for everything else.
+    ///
+    /// Returns `true` if the intrinsic was fully handled, in which case the shared emulation is
+    /// skipped. Like in `call_intrinsic`, it is then the hook's responsibility to advance the
+    /// instruction pointer as appropriate.
+    #[inline(always)]
+    fn before_emulate_intrinsic(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        _instance: ty::Instance<'tcx>,
+        _args: &[OpTy<'tcx, Self::Provenance>],
+        _destination: &PlaceTy<'tcx, Self::Provenance>,
+        _target: Option<mir::BasicBlock>,
+    ) -> InterpResult<'tcx, bool> {
+        Ok(false)
+    }
+
+    /// Called by `emulate_intrinsic` for `atomic_*` intrinsics, which are otherwise emulated with
+    /// plain sequential semantics by `InterpCx::atomic_intrinsic`. Machines that model concurrency
+    /// can perform the operation themselves and return `true` to skip the default emulation.
+    /// Unlike `before_emulate_intrinsic`, the instruction pointer is advanced by the caller.
+    #[inline(always)]
+    fn emulate_atomic_intrinsic(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        _intrinsic_name: Symbol,
+        _args: &[OpTy<'tcx, Self::Provenance>],
+        _dest: &PlaceTy<'tcx, Self::Provenance>,
+    ) -> InterpResult<'tcx, bool> {
+        Ok(false)
+    }
+
+    /// Determines the file, line and column of the `core::panic::Location` that the
+    /// `caller_location` intrinsic returns for `span`. By default this comes from the source map,
+    /// honoring `--remap-path-prefix` and `-Zlocation-detail`; machines can remap or synthesize
+    /// locations instead, e.g. to strip workspace prefixes.
+    #[inline(always)]
+    fn caller_location(ecx: &InterpCx<'mir, 'tcx, Self>, span: Span) -> (Symbol, u32, u32) {
+        ecx.location_triple_for_span(span)
+    }
+
+    /// Determines the result of `size_of_val` and `min_align_of_val` for a place whose type has
+    /// an `extern type` tail, which the interpreter cannot compute by itself. Machines that know
+    /// the layout of some foreign types can answer here.
+    #[inline(always)]
+    fn extern_type_size_and_align(
+        _ecx: &InterpCx<'mir, 'tcx, Self>,
+        _place: &MPlaceTy<'tcx, Self::Provenance>,
+    ) -> InterpResult<'tcx, (Size, Align)> {
+        throw_unsup_format!("`extern type` does not have known layout")
+    }
+
+    /// Called when the program creates a pointer from a non-zero integer, which strict provenance
+    /// does not allow. Machines can use this to point out code that needs to be migrated.
+    #[inline(always)]
+    fn strict_provenance_violation(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        _violation: ProvenanceViolation,
+    ) -> InterpResult<'tcx> {
+        Ok(())
+    }
+
     /// Called to evaluate `Assert` MIR terminators that trigger a panic.
     fn assert_panic(
         ecx: &mut InterpCx<'mir, 'tcx, Self>,
@@ -201,11 +316,32 @@ fn assert_panic(
         unwind: Option<mir::BasicBlock>,
     ) -> InterpResult<'tcx>;
 
+    /// Called to evaluate the `catch_unwind` intrinsic (`try`), which calls `try_fn(data)` and
+    /// then `catch_fn(data, payload)` if that unwinds. The hook has to make execution continue at
+    /// `ret`, usually via `call_catch_unwind_try_fn`. The default implementation cannot catch
+    /// anything: unwinding out of `try_fn` is UB.
+    fn handle_catch_unwind(
+        ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        try_fn: &OpTy<'tcx, Self::Provenance>,
+        data: &OpTy<'tcx, Self::Provenance>,
+        _catch_fn: &OpTy<'tcx, Self::Provenance>,
+        dest: &PlaceTy<'tcx, Self::Provenance>,
+        ret: mir::BasicBlock,
+    ) -> InterpResult<'tcx> {
+        ecx.call_catch_unwind_try_fn(try_fn, data, dest, ret, StackPopUnwind::NotAllowed)
+    }
+
     /// Called to evaluate `Abort` MIR terminator.
     fn abort(_ecx: &mut InterpCx<'mir, 'tcx, Self>, _msg: String) -> InterpResult<'tcx, !> {
         throw_unsup_format!("aborting execution is not supported")
     }
 
+    /// Called to evaluate the `breakpoint` intrinsic. Machines with debugger integration can stop
+    /// execution here; by default hitting a breakpoint is an error.
+    fn breakpoint(_ecx: &mut InterpCx<'mir, 'tcx, Self>) -> InterpResult<'tcx> {
+        throw_unsup_format!("hitting a breakpoint is not supported")
+    }
+
     /// Called for all binary operations where the LHS has pointer type.
     ///
     /// Returns a (value, overflowed) pair if the operation succeeded
@@ -361,6 +497,17 @@ fn before_memory_write(
         Ok(())
     }
 
+    /// Hook for performing extra checks before memory for a new allocation is reserved.
+    #[inline(always)]
+    fn before_memory_allocation(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        _size: Size,
+        _align: Align,
+        _kind: MemoryKind<Self::MemoryKind>,
+    ) -> InterpResult<'tcx> {
+        Ok(())
+    }
+
     /// Hook for performing extra operations on a memory deallocation.
     #[inline(always)]
     fn before_memory_deallocation(
@@ -443,6 +590,12 @@ fn checked_binop_checks_overflow(_ecx: &InterpCx<$mir, $tcx, Self>) -> bool {
         true
     }
 
+    #[inline(always)]
+    fn ub_checks(ecx: &InterpCx<$mir, $tcx, Self>) -> InterpResult<$tcx, bool> {
+        // Match what codegen would do for the current crate.
+        Ok(ecx.tcx.sess.opts.debug_assertions)
+    }
+
     #[inline(always)]
     fn call_extra_fn(
         _ecx: &mut InterpCx<$mir, $tcx, Self>,
@@ -10,17 +10,19 @@
 use std::borrow::Cow;
 use std::collections::VecDeque;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::ptr;
 
 use rustc_ast::Mutability;
-use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxHasher};
 use rustc_middle::mir::display_allocation;
 use rustc_middle::ty::{self, Instance, ParamEnv, Ty, TyCtxt};
 use rustc_target::abi::{Align, HasDataLayout, Size};
 
 use super::{
-    alloc_range, AllocId, AllocMap, AllocRange, Allocation, CheckInAllocMsg, GlobalAlloc, InterpCx,
-    InterpResult, Machine, MayLeak, Pointer, PointerArithmetic, Provenance, Scalar,
+    alloc_range, AllocId, AllocMap, AllocRange, Allocation, CheckInAllocMsg, GlobalAlloc,
+    Immediate, InterpCx, InterpResult, LocalValue, Machine, MayLeak, MemPlace, MemPlaceMeta,
+    Operand, Place, Pointer, PointerArithmetic, Provenance, Scalar, SharedBytes,
 };
 
 #[derive(Debug, PartialEq, Copy, Clone)]
@@ -107,6 +109,29 @@ pub struct Memory<'mir, 'tcx, M: Machine<'mir, 'tcx>> {
     /// that do not exist any more.
     // FIXME: this should not be public, but interning currently needs access to it
     pub(super) dead_alloc_map: FxHashMap<AllocId, (Size, Align)>,
+
+    /// The local allocations that were created or written to since the last call to
+    /// `deduplicate_allocation_bytes`, or `None` if it was never called.
+    changed_allocs: Option<FxHashSet<AllocId>>,
+
+    /// The hashes of the bytes of the local allocations that `deduplicate_allocation_bytes`
+    /// considers for sharing, so that it does not have to hash unchanged allocations again.
+    bytes_hashes: FxHashMap<AllocId, u64>,
+}
+
+impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> Clone for Memory<'mir, 'tcx, M>
+where
+    M::MemoryMap: Clone,
+{
+    fn clone(&self) -> Self {
+        Memory {
+            alloc_map: self.alloc_map.clone(),
+            extra_fn_ptr_map: self.extra_fn_ptr_map.clone(),
+            dead_alloc_map: self.dead_alloc_map.clone(),
+            changed_allocs: self.changed_allocs.clone(),
+            bytes_hashes: self.bytes_hashes.clone(),
+        }
+    }
 }
 
 /// A reference to some allocation that was already bounds-checked for the given region
@@ -133,6 +158,8 @@ pub fn new() -> Self {
             alloc_map: M::MemoryMap::default(),
             extra_fn_ptr_map: FxHashMap::default(),
             dead_alloc_map: FxHashMap::default(),
+            changed_allocs: None,
+            bytes_hashes: FxHashMap::default(),
         }
     }
 
@@ -140,6 +167,15 @@ pub fn new() -> Self {
     pub fn alloc_map(&self) -> &M::MemoryMap {
         &self.alloc_map
     }
+
+    /// Records that the bytes of `id` might have changed, once `deduplicate_allocation_bytes` is
+    /// in use.
+    #[inline]
+    fn mark_changed(&mut self, id: AllocId) {
+        if let Some(changed_allocs) = &mut self.changed_allocs {
+            changed_allocs.insert(id);
+        }
+    }
 }
 
 impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
@@ -197,6 +233,7 @@ pub fn allocate_ptr(
         align: Align,
         kind: MemoryKind<M::MemoryKind>,
     ) -> InterpResult<'tcx, Pointer<M::Provenance>> {
+        M::before_memory_allocation(self, size, align, kind)?;
         let alloc = Allocation::uninit(size, align, M::PANIC_ON_ALLOC_FAIL)?;
         // We can `unwrap` since `alloc` contains no pointers.
         Ok(self.allocate_raw_ptr(alloc, kind).unwrap())
@@ -228,6 +265,7 @@ pub fn allocate_raw_ptr(
         );
         let alloc = M::adjust_allocation(self, id, Cow::Owned(alloc), Some(kind))?;
         self.memory.alloc_map.insert(id, (kind, alloc.into_owned()));
+        self.memory.mark_changed(id);
         Ok(M::adjust_alloc_base_pointer(self, Pointer::from(id)))
     }
 
@@ -619,6 +657,7 @@ fn get_alloc_raw_mut(
             self.memory.alloc_map.insert(id, (MemoryKind::Machine(kind), alloc.into_owned()));
         }
 
+        self.memory.mark_changed(id);
         let (_kind, alloc) = self.memory.alloc_map.get_mut(id).unwrap();
         if alloc.mutability == Mutability::Not {
             throw_ub!(WriteToReadOnly(id))
@@ -815,6 +854,131 @@ pub fn leak_report(&self, static_roots: &[AllocId]) -> usize {
         }
         n
     }
+
+    /// Forget about deallocated allocations that nothing points to any more. The size and
+    /// alignment of every deallocated allocation are remembered in case a dangling pointer to it
+    /// gets used, so evaluations that keep allocating and freeing memory would otherwise grow
+    /// without bound. Machines can call this periodically.
+    ///
+    /// Pointers stored in memory or in the locals and return places of the stack are found
+    /// automatically. `extra_roots` must contain all allocations the machine itself might still
+    /// point to.
+    pub fn remove_unreachable_dead_allocs(
+        &mut self,
+        extra_roots: impl IntoIterator<Item = AllocId>,
+    ) {
+        if self.memory.dead_alloc_map.is_empty() {
+            return;
+        }
+
+        let mut reachable: FxHashSet<AllocId> = extra_roots.into_iter().collect();
+        let add_scalar = |reachable: &mut FxHashSet<_>, scalar: Scalar<M::Provenance>| {
+            if let Scalar::Ptr(ptr, _) = scalar {
+                reachable.extend(ptr.provenance.get_alloc_id());
+            }
+        };
+        let add_mplace = |reachable: &mut FxHashSet<_>, mplace: MemPlace<M::Provenance>| {
+            reachable.extend(mplace.ptr.provenance.and_then(Provenance::get_alloc_id));
+            if let MemPlaceMeta::Meta(meta) = mplace.meta {
+                add_scalar(reachable, meta);
+            }
+        };
+        for frame in self.stack() {
+            for local in &frame.locals {
+                match local.value {
+                    LocalValue::Dead | LocalValue::Live(Operand::Immediate(Immediate::Uninit)) => {}
+                    LocalValue::Live(Operand::Immediate(Immediate::Scalar(val))) => {
+                        add_scalar(&mut reachable, val);
+                    }
+                    LocalValue::Live(Operand::Immediate(Immediate::ScalarPair(val1, val2))) => {
+                        add_scalar(&mut reachable, val1);
+                        add_scalar(&mut reachable, val2);
+                    }
+                    LocalValue::Live(Operand::Indirect(mplace)) => {
+                        add_mplace(&mut reachable, mplace);
+                    }
+                }
+            }
+            if let Place::Ptr(mplace) = *frame.return_place {
+                add_mplace(&mut reachable, mplace);
+            }
+        }
+        // Global allocations cannot point to allocations that only exist in this interpreter's
+        // memory, so only the local allocations need to be scanned.
+        let dead_alloc_map = &self.memory.dead_alloc_map;
+        reachable.extend(
+            self.memory
+                .alloc_map
+                .filter_map_collect(|_, (_, alloc)| {
+                    let dead: Vec<_> = alloc
+                        .provenance()
+                        .values()
+                        .filter_map(|prov| prov.get_alloc_id())
+                        .filter(|id| dead_alloc_map.contains_key(id))
+                        .collect();
+                    if dead.is_empty() { None } else { Some(dead) }
+                })
+                .into_iter()
+                .flatten(),
+        );
+
+        self.memory.dead_alloc_map.retain(|id, _| reachable.contains(id));
+        self.memory.dead_alloc_map.shrink_to_fit();
+    }
+
+    /// Make local allocations with identical bytes share a single buffer, so that e.g. a large
+    /// table that gets computed over and over only takes up memory once. A buffer gets copied
+    /// again as soon as one of the allocations sharing it is written to. Machines can call this
+    /// periodically.
+    ///
+    /// The first call hashes the bytes of all local allocations. After that, only allocations
+    /// that were created or written to since the previous call are hashed again.
+    pub fn deduplicate_allocation_bytes(&mut self) {
+        // Sharing small allocations is not worth the effort.
+        const MIN_SIZE: usize = 256;
+
+        let changed = match self.memory.changed_allocs.replace(FxHashSet::default()) {
+            Some(changed) => changed,
+            None => {
+                self.memory.alloc_map.filter_map_collect(|&id, _| Some(id)).into_iter().collect()
+            }
+        };
+        for id in changed {
+            match self.memory.alloc_map.get_mut(id) {
+                Some((_, alloc)) if alloc.len() >= MIN_SIZE => {
+                    let mut hasher = FxHasher::default();
+                    alloc
+                        .inspect_with_uninit_and_ptr_outside_interpreter(0..alloc.len())
+                        .hash(&mut hasher);
+                    self.memory.bytes_hashes.insert(id, hasher.finish());
+                }
+                _ => {
+                    self.memory.bytes_hashes.remove(&id);
+                }
+            }
+        }
+        // Deallocated and interned allocations are not marked as changed.
+        self.memory.bytes_hashes.retain(|&id, _| self.memory.alloc_map.contains_key(&id));
+
+        // Only allocations whose bytes hash the same can be equal.
+        let mut candidates: FxHashMap<u64, Vec<AllocId>> = FxHashMap::default();
+        for (&id, &hash) in &self.memory.bytes_hashes {
+            candidates.entry(hash).or_default().push(id);
+        }
+
+        for ids in candidates.values().filter(|ids| ids.len() > 1) {
+            // Different bytes can still end up with the same hash, so there might be several
+            // distinct buffers in one group. Allocations that already share a buffer are not
+            // compared again.
+            let mut buffers: Vec<SharedBytes> = Vec::new();
+            for &id in ids {
+                let (_, alloc) = self.memory.alloc_map.get_mut(id).unwrap();
+                if !buffers.iter().any(|bytes| alloc.dedup_bytes(bytes)) {
+                    buffers.push(alloc.share_bytes());
+                }
+            }
+        }
+    }
 }
 
 #[doc(hidden)]
@@ -836,7 +1000,11 @@ fn write_allocation_track_relocs<'tcx, Prov: Provenance, Extra>(
             for alloc_id in alloc.provenance().values().filter_map(|prov| prov.get_alloc_id()) {
                 allocs_to_print.push_back(alloc_id);
             }
-            write!(fmt, "{}", display_allocation(tcx, alloc))
+            if fmt.alternate() {
+                write!(fmt, "{}", display_allocation_details(tcx, alloc))
+            } else {
+                write!(fmt, "{}", display_allocation(tcx, alloc))
+            }
         }
 
         let mut allocs_to_print: VecDeque<_> = self.allocs.iter().copied().collect();
@@ -897,6 +1065,55 @@ fn write_allocation_track_relocs<'tcx, Prov: Provenance, Extra>(
     }
 }
 
+/// Create a lazy printer for an allocation that shows its bytes like `display_allocation`, followed
+/// by its init mask and provenance map spelled out as ranges:
+///
+/// ```text
+/// size: 12, align: 4) {
+///     ╾─alloc7──╼ __ __ __ __ │ ╾──────╼░░░░
+/// }
+/// init mask:
+///     0x0..0x8: init
+///     0x8..0xc: uninit
+/// provenance:
+///     0x0..0x8: alloc7
+/// ```
+///
+/// `DumpAllocs` uses this when printed with `{:#?}`.
+pub fn display_allocation_details<'a, 'tcx, Prov: Provenance, Extra>(
+    tcx: TyCtxt<'tcx>,
+    alloc: &'a Allocation<Prov, Extra>,
+) -> AllocationDetails<'a, 'tcx, Prov, Extra> {
+    AllocationDetails { tcx, alloc }
+}
+
+#[doc(hidden)]
+/// There's no way to use this directly, it's just a helper struct for `display_allocation_details`.
+pub struct AllocationDetails<'a, 'tcx, Prov, Extra> {
+    tcx: TyCtxt<'tcx>,
+    alloc: &'a Allocation<Prov, Extra>,
+}
+
+impl<'a, 'tcx, Prov: Provenance, Extra> fmt::Display for AllocationDetails<'a, 'tcx, Prov, Extra> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let AllocationDetails { tcx, alloc } = *self;
+        writeln!(f, "{}", display_allocation(tcx, alloc))?;
+        write!(f, "init mask:")?;
+        for chunk in alloc.init_mask().range_as_init_chunks(Size::ZERO, alloc.size()) {
+            let range = chunk.range();
+            let state = if chunk.is_init() { "init" } else { "uninit" };
+            write!(f, "\n    {:#x}..{:#x}: {state}", range.start.bytes(), range.end.bytes())?;
+        }
+        write!(f, "\nprovenance:")?;
+        let ptr_size = tcx.data_layout.pointer_size;
+        for &(offset, prov) in alloc.provenance().iter() {
+            let end = offset + ptr_size;
+            write!(f, "\n    {:#x}..{:#x}: {prov:?}", offset.bytes(), end.bytes())?;
+        }
+        Ok(())
+    }
+}
+
 /// Reading and writing.
 impl<'tcx, 'a, Prov: Provenance, Extra> AllocRefMut<'a, 'tcx, Prov, Extra> {
     /// `range` is relative to this allocation reference, not the base of the allocation.
@@ -921,6 +1138,14 @@ pub fn write_uninit(&mut self) -> InterpResult<'tcx> {
             .write_uninit(&self.tcx, self.range)
             .map_err(|e| e.to_interp_error(self.alloc_id))?)
     }
+
+    /// Fill the entire referenced range with copies of `byte`.
+    pub fn write_repeated_byte(&mut self, byte: u8) -> InterpResult<'tcx> {
+        Ok(self
+            .alloc
+            .write_repeated_byte(&self.tcx, self.range, byte)
+            .map_err(|e| e.to_interp_error(self.alloc_id))?)
+    }
 }
 
 impl<'tcx, 'a, Prov: Provenance, Extra> AllocRef<'a, 'tcx, Prov, Extra> {
@@ -1095,6 +1320,11 @@ pub fn mem_copy_repeatedly(
             src_alloc.prepare_provenance_copy(self, src_range, dest_offset, num_copies);
         // Prepare a copy of the initialization mask.
         let compressed = src_alloc.compress_uninit_range(src_range);
+        // If all of `src` gets copied, we might be able to share its bytes instead.
+        let copies_whole_src =
+            num_copies == 1 && src_offset == Size::ZERO && size == src_alloc.size();
+        let shared_bytes =
+            (copies_whole_src && src_alloc_id != dest_alloc_id).then(|| src_alloc.share_bytes());
 
         // Destination alloc preparations and access hooks.
         let (dest_alloc, extra) = self.get_alloc_raw_mut(dest_alloc_id)?;
@@ -1106,6 +1336,19 @@ pub fn mem_copy_repeatedly(
             (dest_alloc_id, dest_prov),
             dest_range,
         )?;
+        if let Some(bytes) = shared_bytes
+            && dest_offset == Size::ZERO
+            && size == dest_alloc.size()
+        {
+            // All of `src` gets copied over all of `dest`. Share the buffer instead of copying it;
+            // it only gets duplicated once either allocation is written to.
+            dest_alloc
+                .set_shared_bytes(&tcx, bytes)
+                .map_err(|e| e.to_interp_error(dest_alloc_id))?;
+            dest_alloc.mark_compressed_init_range(&compressed, dest_range, 1);
+            dest_alloc.mark_provenance_range(provenance);
+            return Ok(());
+        }
         let dest_bytes = dest_alloc
             .get_bytes_mut_ptr(&tcx, dest_range)
             .map_err(|e| e.to_interp_error(dest_alloc_id))?
@@ -1170,6 +1413,93 @@ pub fn mem_copy_repeatedly(
 
         Ok(())
     }
+
+    /// Swaps the `size` bytes behind `left` and `right` in place, together with their
+    /// initialization state and provenance. It is UB for the two ranges to overlap.
+    ///
+    /// In contrast to going through a temporary, this does not need a third allocation and
+    /// moves every byte exactly once.
+    pub fn mem_swap_nonoverlapping(
+        &mut self,
+        left: Pointer<Option<M::Provenance>>,
+        right: Pointer<Option<M::Provenance>>,
+        align: Align,
+        size: Size,
+    ) -> InterpResult<'tcx> {
+        let tcx = self.tcx;
+        // We need to do our own bounds-checks.
+        let left_parts = self.get_ptr_access(left, size, align)?;
+        let right_parts = self.get_ptr_access(right, size, align)?;
+        let (Some((left_id, left_offset, left_prov)), Some((right_id, right_offset, right_prov))) =
+            (left_parts, right_parts)
+        else {
+            // Zero-sized swap, there is nothing to do.
+            return Ok(());
+        };
+        let left_range = alloc_range(left_offset, size);
+        let right_range = alloc_range(right_offset, size);
+
+        // `Size` additions
+        if left_id == right_id
+            && left_offset < right_offset + size
+            && right_offset < left_offset + size
+        {
+            throw_ub_format!("swap_nonoverlapping called on overlapping ranges")
+        }
+
+        // Both sides are read, so run the access hooks and make sure we do not split a pointer.
+        let left_alloc = self.get_alloc_raw(left_id)?;
+        let right_alloc = self.get_alloc_raw(right_id)?;
+        for (alloc, alloc_id, prov, range) in [
+            (left_alloc, left_id, left_prov, left_range),
+            (right_alloc, right_id, right_prov, right_range),
+        ] {
+            M::before_memory_read(*tcx, &self.machine, &alloc.extra, (alloc_id, prov), range)?;
+            if alloc.range_has_provenance(&tcx, alloc_range(range.start, Size::ZERO)) {
+                throw_unsup!(PartialPointerCopy(Pointer::new(alloc_id, range.start)));
+            }
+            if alloc.range_has_provenance(&tcx, alloc_range(range.end(), Size::ZERO)) {
+                throw_unsup!(PartialPointerCopy(Pointer::new(alloc_id, range.end())));
+            }
+        }
+        // Take the provenance and initialization state of both sides before anything gets
+        // overwritten: `get_bytes_mut_ptr` below clears both.
+        let left_provenance = left_alloc.prepare_provenance_copy(self, left_range, right_offset, 1);
+        let right_provenance =
+            right_alloc.prepare_provenance_copy(self, right_range, left_offset, 1);
+        let left_init = left_alloc.compress_uninit_range(left_range);
+        let right_init = right_alloc.compress_uninit_range(right_range);
+
+        let (alloc, extra) = self.get_alloc_raw_mut(left_id)?;
+        M::before_memory_write(*tcx, extra, &mut alloc.extra, (left_id, left_prov), left_range)?;
+        let left_bytes = alloc
+            .get_bytes_mut_ptr(&tcx, left_range)
+            .map_err(|e| e.to_interp_error(left_id))?
+            .as_mut_ptr();
+        let (alloc, extra) = self.get_alloc_raw_mut(right_id)?;
+        M::before_memory_write(*tcx, extra, &mut alloc.extra, (right_id, right_prov), right_range)?;
+        let right_bytes = alloc
+            .get_bytes_mut_ptr(&tcx, right_range)
+            .map_err(|e| e.to_interp_error(right_id))?
+            .as_mut_ptr();
+
+        // SAFE: `get_bytes_mut_ptr` checked that there are `size` bytes behind both pointers, and
+        // we ruled out overlap above. The pointers remain valid even if the `HashMap` table is
+        // moved around because they point into the `Vec` storing the bytes.
+        // `swap_nonoverlapping` works chunk-wise, so this never needs a buffer of size `size`.
+        unsafe {
+            ptr::swap_nonoverlapping(left_bytes, right_bytes, size.bytes_usize());
+        }
+
+        let (alloc, _) = self.get_alloc_raw_mut(left_id)?;
+        alloc.mark_compressed_init_range(&right_init, left_range, 1);
+        alloc.mark_provenance_range(right_provenance);
+        let (alloc, _) = self.get_alloc_raw_mut(right_id)?;
+        alloc.mark_compressed_init_range(&left_init, right_range, 1);
+        alloc.mark_provenance_range(left_provenance);
+
+        Ok(())
+    }
 }
 
 /// Machine pointer introspection.
@@ -20,11 +20,17 @@
 pub use rustc_middle::mir::interpret::*; // have all the `interpret` symbols in one place: here
 
 pub use self::eval_context::{
-    Frame, FrameInfo, InterpCx, LocalState, LocalValue, StackPopCleanup, StackPopUnwind,
+    Frame, FrameInfo, InterpCx, InterpSnapshot, LocalState, LocalValue, StackPopCleanup,
+    StackPopUnwind,
 };
 pub use self::intern::{intern_const_alloc_recursive, InternKind};
-pub use self::machine::{compile_time_machine, AllocMap, Machine, MayLeak, StackPopJump};
-pub use self::memory::{AllocKind, AllocRef, AllocRefMut, FnVal, Memory, MemoryKind};
+pub use self::machine::{
+    compile_time_machine, AllocMap, Machine, MayLeak, ProvenanceViolation, StackPopJump,
+};
+pub use self::memory::{
+    display_allocation_details, AllocKind, AllocRef, AllocRefMut, AllocationDetails, FnVal, Memory,
+    MemoryKind,
+};
 pub use self::operand::{ImmTy, Immediate, OpTy, Operand};
 pub use self::place::{MPlaceTy, MemPlace, MemPlaceMeta, Place, PlaceTy};
 pub use self::validity::{CtfeValidationMode, RefTracking};
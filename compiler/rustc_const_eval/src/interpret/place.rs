@@ -869,7 +869,10 @@ pub(super) fn unpack_dyn_trait(
         mplace: &MPlaceTy<'tcx, M::Provenance>,
     ) -> InterpResult<'tcx, MPlaceTy<'tcx, M::Provenance>> {
         let vtable = mplace.vtable().to_pointer(self)?; // also sanity checks the type
-        let (ty, _) = self.get_ptr_vtable(vtable)?;
+        let (ty, vtable_trait) = self.get_ptr_vtable(vtable)?;
+        if let ty::Dynamic(data, ..) = mplace.layout.ty.kind() {
+            self.check_vtable_for_type(vtable_trait, *data)?;
+        }
         let layout = self.layout_of(ty)?;
 
         let mplace = MPlaceTy {
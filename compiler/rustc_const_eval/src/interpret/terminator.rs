@@ -368,7 +368,13 @@ pub(crate) fn eval_fn_call(
             ty::InstanceDef::Intrinsic(def_id) => {
                 assert!(self.tcx.is_intrinsic(def_id));
                 // caller_fn_abi is not relevant here, we interpret the arguments directly for each intrinsic.
-                M::call_intrinsic(self, instance, args, destination, target, unwind)
+                let intrinsic_name = self.tcx.item_name(def_id);
+                M::before_intrinsic(self, intrinsic_name, args)?;
+                let res = M::call_intrinsic(self, instance, args, destination, target, unwind);
+                // Tell the machine that the intrinsic is done even if it failed, but report the
+                // intrinsic's error rather than one from the hook.
+                let after = M::after_intrinsic(self, intrinsic_name, args, destination);
+                res.and(after)
             }
             ty::InstanceDef::VTableShim(..)
             | ty::InstanceDef::ReifyShim(..)
@@ -571,11 +577,7 @@ pub(crate) fn eval_fn_call(
                 // Get the required information from the vtable.
                 let vptr = receiver_place.meta.unwrap_meta().to_pointer(self)?;
                 let (dyn_ty, dyn_trait) = self.get_ptr_vtable(vptr)?;
-                if dyn_trait != data.principal() {
-                    throw_ub_format!(
-                        "`dyn` call on a pointer whose vtable does not match its type"
-                    );
-                }
+                self.check_vtable_for_type(dyn_trait, *data)?;
 
                 // Now determine the actual method to call. We can do that in two different ways and
                 // compare them to ensure everything fits.
@@ -47,13 +47,40 @@ pub fn get_vtable_entries(
         })
     }
 
+    /// Returns the size and alignment stored in the given vtable. If `expected_trait` is given,
+    /// the vtable must have been created for the principal trait of that `dyn` type.
     pub fn get_vtable_size_and_align(
         &self,
         vtable: Pointer<Option<M::Provenance>>,
+        expected_trait: Option<&'tcx ty::List<ty::Binder<'tcx, ty::ExistentialPredicate<'tcx>>>>,
     ) -> InterpResult<'tcx, (Size, Align)> {
-        let (ty, _trait_ref) = self.get_ptr_vtable(vtable)?;
+        let (ty, vtable_trait) = self.get_ptr_vtable(vtable)?;
+        if let Some(expected_trait) = expected_trait {
+            self.check_vtable_for_type(vtable_trait, expected_trait)?;
+        }
         let layout = self.layout_of(ty)?;
         assert!(!layout.is_unsized(), "there are no vtables for unsized types");
         Ok((layout.size, layout.align.abi))
     }
+
+    /// Checks that a vtable created for `vtable_trait` may be used with a `dyn` type whose
+    /// predicates are `expected_trait`, i.e. that both have the same principal trait.
+    pub fn check_vtable_for_type(
+        &self,
+        vtable_trait: Option<ty::PolyExistentialTraitRef<'tcx>>,
+        expected_trait: &'tcx ty::List<ty::Binder<'tcx, ty::ExistentialPredicate<'tcx>>>,
+    ) -> InterpResult<'tcx> {
+        let expected_principal = self.tcx.erase_regions(expected_trait.principal());
+        if vtable_trait != expected_principal {
+            let describe = |trait_ref: Option<ty::PolyExistentialTraitRef<'tcx>>| match trait_ref {
+                Some(trait_ref) => format!("trait `{trait_ref}`"),
+                None => "a trait object without principal trait".to_string(),
+            };
+            throw_ub!(InvalidVTableTrait {
+                vtable_trait: describe(vtable_trait),
+                expected_trait: describe(expected_principal),
+            });
+        }
+        Ok(())
+    }
 }
@@ -17,6 +17,7 @@ pub fn might_permit_raw_init<'tcx>(
             Limit::new(0),
             /*can_access_statics:*/ false,
             /*check_alignment:*/ true,
+            /*strict_provenance:*/ false,
         );
 
         let mut cx = InterpCx::new(tcx, rustc_span::DUMMY_SP, ParamEnv::reveal_all(), machine);
@@ -225,6 +225,10 @@ passes_rustc_std_internal_symbol = attribute should be applied to functions or s
 
 passes_const_trait = attribute should be applied to a trait
 
+passes_const_eval_limit =
+    attribute should be applied to the crate root, constants or statics
+    .label = not the crate root, a constant or a static
+
 passes_stability_promotable = attribute cannot be applied to an expression
 
 passes_deprecated = attribute is ignored here
@@ -213,6 +213,10 @@ pub struct Diagnostic {
     /// If diagnostic is from Lint, custom hash function ignores notes
     /// otherwise hash is based on the all the fields
     pub is_lint: bool,
+
+    /// Machine-readable details about this diagnostic, as a JSON string. This is not rendered
+    /// for humans; the JSON emitter passes it on as the `data` field.
+    json_data: Option<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Encodable, Decodable)]
@@ -298,6 +302,7 @@ pub fn new_with_code<M: Into<DiagnosticMessage>>(
             args: vec![],
             sort_span: DUMMY_SP,
             is_lint: false,
+            json_data: None,
         }
     }
 
@@ -930,6 +935,17 @@ pub fn set_primary_message(&mut self, msg: impl Into<DiagnosticMessage>) -> &mut
         self
     }
 
+    /// Attaches machine-readable details to this diagnostic, see the `json_data` field. `data`
+    /// must be valid JSON.
+    pub fn set_json_data(&mut self, data: String) -> &mut Self {
+        self.json_data = Some(data);
+        self
+    }
+
+    pub fn json_data(&self) -> Option<&str> {
+        self.json_data.as_deref()
+    }
+
     pub fn args(&self) -> &[DiagnosticArg<'static>] {
         &self.args
     }
@@ -351,7 +351,7 @@ pub struct BuiltinAttribute {
     ungated!(recursion_limit, CrateLevel, template!(NameValueStr: "N"), FutureWarnFollowing),
     ungated!(type_length_limit, CrateLevel, template!(NameValueStr: "N"), FutureWarnFollowing),
     gated!(
-        const_eval_limit, CrateLevel, template!(NameValueStr: "N"), ErrorFollowing,
+        const_eval_limit, Normal, template!(NameValueStr: "N"), ErrorFollowing,
         const_eval_limit, experimental!(const_eval_limit)
     ),
     gated!(
@@ -729,6 +729,8 @@ macro_rules! tracked {
     );
     tracked!(chalk, true);
     tracked!(codegen_backend, Some("abc".to_string()));
+    tracked!(const_eval_memory_limit, Some(1 << 20));
+    tracked!(const_eval_strict_provenance, true);
     tracked!(crate_attr, vec!["abc".to_string()]);
     tracked!(debug_info_for_profiling, true);
     tracked!(debug_macros, true);
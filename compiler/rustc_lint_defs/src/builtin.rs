@@ -294,6 +294,111 @@
     report_in_external_macro
 }
 
+declare_lint! {
+    /// The `long_running_const_eval` lint is emitted when const evaluation is
+    /// taking a long time, to point at the code that is being evaluated.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,compile_fail
+    /// #![feature(const_eval_limit)]
+    /// #![const_eval_limit = "0"]
+    /// #![deny(long_running_const_eval)]
+    ///
+    /// const FOO: () = loop {};
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Const evaluation only stops once it reaches the step limit set by
+    /// `#![const_eval_limit]` and `const_eval_limit_exceeded` is denied, and
+    /// not at all if that limit is disabled. This lint is emitted with the
+    /// current const-eval backtrace once three quarters of the limit have
+    /// been used up, or after 2^20 steps if the limit is disabled, and again
+    /// every time the number of evaluated steps doubles, so that slow or
+    /// infinitely looping constants can be found. Evaluation keeps going
+    /// afterwards unless the lint is denied.
+    pub LONG_RUNNING_CONST_EVAL,
+    Warn,
+    "detects constants that take a long time to evaluate"
+}
+
+declare_lint! {
+    /// The `const_eval_limit_exceeded` lint detects constants whose
+    /// evaluation takes more steps than the const eval limit allows.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,compile_fail
+    /// #![feature(const_eval_limit)]
+    /// #![const_eval_limit = "100"]
+    ///
+    /// const FOO: u32 = {
+    ///     let mut x = 0;
+    ///     while x < 1000 {
+    ///         x += 1;
+    ///     }
+    ///     x
+    /// };
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// The limit is set for the whole crate with `#![const_eval_limit]`,
+    /// and can be raised for a single item by putting
+    /// `#[const_eval_limit]` on it. Reaching the limit usually means that
+    /// the evaluation loops forever. If it is known to finish, this lint can
+    /// be allowed on the item, in which case evaluation continues up to 16
+    /// times the limit before it is aborted with an error.
+    pub CONST_EVAL_LIMIT_EXCEEDED,
+    Deny,
+    "detects constants exceeding the const eval step limit"
+}
+
+declare_lint! {
+    /// The `const_eval_strict_provenance` lint detects constants that create
+    /// pointers from integers while `-Zconst-eval-strict-provenance` is
+    /// enabled.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore (needs -Zconst-eval-strict-provenance)
+    /// const PTR: *const u8 = 4 as *const u8;
+    /// ```
+    ///
+    /// This will produce:
+    ///
+    /// ```text
+    /// warning: casting an integer to a pointer creates a pointer without provenance
+    ///  --> src/main.rs:1:24
+    ///   |
+    /// 1 | const PTR: *const u8 = 4 as *const u8;
+    ///   |                        ^^^^^^^^^^^^^^
+    ///   |
+    ///   = note: `#[warn(const_eval_strict_provenance)]` on by default
+    ///   = note: strict provenance requires every pointer to be derived from another pointer
+    ///   = help: use `ptr::invalid` for a pointer that is never dereferenced, or derive it from an existing pointer with `with_addr`
+    /// ```
+    ///
+    /// ### Explanation
+    ///
+    /// Under [strict provenance], every pointer has to be derived from
+    /// another pointer, so casting or transmuting an integer to a pointer is
+    /// not allowed. This lint points out such code in constants, so that it
+    /// can be migrated. It is only emitted once per location, and only for
+    /// code in the current crate, as the standard library creates such
+    /// pointers on purpose, e.g. in `ptr::invalid`.
+    ///
+    /// [strict provenance]: https://doc.rust-lang.org/nightly/std/ptr/index.html#strict-provenance
+    pub CONST_EVAL_STRICT_PROVENANCE,
+    Warn,
+    "detects constants that create pointers from integers under `-Zconst-eval-strict-provenance`"
+}
+
 declare_lint! {
     /// The `unused_imports` lint detects imports that are never used.
     ///
@@ -3296,6 +3401,9 @@
         PUB_USE_OF_PRIVATE_EXTERN_CRATE,
         INVALID_TYPE_PARAM_DEFAULT,
         CONST_ERR,
+        LONG_RUNNING_CONST_EVAL,
+        CONST_EVAL_LIMIT_EXCEEDED,
+        CONST_EVAL_STRICT_PROVENANCE,
         RENAMED_AND_REMOVED_LINTS,
         UNALIGNED_REFERENCES,
         CONST_ITEM_MUTATION,
@@ -8,6 +8,9 @@
 //! on how deeply they recurse to prevent stack overflow. Users can override
 //! this via an attribute on the crate like `#![recursion_limit="22"]`. This pass
 //! just peeks and looks for that attribute.
+//!
+//! The const_eval_limit can additionally be raised for a single item with
+//! `#[const_eval_limit="N"]` on that item.
 
 use crate::bug;
 use crate::error::LimitInvalid;
@@ -40,7 +43,15 @@ pub fn provide(providers: &mut ty::query::Providers) {
             sym::const_eval_limit,
             1_000_000,
         ),
-    }
+    };
+    providers.item_const_eval_limit = |tcx, def_id| {
+        get_limit(
+            tcx.get_attrs_unchecked(def_id),
+            tcx.sess,
+            sym::const_eval_limit,
+            tcx.const_eval_limit().0,
+        )
+    };
 }
 
 pub fn get_recursion_limit(krate_attrs: &[Attribute], sess: &Session) -> Limit {
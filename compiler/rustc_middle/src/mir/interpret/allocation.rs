@@ -11,6 +11,7 @@
 use rustc_ast::Mutability;
 use rustc_data_structures::intern::Interned;
 use rustc_data_structures::sorted_map::SortedMap;
+use rustc_data_structures::sync::Lrc;
 use rustc_span::DUMMY_SP;
 use rustc_target::abi::{Align, HasDataLayout, Size};
 
@@ -33,7 +34,10 @@
 pub struct Allocation<Prov = AllocId, Extra = ()> {
     /// The actual bytes of the allocation.
     /// Note that the bytes of a pointer represent the offset of the pointer.
-    bytes: Box<[u8]>,
+    ///
+    /// The buffer can be shared with other allocations (see `share_bytes`), in which case it
+    /// is copied on the first write.
+    bytes: Lrc<Box<[u8]>>,
     /// Maps from byte addresses to extra provenance data for each pointer.
     /// Only the first byte of a pointer is inserted into the map; i.e.,
     /// every entry in this map applies to `pointer_size` consecutive bytes starting
@@ -215,7 +219,7 @@ pub fn from_bytes<'a>(
         let bytes = Box::<[u8]>::from(slice.into());
         let size = Size::from_bytes(bytes.len());
         Self {
-            bytes,
+            bytes: Lrc::new(bytes),
             provenance: ProvenanceMap::new(),
             init_mask: InitMask::new(size, true),
             align,
@@ -250,7 +254,7 @@ pub fn uninit<'tcx>(size: Size, align: Align, panic_on_fail: bool) -> InterpResu
         // SAFETY: the box was zero-allocated, which is a valid initial value for Box<[u8]>
         let bytes = unsafe { bytes.assume_init() };
         Ok(Allocation {
-            bytes,
+            bytes: Lrc::new(bytes),
             provenance: ProvenanceMap::new(),
             init_mask: InitMask::new(size, false),
             align,
@@ -276,7 +280,7 @@ pub fn adjust_from_tcx<Prov, Extra, Err>(
         let endian = cx.data_layout().endian;
         for &(offset, alloc_id) in self.provenance.iter() {
             let idx = offset.bytes_usize();
-            let ptr_bytes = &mut bytes[idx..idx + ptr_size];
+            let ptr_bytes = &mut Lrc::make_mut(&mut bytes)[idx..idx + ptr_size];
             let bits = read_target_uint(endian, ptr_bytes).unwrap();
             let (ptr_prov, ptr_offset) =
                 adjust_ptr(Pointer::new(alloc_id, Size::from_bytes(bits)))?.into_parts();
@@ -372,7 +376,8 @@ pub fn get_bytes_mut(
         self.mark_init(range, true);
         self.clear_provenance(cx, range)?;
 
-        Ok(&mut self.bytes[range.start.bytes_usize()..range.end().bytes_usize()])
+        let bytes = Lrc::make_mut(&mut self.bytes);
+        Ok(&mut bytes[range.start.bytes_usize()..range.end().bytes_usize()])
     }
 
     /// A raw pointer variant of `get_bytes_mut` that avoids invalidating existing aliases into this memory.
@@ -385,12 +390,57 @@ pub fn get_bytes_mut_ptr(
         self.clear_provenance(cx, range)?;
 
         assert!(range.end().bytes_usize() <= self.bytes.len()); // need to do our own bounds-check
-        let begin_ptr = self.bytes.as_mut_ptr().wrapping_add(range.start.bytes_usize());
+        let begin_ptr =
+            Lrc::make_mut(&mut self.bytes).as_mut_ptr().wrapping_add(range.start.bytes_usize());
         let len = range.end().bytes_usize() - range.start.bytes_usize();
         Ok(ptr::slice_from_raw_parts_mut(begin_ptr, len))
     }
+
+    /// Returns a handle to the bytes of this allocation that can be installed in another
+    /// allocation of the same size with `set_shared_bytes`, without copying them.
+    pub fn share_bytes(&self) -> SharedBytes {
+        SharedBytes(Lrc::clone(&self.bytes))
+    }
+
+    /// Replaces all bytes of this allocation by `bytes`, which must have been taken from an
+    /// allocation of the same size. The buffer stays shared until one of the allocations
+    /// involved is written to.
+    ///
+    /// Like `get_bytes_mut`, this marks everything as initialized and removes provenance, so the
+    /// caller is expected to restore those.
+    pub fn set_shared_bytes(&mut self, cx: &impl HasDataLayout, bytes: SharedBytes) -> AllocResult {
+        assert_eq!(
+            bytes.0.len(),
+            self.bytes.len(),
+            "sharing bytes between differently sized allocations"
+        );
+        let range = alloc_range(Size::ZERO, self.size());
+        self.mark_init(range, true);
+        self.clear_provenance(cx, range)?;
+        self.bytes = bytes.0;
+        Ok(())
+    }
+
+    /// Switches this allocation over to `bytes` if they are exactly the same as its own bytes,
+    /// freeing its own copy once nothing else uses it. Returns whether the bytes are now shared.
+    ///
+    /// Unlike `set_shared_bytes`, this does not change the contents of the allocation, so the
+    /// init mask and provenance stay as they are.
+    pub fn dedup_bytes(&mut self, bytes: &SharedBytes) -> bool {
+        if Lrc::ptr_eq(&self.bytes, &bytes.0) {
+            return true;
+        }
+        if self.bytes != bytes.0 {
+            return false;
+        }
+        self.bytes = Lrc::clone(&bytes.0);
+        true
+    }
 }
 
+/// The bytes of an allocation, taken with `Allocation::share_bytes`.
+pub struct SharedBytes(Lrc<Box<[u8]>>);
+
 /// Reading and writing.
 impl<Prov: Provenance, Extra> Allocation<Prov, Extra> {
     /// Reads a *non-ZST* scalar.
@@ -503,6 +553,20 @@ pub fn write_uninit(&mut self, cx: &impl HasDataLayout, range: AllocRange) -> Al
         self.clear_provenance(cx, range)?;
         return Ok(());
     }
+
+    /// Fills `range` with copies of `byte`, marking it as initialized and removing provenance.
+    ///
+    /// This works on the whole range at once, so it is much cheaper than writing the bytes
+    /// one by one.
+    pub fn write_repeated_byte(
+        &mut self,
+        cx: &impl HasDataLayout,
+        range: AllocRange,
+        byte: u8,
+    ) -> AllocResult {
+        self.get_bytes_mut(cx, range)?.fill(byte);
+        Ok(())
+    }
 }
 
 /// Provenance.
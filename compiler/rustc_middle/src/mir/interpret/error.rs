@@ -286,6 +286,13 @@ pub enum UndefinedBehaviorInfo {
     InvalidFunctionPointer(Pointer),
     /// Using a pointer-not-to-a-vtable as vtable pointer.
     InvalidVTablePointer(Pointer),
+    /// Using a vtable for a different trait than the one the `dyn` type expects.
+    InvalidVTableTrait {
+        /// The principal trait the vtable was created for.
+        vtable_trait: String,
+        /// The principal trait of the `dyn` type the vtable is used with.
+        expected_trait: String,
+    },
     /// Using a string that is not valid UTF-8,
     InvalidStr(std::str::Utf8Error),
     /// Using uninitialized data where it is not allowed.
@@ -369,6 +376,9 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             InvalidVTablePointer(p) => {
                 write!(f, "using {p:?} as vtable pointer but it does not point to a vtable")
             }
+            InvalidVTableTrait { vtable_trait, expected_trait } => {
+                write!(f, "using vtable for {vtable_trait} but {expected_trait} was expected")
+            }
             InvalidStr(err) => write!(f, "this string is not valid UTF-8: {err}"),
             InvalidUninitBytes(Some((alloc, info))) => write!(
                 f,
@@ -442,12 +452,16 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 pub enum ResourceExhaustionInfo {
     /// The stack grew too big.
     StackFrameLimitReached,
-    /// The program ran for too long.
+    /// The program ran for too long, even after the `const_eval_limit_exceeded` lint fired.
     ///
-    /// The exact limit is set by the `const_eval_limit` attribute.
+    /// The exact limit is a multiple of the one set by the `const_eval_limit` attribute.
     StepLimitReached,
     /// There is not enough memory to perform an allocation.
     MemoryExhausted,
+    /// Evaluating `item` tried to allocate `size` bytes, which would have exceeded the
+    /// `-Zconst-eval-memory-limit` of `limit` bytes. `item` is `None` if evaluation did not
+    /// start yet, e.g. for the allocation of the return place.
+    MemoryLimitReached { item: Option<String>, size: u64, limit: u64 },
 }
 
 impl fmt::Display for ResourceExhaustionInfo {
@@ -463,6 +477,16 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             MemoryExhausted => {
                 write!(f, "tried to allocate more memory than available to compiler")
             }
+            MemoryLimitReached { item, size, limit } => {
+                write!(f, "allocating {size} bytes ")?;
+                if let Some(item) = item {
+                    write!(f, "while evaluating `{item}` ")?;
+                }
+                write!(
+                    f,
+                    "exceeds the memory limit of {limit} bytes (see `-Zconst-eval-memory-limit`)"
+                )
+            }
         }
     }
 }
@@ -551,7 +575,10 @@ pub fn is_hard_err(&self) -> bool {
         match *self {
             MachineStop(ref err) => err.is_hard_err(),
             UndefinedBehavior(_) => true,
-            ResourceExhaustion(ResourceExhaustionInfo::MemoryExhausted) => true,
+            ResourceExhaustion(
+                ResourceExhaustionInfo::MemoryExhausted
+                | ResourceExhaustionInfo::MemoryLimitReached { .. },
+            ) => true,
             _ => false,
         }
     }
@@ -128,7 +128,7 @@ macro_rules! throw_machine_stop {
 
 pub use self::allocation::{
     alloc_range, AllocRange, Allocation, ConstAllocation, InitChunk, InitChunkIter, InitMask,
-    ProvenanceMap,
+    ProvenanceMap, SharedBytes,
 };
 
 pub use self::pointer::{Pointer, PointerArithmetic, Provenance};
@@ -2060,6 +2060,13 @@
         desc { "looking up limits" }
     }
 
+    /// The number of steps the evaluation of the given item may take before the
+    /// `const_eval_limit_exceeded` lint fires. This is the value of `#[const_eval_limit]` on the
+    /// item, falling back to the crate-level limit.
+    query item_const_eval_limit(key: DefId) -> Limit {
+        desc { |tcx| "looking up the const eval limit of `{}`", tcx.def_path_str(key) }
+    }
+
     /// Performs an HIR-based well-formed check on the item with the given `HirId`. If
     /// we get an `Unimplemented` error that matches the provided `Predicate`, return
     /// the cause of the newly created obligation.
@@ -2090,10 +2097,16 @@
         separate_provide_extern
     }
 
+    /// Checks whether leaving a value of the given type uninitialized may be valid, for
+    /// `assert_uninit_valid`. This is a query so that the check, which runs the interpreter's
+    /// validity check under `-Zstrict-init-checks`, happens only once per type even though the
+    /// interpreter and codegen ask for every use of the intrinsic.
     query permits_uninit_init(key: TyAndLayout<'tcx>) -> bool {
         desc { "checking to see if {:?} permits being left uninit", key.ty }
     }
 
+    /// Checks whether a value of the given type may be all zeroes, for `assert_zero_valid`. Like
+    /// `permits_uninit_init`, this is cached per type.
     query permits_zero_init(key: TyAndLayout<'tcx>) -> bool {
         desc { "checking to see if {:?} permits being left zeroed", key.ty }
     }
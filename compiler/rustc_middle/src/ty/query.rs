@@ -53,7 +53,7 @@
 use rustc_session::cstore::{ExternCrate, ForeignModule, LinkagePreference, NativeLib};
 use rustc_session::lint::LintExpectationId;
 use rustc_session::utils::NativeLibKind;
-use rustc_session::Limits;
+use rustc_session::{Limit, Limits};
 use rustc_span::symbol::Symbol;
 use rustc_span::{Span, DUMMY_SP};
 use rustc_target::abi;
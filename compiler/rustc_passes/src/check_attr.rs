@@ -133,6 +133,7 @@ fn check_attributes(
                 sym::cmse_nonsecure_entry => self.check_cmse_nonsecure_entry(attr, span, target),
                 sym::collapse_debuginfo => self.check_collapse_debuginfo(attr, span, target),
                 sym::const_trait => self.check_const_trait(attr, span, target),
+                sym::const_eval_limit => self.check_const_eval_limit(hir_id, attr, span, target),
                 sym::must_not_suspend => self.check_must_not_suspend(&attr, span, target),
                 sym::must_use => self.check_must_use(hir_id, &attr, span, target),
                 sym::rustc_pass_by_value => self.check_pass_by_value(&attr, span, target),
@@ -1924,6 +1925,27 @@ fn check_const_trait(&self, attr: &Attribute, _span: Span, target: Target) -> bo
         }
     }
 
+    /// `#[const_eval_limit]` is only read from the crate root and from the items whose
+    /// initializers get evaluated at compile time.
+    fn check_const_eval_limit(
+        &self,
+        hir_id: HirId,
+        attr: &Attribute,
+        span: Span,
+        target: Target,
+    ) -> bool {
+        match target {
+            Target::Mod if hir_id == CRATE_HIR_ID => true,
+            Target::Const | Target::Static => true,
+            // The limit of an associated constant is read from the impl, not from the trait.
+            Target::AssocConst if self.is_impl_item(hir_id) => true,
+            _ => {
+                self.tcx.sess.emit_err(errors::ConstEvalLimit { attr_span: attr.span, span });
+                false
+            }
+        }
+    }
+
     fn check_stability_promotable(&self, attr: &Attribute, _span: Span, target: Target) -> bool {
         match target {
             Target::Expression => {
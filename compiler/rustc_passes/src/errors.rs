@@ -551,6 +551,15 @@ pub struct ConstTrait {
     pub attr_span: Span,
 }
 
+#[derive(SessionDiagnostic)]
+#[diag(passes::const_eval_limit)]
+pub struct ConstEvalLimit {
+    #[primary_span]
+    pub attr_span: Span,
+    #[label]
+    pub span: Span,
+}
+
 #[derive(SessionDiagnostic)]
 #[diag(passes::link_ordinal)]
 pub struct LinkOrdinal {
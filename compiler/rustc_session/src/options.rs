@@ -1250,6 +1250,11 @@ pub(crate) fn parse_proc_macro_execution_strategy(
         "the backend to use"),
     combine_cgu: bool = (false, parse_bool, [TRACKED],
         "combine CGUs into a single one"),
+    const_eval_memory_limit: Option<u64> = (None, parse_opt_number, [TRACKED],
+        "the maximum number of bytes a single constant evaluation may have allocated at once \
+        (default: no limit)"),
+    const_eval_strict_provenance: bool = (false, parse_bool, [TRACKED],
+        "warn when constant evaluation creates pointers from integers (default: no)"),
     crate_attr: Vec<String> = (Vec::new(), parse_string_push, [TRACKED],
         "inject the given attribute in the crate"),
     debug_info_for_profiling: bool = (false, parse_bool, [TRACKED],
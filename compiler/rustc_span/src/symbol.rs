@@ -337,6 +337,7 @@
         adt_const_params,
         advanced_slice_patterns,
         adx_target_feature,
+        aggregate_raw_ptr,
         alias,
         align,
         align_offset,
@@ -447,6 +448,7 @@
         call_once,
         caller_location,
         capture_disjoint_fields,
+        carrying_mul_add,
         cdylib,
         ceilf32,
         ceilf64,
@@ -726,6 +728,8 @@
         floorf64,
         fmaf32,
         fmaf64,
+        fmuladdf32,
+        fmuladdf64,
         fmt,
         fmt_as_str,
         fmt_internals,
@@ -840,6 +844,7 @@
         intra_doc_pointers,
         intrinsics,
         irrefutable_let_patterns,
+        is_val_statically_known,
         isa_attribute,
         isize,
         issue,
@@ -1123,6 +1128,7 @@
         ptr,
         ptr_guaranteed_cmp,
         ptr_mask,
+        ptr_metadata,
         ptr_null,
         ptr_null_mut,
         ptr_offset_from,
@@ -1149,6 +1155,7 @@
         read_enum_variant_arg,
         read_struct,
         read_struct_field,
+        read_via_copy,
         readonly,
         realloc,
         reason,
@@ -1193,6 +1200,8 @@
         rlib,
         rotate_left,
         rotate_right,
+        round_ties_even_f32,
+        round_ties_even_f64,
         roundf32,
         roundf64,
         rt,
@@ -1304,6 +1313,7 @@
         sanitizer_runtime,
         saturating_add,
         saturating_sub,
+        select_unpredictable,
         self_in_typedefs,
         self_struct_ctor,
         semitransparent,
@@ -1324,6 +1334,9 @@
         simd_cast,
         simd_cast_ptr,
         simd_ceil,
+        simd_ctlz,
+        simd_ctpop,
+        simd_cttz,
         simd_div,
         simd_eq,
         simd_expose_addr,
@@ -1351,6 +1364,8 @@
         simd_insert,
         simd_le,
         simd_lt,
+        simd_masked_load,
+        simd_masked_store,
         simd_mul,
         simd_ne,
         simd_neg,
@@ -1515,11 +1530,13 @@
         type_length_limit,
         type_macros,
         type_name,
+        typed_swap_nonoverlapping,
         u128,
         u16,
         u32,
         u64,
         u8,
+        ub_checks,
         unaligned_volatile_load,
         unaligned_volatile_store,
         unboxed_closures,
@@ -1620,6 +1637,7 @@
         write_bytes,
         write_macro,
         write_str,
+        write_via_move,
         writeln_macro,
         x87_reg,
         xer,
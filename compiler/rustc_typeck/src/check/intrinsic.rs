@@ -84,6 +84,7 @@ pub fn intrinsic_operation_unsafety(intrinsic: Symbol) -> hir::Unsafety {
         | sym::wrapping_mul
         | sym::saturating_add
         | sym::saturating_sub
+        | sym::carrying_mul_add
         | sym::rotate_left
         | sym::rotate_right
         | sym::ctpop
@@ -95,6 +96,9 @@ pub fn intrinsic_operation_unsafety(intrinsic: Symbol) -> hir::Unsafety {
         | sym::type_id
         | sym::likely
         | sym::unlikely
+        | sym::is_val_statically_known
+        | sym::select_unpredictable
+        | sym::ub_checks
         | sym::ptr_guaranteed_cmp
         | sym::minnumf32
         | sym::minnumf64
@@ -105,7 +109,9 @@ pub fn intrinsic_operation_unsafety(intrinsic: Symbol) -> hir::Unsafety {
         | sym::forget
         | sym::black_box
         | sym::variant_count
-        | sym::ptr_mask => hir::Unsafety::Normal,
+        | sym::ptr_mask
+        | sym::ptr_metadata
+        | sym::aggregate_raw_ptr => hir::Unsafety::Normal,
         _ => hir::Unsafety::Unsafe,
     }
 }
@@ -211,6 +217,8 @@ pub fn check_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>) {
                 ],
                 tcx.mk_ptr(ty::TypeAndMut { ty: param(0), mutbl: hir::Mutability::Not }),
             ),
+            sym::ptr_metadata => (2, vec![tcx.mk_imm_ptr(param(0))], param(1)),
+            sym::aggregate_raw_ptr => (3, vec![param(1), param(2)], param(0)),
 
             sym::copy | sym::copy_nonoverlapping => (
                 1,
@@ -261,6 +269,12 @@ pub fn check_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>) {
             sym::log2f64 => (0, vec![tcx.types.f64], tcx.types.f64),
             sym::fmaf32 => (0, vec![tcx.types.f32, tcx.types.f32, tcx.types.f32], tcx.types.f32),
             sym::fmaf64 => (0, vec![tcx.types.f64, tcx.types.f64, tcx.types.f64], tcx.types.f64),
+            sym::fmuladdf32 => {
+                (0, vec![tcx.types.f32, tcx.types.f32, tcx.types.f32], tcx.types.f32)
+            }
+            sym::fmuladdf64 => {
+                (0, vec![tcx.types.f64, tcx.types.f64, tcx.types.f64], tcx.types.f64)
+            }
             sym::fabsf32 => (0, vec![tcx.types.f32], tcx.types.f32),
             sym::fabsf64 => (0, vec![tcx.types.f64], tcx.types.f64),
             sym::minnumf32 => (0, vec![tcx.types.f32, tcx.types.f32], tcx.types.f32),
@@ -281,6 +295,8 @@ pub fn check_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>) {
             sym::nearbyintf64 => (0, vec![tcx.types.f64], tcx.types.f64),
             sym::roundf32 => (0, vec![tcx.types.f32], tcx.types.f32),
             sym::roundf64 => (0, vec![tcx.types.f64], tcx.types.f64),
+            sym::round_ties_even_f32 => (0, vec![tcx.types.f32], tcx.types.f32),
+            sym::round_ties_even_f64 => (0, vec![tcx.types.f64], tcx.types.f64),
 
             sym::volatile_load | sym::unaligned_volatile_load => {
                 (1, vec![tcx.mk_imm_ptr(param(0))], param(0))
@@ -288,6 +304,9 @@ pub fn check_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>) {
             sym::volatile_store | sym::unaligned_volatile_store => {
                 (1, vec![tcx.mk_mut_ptr(param(0)), param(0)], tcx.mk_unit())
             }
+            sym::read_via_copy => (1, vec![tcx.mk_imm_ptr(param(0))], param(0)),
+            sym::write_via_move => (1, vec![tcx.mk_mut_ptr(param(0)), param(0)], tcx.mk_unit()),
+            sym::typed_swap_nonoverlapping => (1, vec![tcx.mk_mut_ptr(param(0)); 2], tcx.mk_unit()),
 
             sym::ctpop
             | sym::ctlz
@@ -333,14 +352,18 @@ pub fn check_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>) {
                 (1, vec![param(0), param(0)], param(0))
             }
             sym::saturating_add | sym::saturating_sub => (1, vec![param(0), param(0)], param(0)),
+            sym::carrying_mul_add => (1, vec![param(0); 4], tcx.intern_tup(&[param(0), param(0)])),
             sym::fadd_fast | sym::fsub_fast | sym::fmul_fast | sym::fdiv_fast | sym::frem_fast => {
                 (1, vec![param(0), param(0)], param(0))
             }
             sym::float_to_int_unchecked => (2, vec![param(0)], param(1)),
 
             sym::assume => (0, vec![tcx.types.bool], tcx.mk_unit()),
+            sym::ub_checks => (0, Vec::new(), tcx.types.bool),
             sym::likely => (0, vec![tcx.types.bool], tcx.types.bool),
             sym::unlikely => (0, vec![tcx.types.bool], tcx.types.bool),
+            sym::is_val_statically_known => (1, vec![param(0)], tcx.types.bool),
+            sym::select_unpredictable => (1, vec![tcx.types.bool, param(0), param(0)], param(0)),
 
             sym::discriminant_value => {
                 let assoc_items = tcx.associated_item_def_ids(
@@ -458,6 +481,9 @@ pub fn check_platform_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>)
         | sym::simd_saturating_sub => (1, vec![param(0), param(0)], param(0)),
         sym::simd_arith_offset => (2, vec![param(0), param(1)], param(0)),
         sym::simd_neg
+        | sym::simd_ctlz
+        | sym::simd_cttz
+        | sym::simd_ctpop
         | sym::simd_fsqrt
         | sym::simd_fsin
         | sym::simd_fcos
@@ -475,6 +501,8 @@ pub fn check_platform_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>)
         sym::simd_fma => (1, vec![param(0), param(0), param(0)], param(0)),
         sym::simd_gather => (3, vec![param(0), param(1), param(2)], param(0)),
         sym::simd_scatter => (3, vec![param(0), param(1), param(2)], tcx.mk_unit()),
+        sym::simd_masked_load => (3, vec![param(0), param(1), param(2)], param(2)),
+        sym::simd_masked_store => (3, vec![param(0), param(1), param(2)], tcx.mk_unit()),
         sym::simd_insert => (2, vec![param(0), tcx.types.u32, param(1)], param(0)),
         sym::simd_extract => (2, vec![param(0), tcx.types.u32], param(1)),
         sym::simd_cast
@@ -847,6 +847,59 @@ pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
     #[rustc_const_unstable(feature = "const_likely", issue = "none")]
     pub fn likely(b: bool) -> bool;
 
+    /// Returns whether the argument's value is statically known at
+    /// compile-time.
+    ///
+    /// This is useful when there is a way of writing the code that will
+    /// be *faster* when some variables have known values, but *slower*
+    /// in the general case: an `if is_val_statically_known(var) {}` can be used
+    /// to keep the general implementation for unknown values.
+    ///
+    /// The result may differ between runtime and const evaluation, and between
+    /// compilations; it must never change the observable behavior of a program.
+    /// During const evaluation it currently always returns `false`.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    /// Therefore, implementations must not require the user to uphold
+    /// any safety invariants.
+    ///
+    /// This intrinsic does not have a stable counterpart.
+    #[cfg(not(bootstrap))]
+    #[rustc_const_unstable(feature = "is_val_statically_known", issue = "none")]
+    pub fn is_val_statically_known<T: Copy>(arg: T) -> bool;
+
+    /// Returns either `true_val` or `false_val` depending on condition `b` with a
+    /// hint to the compiler that this condition is unlikely to be correctly
+    /// predicted by a CPU's branch predictor (e.g. a binary search).
+    ///
+    /// This is otherwise functionally equivalent to `if b { true_val } else { false_val }`.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    /// Therefore, implementations must not require the user to uphold
+    /// any safety invariants.
+    ///
+    /// This intrinsic does not have a stable counterpart.
+    #[cfg(not(bootstrap))]
+    #[rustc_const_unstable(feature = "const_select_unpredictable", issue = "none")]
+    pub fn select_unpredictable<T>(b: bool, true_val: T, false_val: T) -> T;
+
+    /// Returns whether we should perform some UB-checking at runtime. This eventually evaluates to
+    /// `cfg!(debug_assertions)` in the crate that the call ends up being codegened in, rather than
+    /// the crate that defines the calling function. During const evaluation, the interpreter
+    /// decides the result (rustc uses the `-Cdebug-assertions` setting of the current crate).
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    /// Therefore, implementations must not require the user to uphold
+    /// any safety invariants.
+    ///
+    /// This intrinsic does not have a stable counterpart.
+    #[cfg(not(bootstrap))]
+    #[rustc_const_unstable(feature = "const_ub_checks", issue = "none")]
+    pub fn ub_checks() -> bool;
+
     /// Hints to the compiler that branch condition is likely to be false.
     /// Returns the value passed to it.
     ///
@@ -1298,8 +1351,39 @@ pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
     ///
     /// Consider using [`pointer::mask`] instead.
     #[cfg(not(bootstrap))]
+    #[rustc_const_unstable(feature = "const_ptr_mask", issue = "none")]
     pub fn ptr_mask<T>(ptr: *const T, mask: usize) -> *const T;
 
+    /// Extracts the metadata part of a (possibly wide) pointer.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    /// Therefore, implementations must not require the user to uphold
+    /// any safety invariants.
+    ///
+    /// Consider using [`ptr::metadata`](crate::ptr::metadata) instead.
+    #[cfg(not(bootstrap))]
+    #[rustc_const_unstable(feature = "ptr_metadata", issue = "81513")]
+    pub fn ptr_metadata<P: ?Sized + crate::ptr::Pointee<Metadata = M>, M>(ptr: *const P) -> M;
+
+    /// Combines a thin data pointer and metadata into a (possibly wide) raw pointer.
+    ///
+    /// `P` is either `*const T` or `*mut T` and `D` the matching thin pointer type.
+    /// The resulting pointer is not necessarily valid to dereference.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    /// Therefore, implementations must not require the user to uphold
+    /// any safety invariants.
+    ///
+    /// Consider using [`ptr::from_raw_parts`](crate::ptr::from_raw_parts) instead.
+    #[cfg(not(bootstrap))]
+    #[rustc_const_unstable(feature = "ptr_metadata", issue = "81513")]
+    pub fn aggregate_raw_ptr<P: crate::ptr::AggregateRawPtr<D, Metadata = M>, D, M>(
+        data: D,
+        meta: M,
+    ) -> P;
+
     /// Equivalent to the appropriate `llvm.memcpy.p0i8.0i8.*` intrinsic, with
     /// a size of `count` * `size_of::<T>()` and an alignment of
     /// `min_align_of::<T>()`
@@ -1348,6 +1432,37 @@ pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
     /// This intrinsic does not have a stable counterpart.
     pub fn unaligned_volatile_store<T>(dst: *mut T, val: T);
 
+    /// Reads the value behind `ptr` as if by `*ptr`, without requiring `T: Copy`.
+    ///
+    /// The same requirements as for a place expression apply: the pointer must be
+    /// aligned and dereferenceable, and the value read must be valid at type `T`.
+    ///
+    /// The stabilized version of this intrinsic is [`crate::ptr::read`].
+    #[cfg(not(bootstrap))]
+    #[rustc_const_unstable(feature = "const_ptr_read", issue = "80377")]
+    pub fn read_via_copy<T>(ptr: *const T) -> T;
+
+    /// Moves `value` into the place behind `ptr` as if by `*ptr = value`, except
+    /// that the old value is not dropped.
+    ///
+    /// The pointer must be aligned and dereferenceable.
+    ///
+    /// The stabilized version of this intrinsic is [`crate::ptr::write`].
+    #[cfg(not(bootstrap))]
+    #[rustc_const_unstable(feature = "const_ptr_write", issue = "86302")]
+    pub fn write_via_move<T>(ptr: *mut T, value: T);
+
+    /// Swaps the values behind `x` and `y` in place, without going through a temporary.
+    ///
+    /// Both pointers must be aligned and dereferenceable, both values must be valid at
+    /// type `T`, and the two regions of memory must *not* overlap.
+    ///
+    /// The stabilized version of this intrinsic is [`crate::ptr::swap_nonoverlapping`]
+    /// with a `count` of 1.
+    #[cfg(not(bootstrap))]
+    #[rustc_const_unstable(feature = "const_swap", issue = "83163")]
+    pub fn typed_swap_nonoverlapping<T>(x: *mut T, y: *mut T);
+
     /// Returns the square root of an `f32`
     ///
     /// The stabilized version of this intrinsic is
@@ -1469,6 +1584,30 @@ pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
     /// [`f64::mul_add`](../../std/primitive.f64.html#method.mul_add)
     pub fn fmaf64(a: f64, b: f64, c: f64) -> f64;
 
+    /// Returns `a * b + c` for `f32` values, non-deterministically executing
+    /// either a fused multiply-add or two operations with rounding of the
+    /// intermediate result.
+    ///
+    /// The operation is fused if the code generator determines that the target
+    /// instruction set has support for a fused operation, and that the fused
+    /// operation is more efficient than the equivalent, separate pair of mul
+    /// and add instructions. It is unspecified whether or not a fused operation
+    /// is selected, and that may depend on optimization level and context, for
+    /// example. During const evaluation the operation is always fused.
+    ///
+    /// This intrinsic does not have a stable counterpart.
+    #[cfg(not(bootstrap))]
+    pub fn fmuladdf32(a: f32, b: f32, c: f32) -> f32;
+    /// Returns `a * b + c` for `f64` values, non-deterministically executing
+    /// either a fused multiply-add or two operations with rounding of the
+    /// intermediate result.
+    ///
+    /// See [`fmuladdf32`] for details on when the operation is fused.
+    ///
+    /// This intrinsic does not have a stable counterpart.
+    #[cfg(not(bootstrap))]
+    pub fn fmuladdf64(a: f64, b: f64, c: f64) -> f64;
+
     /// Returns the absolute value of an `f32`.
     ///
     /// The stabilized version of this intrinsic is
@@ -1592,6 +1731,19 @@ pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
     /// [`f64::round`](../../std/primitive.f64.html#method.round)
     pub fn roundf64(x: f64) -> f64;
 
+    /// Returns the nearest integer to an `f32`. Rounds half-way cases to the number
+    /// with an even least significant digit.
+    ///
+    /// This intrinsic does not have a stable counterpart.
+    #[cfg(not(bootstrap))]
+    pub fn round_ties_even_f32(x: f32) -> f32;
+    /// Returns the nearest integer to an `f64`. Rounds half-way cases to the number
+    /// with an even least significant digit.
+    ///
+    /// This intrinsic does not have a stable counterpart.
+    #[cfg(not(bootstrap))]
+    pub fn round_ties_even_f64(x: f64) -> f64;
+
     /// Float addition that allows optimizations based on algebraic rules.
     /// May assume inputs are finite.
     ///
@@ -1626,6 +1778,7 @@ pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
     /// (<https://github.com/rust-lang/rust/issues/10184>)
     ///
     /// Stabilized as [`f32::to_int_unchecked`] and [`f64::to_int_unchecked`].
+    #[rustc_const_unstable(feature = "const_float_to_int_unchecked", issue = "none")]
     pub fn float_to_int_unchecked<Float: Copy, Int: Copy>(value: Float) -> Int;
 
     /// Returns the number of bits set in an integer type `T`
@@ -1967,6 +2120,24 @@ pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
     #[rustc_const_stable(feature = "const_int_saturating", since = "1.40.0")]
     pub fn saturating_sub<T: Copy>(a: T, b: T) -> T;
 
+    /// Computes `multiplier * multiplicand + addend + carry` without any
+    /// overflow, returning the low and high halves of the double-width result
+    /// (in that order).
+    ///
+    /// This can never overflow: even with all inputs at `T::MAX`, the result
+    /// fits in twice the width of `T`. `T` must be an unsigned integer type.
+    ///
+    /// Note that, unlike most intrinsics, this is safe to call;
+    /// it does not require an `unsafe` block.
+    /// Therefore, implementations must not require the user to uphold
+    /// any safety invariants.
+    ///
+    /// This intrinsic does not have a stable counterpart.
+    #[cfg(not(bootstrap))]
+    #[rustc_const_unstable(feature = "const_carrying_mul_add", issue = "none")]
+    pub fn carrying_mul_add<T: Copy>(multiplier: T, multiplicand: T, addend: T, carry: T)
+        -> (T, T);
+
     /// Returns the value of the discriminant for the variant in 'v';
     /// if `T` has no discriminant, returns `0`.
     ///
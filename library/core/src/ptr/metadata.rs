@@ -134,6 +134,25 @@ pub const fn from_raw_parts_mut<T: ?Sized>(
     unsafe { PtrRepr { components: PtrComponents { data_address, metadata } }.mut_ptr }
 }
 
+/// Relates a raw pointer type to the thin data pointer and metadata it is built from, for use by
+/// the `aggregate_raw_ptr` intrinsic.
+#[doc(hidden)]
+#[unstable(feature = "ptr_metadata", issue = "81513")]
+pub trait AggregateRawPtr<Data> {
+    /// The metadata that is combined with `Data` to form `Self`.
+    type Metadata: Copy;
+}
+
+#[unstable(feature = "ptr_metadata", issue = "81513")]
+impl<P: ?Sized, T: Thin> AggregateRawPtr<*const T> for *const P {
+    type Metadata = <P as Pointee>::Metadata;
+}
+
+#[unstable(feature = "ptr_metadata", issue = "81513")]
+impl<P: ?Sized, T: Thin> AggregateRawPtr<*mut T> for *mut P {
+    type Metadata = <P as Pointee>::Metadata;
+}
+
 #[repr(C)]
 pub(crate) union PtrRepr<T: ?Sized> {
     pub(crate) const_ptr: *const T,
@@ -3566,6 +3566,9 @@ pub unsafe fn align_to_mut<U>(&mut self) -> (&mut [T], &mut [U], &mut [T]) {
     /// That said, this is a safe method, so if you're only writing safe code,
     /// then this can at most cause incorrect logic, not unsoundness.
     ///
+    /// A `prefix` or `suffix` shorter than `LANES` can still be loaded as a
+    /// vector with [`Simd::load_or_default`], which fills the missing lanes.
+    ///
     /// # Panics
     ///
     /// This will panic if the size of the SIMD type is different from
@@ -3670,6 +3673,50 @@ pub fn as_simd_mut<const LANES: usize>(&mut self) -> (&mut [T], &mut [Simd<T, LA
         unsafe { self.align_to_mut() }
     }
 
+    /// Reads the element at each index in `idxs` into a SIMD vector, like
+    /// indexing the slice once per lane.
+    ///
+    /// This is a gather, so the indices may be in any order and may repeat.
+    /// To get a fallback value for out-of-bounds indices instead of a panic,
+    /// use [`Simd::gather_or`] or [`Simd::gather_or_default`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    /// use core::simd::Simd;
+    ///
+    /// let table = [10, 11, 12, 13, 14];
+    /// let idxs = Simd::from_array([4, 0, 0, 2]);
+    /// assert_eq!(table.simd_index(idxs), Simd::from_array([14, 10, 10, 12]));
+    /// ```
+    #[unstable(feature = "portable_simd", issue = "86656")]
+    #[must_use]
+    #[track_caller]
+    pub fn simd_index<const LANES: usize>(&self, idxs: Simd<usize, LANES>) -> Simd<T, LANES>
+    where
+        T: simd::SimdElement,
+        simd::LaneCount<LANES>: simd::SupportedLaneCount,
+    {
+        use simd::SimdPartialOrd;
+
+        let len = self.len();
+        assert!(
+            idxs.simd_lt(Simd::splat(len)).all(),
+            "index out of bounds: the len is {len} but an index is not less than it"
+        );
+        // SAFETY: every index was just checked to be in bounds, which also
+        // means the slice is not empty. All lanes are enabled, so the `or`
+        // vector is never used.
+        unsafe {
+            Simd::gather_select_unchecked(self, simd::Mask::splat(true), idxs, Simd::splat(self[0]))
+        }
+    }
+
     /// Checks if the elements of this slice are sorted.
     ///
     /// That is, for each element `a` and its following element `b`, `a <= b` must hold. If the
@@ -12,3 +12,22 @@ fn testing() {
     assert_eq!(x, r);
     assert_eq!(h, f32x4::splat(0.5));
 }
+
+#[test]
+fn simd_index() {
+    use core::simd::Simd;
+
+    let table = [1u8, 2, 4, 8, 16, 32, 64, 128];
+    let idxs = Simd::from_array([7, 0, 3, 3]);
+    assert_eq!(table.simd_index(idxs), Simd::from_array([128, 1, 8, 8]));
+    assert_eq!(table[2..].simd_index(Simd::from_array([0, 5])), Simd::from_array([4, 128]));
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn simd_index_out_of_bounds() {
+    use core::simd::Simd;
+
+    let table = [1u8, 2, 4, 8];
+    let _ = table.simd_index(Simd::from_array([0, 1, 2, 4]));
+}
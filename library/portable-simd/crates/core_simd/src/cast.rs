@@ -0,0 +1,22 @@
+use crate::simd::SimdElement;
+
+mod sealed {
+    pub trait Sealed {}
+}
+use sealed::Sealed;
+
+/// Supporting trait for [`Simd::cast`](crate::simd::Simd::cast), implemented by the numeric
+/// element types. Vectors of pointers are cast with their own methods instead, like `cast_ptr`
+/// and [`SimdConstPtr::expose_addr`](crate::simd::SimdConstPtr::expose_addr).
+pub trait SimdCast: Sealed + SimdElement {}
+
+macro_rules! impl_simd_cast {
+    { $($ty:ty),* } => {
+        $(
+        impl Sealed for $ty {}
+        impl SimdCast for $ty {}
+        )*
+    }
+}
+
+impl_simd_cast! { i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64 }
@@ -0,0 +1,116 @@
+use crate::simd::{LaneCount, Mask, Simd, SimdElement, SupportedLaneCount};
+
+impl<T, const N: usize> Simd<T, N>
+where
+    T: SimdElement + Default,
+    LaneCount<N>: SupportedLaneCount,
+{
+    /// Moves the enabled lanes of `self` to the front of the vector, keeping their order.
+    /// The remaining lanes are set to the default value for the type.
+    ///
+    /// This matches compress instructions like AVX-512's `vpcompressd` or SVE's `compact`,
+    /// and is useful for filtering: the number of lanes kept is `enable.count_set()`.
+    ///
+    /// Note that the current implementation is selected during build-time
+    /// of the standard library, so `cargo build -Zbuild-std` may be necessary
+    /// to unlock better performance, especially for larger vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Mask, Simd};
+    /// let values = Simd::from_array([10, 11, 12, 13]);
+    /// let enable = Mask::from_array([false, true, false, true]);
+    /// assert_eq!(values.compress(enable).to_array(), [11, 13, 0, 0]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original inputs"]
+    pub fn compress(self, enable: Mask<<T as SimdElement>::Mask, N>) -> Self {
+        #[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+        // Safety: AVX-512F is enabled, and each arm only transmutes between types of the size
+        // it matched on
+        unsafe {
+            use crate::simd::ToBitMask;
+            use core::{arch::x86_64 as x86, mem};
+            match (mem::size_of::<T>(), N) {
+                (4, 16) => {
+                    let enable = mem::transmute_copy::<_, Mask<i32, 16>>(&enable).to_bitmask();
+                    let values = mem::transmute_copy(&self);
+                    return mem::transmute_copy(&x86::_mm512_maskz_compress_epi32(enable, values));
+                }
+                (8, 8) => {
+                    let enable = mem::transmute_copy::<_, Mask<i64, 8>>(&enable).to_bitmask();
+                    let values = mem::transmute_copy(&self);
+                    return mem::transmute_copy(&x86::_mm512_maskz_compress_epi64(enable, values));
+                }
+                _ => {}
+            }
+        }
+        let mut array = [T::default(); N];
+        let mut kept = 0;
+        for (value, enabled) in self.to_array().into_iter().zip(enable.to_array()) {
+            if enabled {
+                array[kept] = value;
+                kept += 1;
+            }
+        }
+        Self::from_array(array)
+    }
+
+    /// Moves the lanes at the front of `self` into the enabled lanes, keeping their order.
+    /// Disabled lanes are set to the default value for the type.
+    ///
+    /// This is the inverse of [`Self::compress`], and matches expand instructions like
+    /// AVX-512's `vpexpandd`.
+    ///
+    /// Note that the current implementation is selected during build-time
+    /// of the standard library, so `cargo build -Zbuild-std` may be necessary
+    /// to unlock better performance, especially for larger vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Mask, Simd};
+    /// let values = Simd::from_array([10, 11, 12, 13]);
+    /// let enable = Mask::from_array([false, true, false, true]);
+    /// assert_eq!(values.expand(enable).to_array(), [0, 10, 0, 11]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original inputs"]
+    pub fn expand(self, enable: Mask<<T as SimdElement>::Mask, N>) -> Self {
+        #[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+        // Safety: AVX-512F is enabled, and each arm only transmutes between types of the size
+        // it matched on
+        unsafe {
+            use crate::simd::ToBitMask;
+            use core::{arch::x86_64 as x86, mem};
+            match (mem::size_of::<T>(), N) {
+                (4, 16) => {
+                    let enable = mem::transmute_copy::<_, Mask<i32, 16>>(&enable).to_bitmask();
+                    let values = mem::transmute_copy(&self);
+                    return mem::transmute_copy(&x86::_mm512_maskz_expand_epi32(enable, values));
+                }
+                (8, 8) => {
+                    let enable = mem::transmute_copy::<_, Mask<i64, 8>>(&enable).to_bitmask();
+                    let values = mem::transmute_copy(&self);
+                    return mem::transmute_copy(&x86::_mm512_maskz_expand_epi64(enable, values));
+                }
+                _ => {}
+            }
+        }
+        let values = self.to_array();
+        let mut array = [T::default(); N];
+        let mut taken = 0;
+        for (lane, enabled) in array.iter_mut().zip(enable.to_array()) {
+            if enabled {
+                *lane = values[taken];
+                taken += 1;
+            }
+        }
+        Self::from_array(array)
+    }
+}
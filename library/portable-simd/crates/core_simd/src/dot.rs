@@ -0,0 +1,57 @@
+use crate::simd::{LaneCount, Simd, SupportedLaneCount};
+
+macro_rules! impl_dot_widening {
+    { $($ty:ty => $wide:ty, $arm_dot:ident);* } => {
+        $(
+        impl<const LANES: usize> Simd<$ty, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+            LaneCount<{ LANES / 4 }>: SupportedLaneCount,
+        {
+            /// Multiplies the lanes of `self` and `rhs`, and sums each group of four consecutive
+            /// products into a lane of a vector with four times wider lanes.
+            ///
+            /// The products and sums are computed in the wide type, so they never overflow.
+            /// This matches dot product instructions like AArch64's `sdot` and `udot`, which are
+            /// used for 16-lane vectors when the `dotprod` target feature is enabled.
+            ///
+            /// # Examples
+            /// ```
+            /// # #![feature(portable_simd)]
+            /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+            /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+            /// # use simd::Simd;
+            #[doc = concat!("let x = Simd::<", stringify!($ty), ", 8>::from_array([1, 2, 3, 4, 5, 6, 7, 8]);")]
+            /// let y = Simd::from_array([1, 1, 1, 1, 2, 0, 2, 0]);
+            /// assert_eq!(x.dot_widening(y).to_array(), [10, 24]);
+            /// ```
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original inputs"]
+            pub fn dot_widening(self, rhs: Self) -> Simd<$wide, { LANES / 4 }> {
+                #[cfg(all(target_arch = "aarch64", target_feature = "dotprod"))]
+                if LANES == 16 {
+                    use core::arch::aarch64;
+                    use core::mem;
+                    // Safety: the vectors are 16 lanes of bytes and 4 lanes of 32-bit integers,
+                    // matching the intrinsic, and the `dotprod` feature is enabled
+                    return unsafe {
+                        mem::transmute_copy(&aarch64::$arm_dot(
+                            mem::transmute_copy(&Simd::<$wide, 4>::splat(0)),
+                            mem::transmute_copy(&self),
+                            mem::transmute_copy(&rhs),
+                        ))
+                    };
+                }
+                let products = (self.cast::<$wide>() * rhs.cast::<$wide>()).to_array();
+                let mut sums = [0; LANES / 4];
+                for (sum, group) in sums.iter_mut().zip(products.chunks_exact(4)) {
+                    *sum = group.iter().sum();
+                }
+                Simd::from_array(sums)
+            }
+        }
+        )*
+    }
+}
+
+impl_dot_widening! { i8 => i32, vdotq_s32; u8 => u32, vdotq_u32 }
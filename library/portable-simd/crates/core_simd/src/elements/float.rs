@@ -3,6 +3,8 @@
     intrinsics, LaneCount, Mask, Simd, SimdElement, SimdPartialEq, SimdPartialOrd,
     SupportedLaneCount,
 };
+use core::mem;
+use core::num::FpCategory;
 
 /// Operations on SIMD vectors of floats.
 pub trait SimdFloat: Copy + Sealed {
@@ -34,6 +36,25 @@ pub trait SimdFloat: Copy + Sealed {
     #[must_use = "method returns a new vector and does not mutate the original value"]
     fn recip(self) -> Self;
 
+    /// Takes an approximate reciprocal (inverse) of each lane, `1/x`.
+    ///
+    /// Uses the target's reciprocal estimate instruction where one is available, such as
+    /// `rcpps` on x86 or a refined `frecpe` on AArch64, and the precise [`recip`](Self::recip)
+    /// otherwise. For finite, nonzero lanes the result has a relative error of at most
+    /// 1.5 × 2<sup>-12</sup>. Zero becomes an infinity with the same sign and infinities become
+    /// zero, like `recip`. The exact result may differ between targets.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn recip_fast(self) -> Self;
+
+    /// Takes an approximate reciprocal square root of each lane, `1/sqrt(x)`.
+    ///
+    /// Uses the target's reciprocal square root estimate instruction where one is available,
+    /// such as `rsqrtps` on x86 or a refined `frsqrte` on AArch64, and a precise square root
+    /// and division otherwise. For positive, finite lanes the result has a relative error of at
+    /// most 1.5 × 2<sup>-12</sup>. The exact result may differ between targets.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn rsqrt_fast(self) -> Self;
+
     /// Converts each lane from radians to degrees.
     #[must_use = "method returns a new vector and does not mutate the original value"]
     fn to_degrees(self) -> Self;
@@ -73,6 +94,53 @@ pub trait SimdFloat: Copy + Sealed {
     #[must_use = "method returns a new mask and does not mutate the original value"]
     fn is_normal(self) -> Self::Mask;
 
+    /// Returns true for each lane if its floating point category is `category`, as reported
+    /// by the scalar `classify` method.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{f32x4, Mask, SimdFloat};
+    /// use core::num::FpCategory;
+    /// let x = f32x4::from_array([-0.0, 1.0e-40, f32::NAN, f32::INFINITY]);
+    /// let zero = x.classify(FpCategory::Zero);
+    /// let subnormal = x.classify(FpCategory::Subnormal);
+    /// assert_eq!(zero, Mask::from_array([true, false, false, false]));
+    /// assert_eq!(subnormal, Mask::from_array([false, true, false, false]));
+    /// assert_eq!(x.classify(FpCategory::Normal), Mask::splat(false));
+    /// ```
+    #[must_use = "method returns a new mask and does not mutate the original value"]
+    fn classify(self, category: FpCategory) -> Self::Mask;
+
+    /// Returns true for each lane where `self` is less than `other` under the total order
+    /// of [`f32::total_cmp`] and [`f64::total_cmp`].
+    ///
+    /// Unlike `simd_lt`, this orders `-0.0` before `+0.0`, and sorts `NaN`s by their sign,
+    /// placing negative `NaN`s before every other value and positive `NaN`s after.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{f32x4, Mask, SimdFloat};
+    /// let x = f32x4::from_array([-0.0, 1.0, f32::NAN, -f32::NAN]);
+    /// let y = f32x4::from_array([0.0, 1.0, f32::INFINITY, f32::NEG_INFINITY]);
+    /// assert_eq!(x.total_lt(y), Mask::from_array([true, false, false, true]));
+    /// assert_eq!(x.total_gt(y), Mask::from_array([false, false, true, false]));
+    /// ```
+    #[must_use = "method returns a new mask and does not mutate the original value"]
+    fn total_lt(self, other: Self) -> Self::Mask;
+
+    /// Returns true for each lane where `self` is greater than `other` under the total order
+    /// of [`f32::total_cmp`] and [`f64::total_cmp`].
+    ///
+    /// See [`total_lt`](Self::total_lt) for details.
+    #[must_use = "method returns a new mask and does not mutate the original value"]
+    fn total_gt(self, other: Self) -> Self::Mask;
+
     /// Replaces each lane with a number that represents its sign.
     ///
     /// * `1.0` if the number is positive, `+0.0`, or `INFINITY`
@@ -87,6 +155,88 @@ pub trait SimdFloat: Copy + Sealed {
     #[must_use = "method returns a new vector and does not mutate the original value"]
     fn copysign(self, sign: Self) -> Self;
 
+    /// Returns the smallest integer greater than or equal to each lane.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn ceil(self) -> Self;
+
+    /// Returns the largest integer less than or equal to each lane.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn floor(self) -> Self;
+
+    /// Rounds each lane to the nearest integer. Ties round away from zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{f32x4, SimdFloat};
+    /// let x = f32x4::from_array([0.5, 1.5, -2.5, 2.4]);
+    /// assert_eq!(x.round(), f32x4::from_array([1.0, 2.0, -3.0, 2.0]));
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn round(self) -> Self;
+
+    /// Returns the integer part of each lane, removing its fractional part.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn trunc(self) -> Self;
+
+    /// Returns the fractional part of each lane, removing its integer part.
+    ///
+    /// This is `self - self.trunc()`, so the result has the same sign as `self`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn fract(self) -> Self;
+
+    /// Lanewise midpoint, `(self + second) / 2`, without overflowing for large lanes.
+    ///
+    /// The result is the correctly rounded average unless it is subnormal. If either lane is
+    /// `NAN`, the result is `NAN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{f32x4, SimdFloat};
+    /// let x = f32x4::from_array([1.0, -4.0, f32::MAX, f32::MAX]);
+    /// let y = f32x4::from_array([2.0, 8.0, f32::MAX, -f32::MAX]);
+    /// assert_eq!(x.midpoint(y), f32x4::from_array([1.5, 2.0, f32::MAX, 0.0]));
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn midpoint(self, second: Self) -> Self;
+
+    /// Fused multiply-add.  Computes `(self * a) + b` with only one rounding error,
+    /// yielding a more accurate result than an unfused multiply-add.
+    ///
+    /// Using `mul_add` *may* be more performant than an unfused multiply-add if the target
+    /// architecture has a dedicated `fma` CPU instruction.  Otherwise, it is lowered to calls to
+    /// libm's `fmaf` or `fma`, which are much slower than
+    /// [`relaxed_mul_add`](Self::relaxed_mul_add), and which `#![no_std]` crates have to link
+    /// in themselves.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{f32x2, SimdFloat};
+    /// // `x * x` is `1 + 2 * EPSILON + EPSILON * EPSILON`, which rounds to `1 + 2 * EPSILON`.
+    /// let x = f32x2::splat(1.0 + f32::EPSILON);
+    /// let y = f32x2::splat(-(1.0 + 2.0 * f32::EPSILON));
+    /// assert_eq!(x.mul_add(x, y), f32x2::splat(f32::EPSILON * f32::EPSILON));
+    /// assert_eq!(x.relaxed_mul_add(x, y), f32x2::splat(0.0));
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn mul_add(self, a: Self, b: Self) -> Self;
+
+    /// Unfused multiply-add.  Computes `(self * a) + b` with two rounding errors, exactly like
+    /// writing out the multiplication and the addition.
+    ///
+    /// Unlike [`mul_add`](Self::mul_add), this never needs a software fallback, so it is the
+    /// better choice when the extra precision is not needed and the target may lack `fma`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn relaxed_mul_add(self, a: Self, b: Self) -> Self;
+
     /// Returns the minimum of each lane.
     ///
     /// If one of the values is `NAN`, then the other value is returned.
@@ -104,6 +254,11 @@ pub trait SimdFloat: Copy + Sealed {
     /// For each lane in `self`, returns the corresponding lane in `max` if the lane is
     /// greater than `max`, and the corresponding lane in `min` if the lane is less
     /// than `min`.  Otherwise returns the lane in `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max` on any lane, or if any lane of `min` or `max` is NaN, like
+    /// the scalar `clamp`.
     #[must_use = "method returns a new vector and does not mutate the original value"]
     fn simd_clamp(self, min: Self, max: Self) -> Self;
 
@@ -135,6 +290,46 @@ pub trait SimdFloat: Copy + Sealed {
     /// ```
     fn reduce_product(self) -> Self::Scalar;
 
+    /// Returns the sum of the lanes of the vector, added in a fixed pairwise order.
+    ///
+    /// Each step adds the upper half of the remaining lanes to the lower half, lane by lane,
+    /// until one lane is left. For four lanes this is `(x[0] + x[2]) + (x[1] + x[3])`.
+    ///
+    /// This takes `log2(LANES)` vector additions, so it is usually faster than
+    /// [`reduce_sum`](Self::reduce_sum), which adds the lanes in order. Since floating point
+    /// addition is not associative the two may round differently, but neither depends on the
+    /// target or optimization level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{f32x4, SimdFloat};
+    /// let v = f32x4::from_array([1e8, 1., -1e8, 1.]);
+    /// assert_eq!(v.reduce_sum_tree(), (1e8 + -1e8) + (1. + 1.));
+    /// assert_eq!(v.reduce_sum_tree(), 2.);
+    /// assert_eq!(v.reduce_sum(), 1.);
+    /// ```
+    fn reduce_sum_tree(self) -> Self::Scalar;
+
+    /// Returns the product of the lanes of the vector, multiplied in a fixed pairwise order.
+    ///
+    /// The lanes are combined in the same order as [`reduce_sum_tree`](Self::reduce_sum_tree).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{f32x4, SimdFloat};
+    /// let v = f32x4::from_array([2., 3., 4., 5.]);
+    /// assert_eq!(v.reduce_product_tree(), 120.);
+    /// ```
+    fn reduce_product_tree(self) -> Self::Scalar;
+
     /// Returns the maximum lane in the vector.
     ///
     /// Returns values based on equality, so a vector containing both `0.` and `-0.` may
@@ -199,6 +394,21 @@ impl<const LANES: usize> Sealed for Simd<$ty, LANES>
         {
         }
 
+        impl<const LANES: usize> Simd<$ty, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            /// Maps each lane to a signed integer that orders like `total_cmp`, by flipping
+            /// all but the sign bit of negative lanes.
+            #[inline]
+            fn total_order_key(self) -> Simd<$mask_ty, LANES> {
+                let bits = self.to_bits().cast::<$mask_ty>();
+                // All ones for negative lanes, all zeros otherwise
+                let sign = bits >> Simd::splat(<$mask_ty>::BITS as $mask_ty - 1);
+                bits ^ (sign.cast::<$bits_ty>() >> Simd::splat(1)).cast::<$mask_ty>()
+            }
+        }
+
         impl<const LANES: usize> SimdFloat for Simd<$ty, LANES>
         where
             LaneCount<LANES>: SupportedLaneCount,
@@ -232,6 +442,19 @@ fn recip(self) -> Self {
                 Self::splat(1.0) / self
             }
 
+            #[inline]
+            fn recip_fast(self) -> Self {
+                self.recip_estimate().unwrap_or_else(|| self.recip())
+            }
+
+            #[inline]
+            fn rsqrt_fast(self) -> Self {
+                self.rsqrt_estimate().unwrap_or_else(|| {
+                    // Safety: `self` is a float vector
+                    unsafe { intrinsics::simd_fsqrt(self) }.recip()
+                })
+            }
+
             #[inline]
             fn to_degrees(self) -> Self {
                 // to_degrees uses a special constant for better precision, so extract that constant
@@ -280,6 +503,27 @@ fn is_normal(self) -> Self::Mask {
                 !(self.abs().simd_eq(Self::splat(0.0)) | self.is_nan() | self.is_subnormal() | self.is_infinite())
             }
 
+            #[inline]
+            fn total_lt(self, other: Self) -> Self::Mask {
+                self.total_order_key().simd_lt(other.total_order_key())
+            }
+
+            #[inline]
+            fn total_gt(self, other: Self) -> Self::Mask {
+                self.total_order_key().simd_gt(other.total_order_key())
+            }
+
+            #[inline]
+            fn classify(self, category: FpCategory) -> Self::Mask {
+                match category {
+                    FpCategory::Nan => self.is_nan(),
+                    FpCategory::Infinite => self.is_infinite(),
+                    FpCategory::Zero => self.abs().simd_eq(Self::splat(0.0)),
+                    FpCategory::Subnormal => self.is_subnormal(),
+                    FpCategory::Normal => self.is_normal(),
+                }
+            }
+
             #[inline]
             fn signum(self) -> Self {
                 self.is_nan().select(Self::splat(Self::Scalar::NAN), Self::splat(1.0).copysign(self))
@@ -292,6 +536,64 @@ fn copysign(self, sign: Self) -> Self {
                 Self::from_bits(sign_bit | magnitude)
             }
 
+            #[inline]
+            fn ceil(self) -> Self {
+                // Safety: `self` is a float vector
+                unsafe { intrinsics::simd_ceil(self) }
+            }
+
+            #[inline]
+            fn floor(self) -> Self {
+                // Safety: `self` is a float vector
+                unsafe { intrinsics::simd_floor(self) }
+            }
+
+            #[inline]
+            fn round(self) -> Self {
+                // Safety: `self` is a float vector
+                unsafe { intrinsics::simd_round(self) }
+            }
+
+            #[inline]
+            fn trunc(self) -> Self {
+                // Safety: `self` is a float vector
+                unsafe { intrinsics::simd_trunc(self) }
+            }
+
+            #[inline]
+            fn fract(self) -> Self {
+                self - self.trunc()
+            }
+
+            #[inline]
+            fn midpoint(self, second: Self) -> Self {
+                // Halve before adding when the sum could overflow, but not the lanes that are so
+                // small that halving them would lose precision.
+                let high = Self::splat(<$ty>::MAX / 2.);
+                let low = Self::splat(<$ty>::MIN_POSITIVE * 2.);
+                let two = Self::splat(2.);
+                let (abs_a, abs_b) = (self.abs(), second.abs());
+                let fits = abs_a.simd_le(high) & abs_b.simd_le(high);
+                fits.select(
+                    (self + second) / two,
+                    abs_a.simd_lt(low).select(
+                        self + second / two,
+                        abs_b.simd_lt(low).select(self / two + second, self / two + second / two),
+                    ),
+                )
+            }
+
+            #[inline]
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                // Safety: `self`, `a` and `b` are float vectors
+                unsafe { intrinsics::simd_fma(self, a, b) }
+            }
+
+            #[inline]
+            fn relaxed_mul_add(self, a: Self, b: Self) -> Self {
+                self * a + b
+            }
+
             #[inline]
             fn simd_min(self, other: Self) -> Self {
                 // Safety: `self` and `other` are float vectors
@@ -338,6 +640,16 @@ fn reduce_product(self) -> Self::Scalar {
                 }
             }
 
+            #[inline]
+            fn reduce_sum_tree(self) -> Self::Scalar {
+                reduce_tree(self, |x, y| x + y)
+            }
+
+            #[inline]
+            fn reduce_product_tree(self) -> Self::Scalar {
+                reduce_tree(self, |x, y| x * y)
+            }
+
             #[inline]
             fn reduce_max(self) -> Self::Scalar {
                 // Safety: `self` is a float vector
@@ -355,3 +667,140 @@ fn reduce_min(self) -> Self::Scalar {
 }
 
 impl_trait! { f32 { bits: u32, mask: i32 }, f64 { bits: u64, mask: i64 } }
+
+/// Combines the lanes of `x` with `f` in pairwise order, by repeatedly combining the lower half
+/// of the remaining lanes with the upper half, and returns the last remaining lane.
+#[inline]
+fn reduce_tree<T, const LANES: usize>(
+    mut x: Simd<T, LANES>,
+    f: impl Fn(Simd<T, LANES>, Simd<T, LANES>) -> Simd<T, LANES>,
+) -> T
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    // `LANES` is a power of two, so the first step that runs pairs up the two halves, and each
+    // later step only needs the lower lanes of the previous one to be correct.
+    if LANES > 32 {
+        x = f(x, x.rotate_elements_left::<32>());
+    }
+    if LANES > 16 {
+        x = f(x, x.rotate_elements_left::<16>());
+    }
+    if LANES > 8 {
+        x = f(x, x.rotate_elements_left::<8>());
+    }
+    if LANES > 4 {
+        x = f(x, x.rotate_elements_left::<4>());
+    }
+    if LANES > 2 {
+        x = f(x, x.rotate_elements_left::<2>());
+    }
+    if LANES > 1 {
+        x = f(x, x.rotate_elements_left::<1>());
+    }
+    x.as_array()[0]
+}
+
+/// Hardware estimates of `1/x` and `1/sqrt(x)`, for the vector types that the target has
+/// instructions for.
+///
+/// Every estimate is accurate to a relative error of 1.5 × 2<sup>-12</sup>, and maps zeros and
+/// infinities like the precise operations do.
+trait Estimate: Sized {
+    #[inline]
+    fn recip_estimate(self) -> Option<Self> {
+        None
+    }
+
+    #[inline]
+    fn rsqrt_estimate(self) -> Option<Self> {
+        None
+    }
+}
+
+// Only `f32` lanes have estimate instructions.
+impl<const LANES: usize> Estimate for Simd<f64, LANES> where LaneCount<LANES>: SupportedLaneCount {}
+
+#[allow(unused_imports)]
+impl<const LANES: usize> Estimate for Simd<f32, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn recip_estimate(self) -> Option<Self> {
+        #[cfg(target_arch = "aarch64")]
+        use core::arch::aarch64;
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64 as x86;
+
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"))]
+        if LANES == 4 {
+            // Safety: `self` is an `f32x4` and SSE is enabled
+            return Some(unsafe {
+                mem::transmute_copy(&x86::_mm_rcp_ps(mem::transmute_copy(&self)))
+            });
+        }
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "avx"))]
+        if LANES == 8 {
+            // Safety: `self` is an `f32x8` and AVX is enabled
+            return Some(unsafe {
+                mem::transmute_copy(&x86::_mm256_rcp_ps(mem::transmute_copy(&self)))
+            });
+        }
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        if LANES == 4 {
+            // `frecpe` is only accurate to 8 bits, so refine it with a Newton-Raphson step.
+            // `frecps` returns 2 for `0 * inf`, so zeros and infinities stay exact.
+            // Safety: `self` is an `f32x4` and NEON is enabled
+            return Some(unsafe {
+                let x = mem::transmute_copy(&self);
+                let estimate = aarch64::vrecpeq_f32(x);
+                let refined = aarch64::vmulq_f32(estimate, aarch64::vrecpsq_f32(x, estimate));
+                mem::transmute_copy(&refined)
+            });
+        }
+        None
+    }
+
+    #[inline]
+    fn rsqrt_estimate(self) -> Option<Self> {
+        #[cfg(target_arch = "aarch64")]
+        use core::arch::aarch64;
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64 as x86;
+
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"))]
+        if LANES == 4 {
+            // Safety: `self` is an `f32x4` and SSE is enabled
+            return Some(unsafe {
+                mem::transmute_copy(&x86::_mm_rsqrt_ps(mem::transmute_copy(&self)))
+            });
+        }
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "avx"))]
+        if LANES == 8 {
+            // Safety: `self` is an `f32x8` and AVX is enabled
+            return Some(unsafe {
+                mem::transmute_copy(&x86::_mm256_rsqrt_ps(mem::transmute_copy(&self)))
+            });
+        }
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        if LANES == 4 {
+            // `frsqrte` is only accurate to 8 bits, so refine it with a Newton-Raphson step.
+            // Squaring the estimate first means the only product of zero and infinity is the
+            // one `frsqrts` sees, which it defines as 1.5, so zeros and infinities stay exact.
+            // Safety: `self` is an `f32x4` and NEON is enabled
+            return Some(unsafe {
+                let x = mem::transmute_copy(&self);
+                let estimate = aarch64::vrsqrteq_f32(x);
+                let step = aarch64::vrsqrtsq_f32(x, aarch64::vmulq_f32(estimate, estimate));
+                mem::transmute_copy(&aarch64::vmulq_f32(estimate, step))
+            });
+        }
+        None
+    }
+}
@@ -1,6 +1,7 @@
 use super::sealed::Sealed;
 use crate::simd::{
-    intrinsics, LaneCount, Mask, Simd, SimdElement, SimdPartialOrd, SupportedLaneCount,
+    intrinsics, LaneCount, Mask, Simd, SimdElement, SimdPartialEq, SimdPartialOrd, SimdUint,
+    SupportedLaneCount,
 };
 
 /// Operations on SIMD vectors of signed integers.
@@ -11,6 +12,9 @@ pub trait SimdInt: Copy + Sealed {
     /// Scalar type contained by this SIMD vector type.
     type Scalar;
 
+    /// Vector of unsigned integers of the same width, used for bit counts.
+    type Unsigned;
+
     /// Lanewise saturating add.
     ///
     /// # Examples
@@ -44,8 +48,105 @@ pub trait SimdInt: Copy + Sealed {
     /// let sat = x.saturating_sub(max);
     /// assert_eq!(unsat, Simd::from_array([1, MAX, MIN, 0]));
     /// assert_eq!(sat, Simd::from_array([MIN, MIN, MIN, 0]));
+    /// ```
     fn saturating_sub(self, second: Self) -> Self;
 
+    /// Lanewise overflowing add.
+    ///
+    /// Returns the wrapped result along with a mask indicating which lanes overflowed.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Mask, Simd, SimdInt};
+    /// use core::i32::{MIN, MAX};
+    /// let x = Simd::from_array([MIN, 0, 1, MAX]);
+    /// let (sum, overflowed) = x.overflowing_add(Simd::splat(MAX));
+    /// assert_eq!(sum, Simd::from_array([-1, MAX, MIN, -2]));
+    /// assert_eq!(overflowed, Mask::from_array([false, false, true, true]));
+    /// ```
+    fn overflowing_add(self, second: Self) -> (Self, Self::Mask);
+
+    /// Lanewise overflowing subtract.
+    ///
+    /// Returns the wrapped result along with a mask indicating which lanes overflowed.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Mask, Simd, SimdInt};
+    /// use core::i32::{MIN, MAX};
+    /// let x = Simd::from_array([MIN, -2, -1, MAX]);
+    /// let (diff, overflowed) = x.overflowing_sub(Simd::splat(MAX));
+    /// assert_eq!(diff, Simd::from_array([1, MAX, MIN, 0]));
+    /// assert_eq!(overflowed, Mask::from_array([true, true, false, false]));
+    /// ```
+    fn overflowing_sub(self, second: Self) -> (Self, Self::Mask);
+
+    /// Lanewise overflowing multiply.
+    ///
+    /// Returns the wrapped result along with a mask indicating which lanes overflowed.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Mask, Simd, SimdInt};
+    /// use core::i32::{MIN, MAX};
+    /// let x = Simd::from_array([MIN, -1, 1 << 30, MAX]);
+    /// let (product, overflowed) = x.overflowing_mul(Simd::from_array([-1, MIN, -2, 1]));
+    /// assert_eq!(product, Simd::from_array([MIN, MIN, MIN, MAX]));
+    /// assert_eq!(overflowed, Mask::from_array([true, true, false, false]));
+    /// ```
+    fn overflowing_mul(self, second: Self) -> (Self, Self::Mask);
+
+    /// Lanewise wrapping add.
+    ///
+    /// This is the same as the `+` operator, which always wraps for integer vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdInt};
+    /// use core::i32::{MIN, MAX};
+    /// let x = Simd::from_array([MIN, 0, 1, MAX]);
+    /// assert_eq!(x.wrapping_add(Simd::splat(MAX)), Simd::from_array([-1, MAX, MIN, -2]));
+    /// ```
+    fn wrapping_add(self, second: Self) -> Self;
+
+    /// Lanewise wrapping subtract.
+    ///
+    /// This is the same as the `-` operator, which always wraps for integer vectors.
+    fn wrapping_sub(self, second: Self) -> Self;
+
+    /// Lanewise wrapping multiply.
+    ///
+    /// This is the same as the `*` operator, which always wraps for integer vectors.
+    fn wrapping_mul(self, second: Self) -> Self;
+
+    /// Lanewise wrapping negation.
+    ///
+    /// This is the same as the `-` operator, which wraps `MIN` around to itself.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdInt};
+    /// use core::i32::{MIN, MAX};
+    /// let x = Simd::from_array([MIN, -1, 0, MAX]);
+    /// assert_eq!(x.wrapping_neg(), Simd::from_array([MIN, 1, 0, MIN + 1]));
+    /// ```
+    fn wrapping_neg(self) -> Self;
+
     /// Lanewise absolute value, implemented in Rust.
     /// Every lane becomes its absolute value.
     ///
@@ -97,6 +198,38 @@ pub trait SimdInt: Copy + Sealed {
     /// ```
     fn saturating_neg(self) -> Self;
 
+    /// Lanewise absolute difference, as an unsigned integer.
+    ///
+    /// Unlike `(self - second).abs()`, this never overflows, so it works for any pair of lanes.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdInt};
+    /// let x = Simd::<i8, 4>::from_array([10, -10, i8::MIN, i8::MAX]);
+    /// let y = Simd::from_array([3, 5, i8::MAX, i8::MAX]);
+    /// assert_eq!(x.abs_diff(y), Simd::from_array([7, 15, 255, 0]));
+    /// ```
+    fn abs_diff(self, second: Self) -> Self::Unsigned;
+
+    /// Lanewise midpoint, `(self + second) / 2`, without overflowing.
+    ///
+    /// Like integer division, this rounds toward zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdInt};
+    /// let x = Simd::<i8, 4>::from_array([1, -1, -4, i8::MAX]);
+    /// let y = Simd::from_array([2, -2, 1, i8::MAX]);
+    /// assert_eq!(x.midpoint(y), Simd::from_array([1, -1, -1, i8::MAX]));
+    /// ```
+    fn midpoint(self, second: Self) -> Self;
+
     /// Returns true for each positive lane and false if it is zero or negative.
     fn is_positive(self) -> Self::Mask;
 
@@ -109,6 +242,75 @@ pub trait SimdInt: Copy + Sealed {
     /// * `-1` if the number is negative
     fn signum(self) -> Self;
 
+    /// Returns the number of leading zeros in the binary representation of each lane.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdInt};
+    /// let x = Simd::<i8, 4>::from_array([0, 1, 0b0110_0000, -1]);
+    /// assert_eq!(x.leading_zeros(), Simd::from_array([8, 7, 1, 0]));
+    /// ```
+    fn leading_zeros(self) -> Self::Unsigned;
+
+    /// Returns the number of trailing zeros in the binary representation of each lane.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdInt};
+    /// let x = Simd::<i8, 4>::from_array([0, 1, 0b0110_0000, -1]);
+    /// assert_eq!(x.trailing_zeros(), Simd::from_array([8, 0, 5, 0]));
+    /// ```
+    fn trailing_zeros(self) -> Self::Unsigned;
+
+    /// Returns the number of ones in the binary representation of each lane.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdInt};
+    /// let x = Simd::<i8, 4>::from_array([0, 1, 0b0110_0000, -1]);
+    /// assert_eq!(x.count_ones(), Simd::from_array([0, 1, 2, 8]));
+    /// ```
+    fn count_ones(self) -> Self::Unsigned;
+
+    /// Returns the running sum of the lanes, with wrapping addition: lane `i` of the result is
+    /// the sum of lanes `0..=i` of `self`.
+    ///
+    /// This takes `log2(LANES)` shifts and additions.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdInt};
+    /// let x = Simd::<i8, 4>::from_array([1, -2, 3, 4]);
+    /// assert_eq!(x.prefix_sum(), Simd::from_array([1, -1, 2, 6]));
+    /// ```
+    fn prefix_sum(self) -> Self;
+
+    /// Returns the running sum of the lanes before each lane, with wrapping addition: lane `i`
+    /// of the result is the sum of lanes `0..i` of `self`, so the first lane is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdInt};
+    /// let x = Simd::<i8, 4>::from_array([1, -2, 3, 4]);
+    /// assert_eq!(x.exclusive_prefix_sum(), Simd::from_array([0, 1, -1, 2]));
+    /// ```
+    fn exclusive_prefix_sum(self) -> Self;
+
     /// Returns the sum of the lanes of the vector, with wrapping addition.
     ///
     /// # Examples
@@ -184,7 +386,7 @@ pub trait SimdInt: Copy + Sealed {
 }
 
 macro_rules! impl_trait {
-    { $($ty:ty),* } => {
+    { $($ty:ty => $unsigned:ty),* } => {
         $(
         impl<const LANES: usize> Sealed for Simd<$ty, LANES>
         where
@@ -198,6 +400,7 @@ impl<const LANES: usize> SimdInt for Simd<$ty, LANES>
         {
             type Mask = Mask<<$ty as SimdElement>::Mask, LANES>;
             type Scalar = $ty;
+            type Unsigned = Simd<$unsigned, LANES>;
 
             #[inline]
             fn saturating_add(self, second: Self) -> Self {
@@ -211,6 +414,57 @@ fn saturating_sub(self, second: Self) -> Self {
                 unsafe { intrinsics::simd_saturating_sub(self, second) }
             }
 
+            #[inline]
+            fn overflowing_add(self, second: Self) -> (Self, Self::Mask) {
+                let sum = self + second;
+                // Overflow happened iff both operands have the same sign and the sum doesn't
+                (sum, ((self ^ sum) & (second ^ sum)).is_negative())
+            }
+
+            #[inline]
+            fn overflowing_sub(self, second: Self) -> (Self, Self::Mask) {
+                let diff = self - second;
+                // Overflow happened iff the operands have different signs and the difference
+                // doesn't have the sign of `self`
+                (diff, ((self ^ second) & (self ^ diff)).is_negative())
+            }
+
+            #[inline]
+            fn overflowing_mul(self, second: Self) -> (Self, Self::Mask) {
+                let product = self * second;
+                // The product wrapped iff dividing it by `self` doesn't give back `second`.
+                // Dividing by 0 and by -1 (which overflows for `MIN`) are avoided, and
+                // multiplying by -1 only overflows for `MIN`.
+                let zero = Self::splat(0);
+                let minus_one = Self::splat(-1);
+                let by_minus_one = self.simd_eq(minus_one);
+                let checked = self.simd_ne(zero) & !by_minus_one;
+                let divisor = checked.select(self, Self::splat(1));
+                let overflowed = (checked & (product / divisor).simd_ne(second))
+                    | (by_minus_one & second.simd_eq(Self::splat(<$ty>::MIN)));
+                (product, overflowed)
+            }
+
+            #[inline]
+            fn wrapping_add(self, second: Self) -> Self {
+                self + second
+            }
+
+            #[inline]
+            fn wrapping_sub(self, second: Self) -> Self {
+                self - second
+            }
+
+            #[inline]
+            fn wrapping_mul(self, second: Self) -> Self {
+                self * second
+            }
+
+            #[inline]
+            fn wrapping_neg(self) -> Self {
+                -self
+            }
+
             #[inline]
             fn abs(self) -> Self {
                 const SHR: $ty = <$ty>::BITS as $ty - 1;
@@ -231,6 +485,24 @@ fn saturating_neg(self) -> Self {
                 Self::splat(0).saturating_sub(self)
             }
 
+            #[inline]
+            fn abs_diff(self, second: Self) -> Self::Unsigned {
+                // The difference always fits in the unsigned type, and wrapping subtraction
+                // gives the same bits whether the lanes are signed or unsigned.
+                let (a, b) = (self.cast::<$unsigned>(), second.cast::<$unsigned>());
+                self.simd_lt(second).select(b - a, a - b)
+            }
+
+            #[inline]
+            fn midpoint(self, second: Self) -> Self {
+                const SHR: $ty = <$ty>::BITS as $ty - 1;
+                // The common bits plus half of the differing bits is the average rounded down.
+                let diff = self ^ second;
+                let floor = (self & second) + (diff >> Simd::splat(1));
+                // Round negative averages with a dropped half up, toward zero.
+                floor + ((floor >> Simd::splat(SHR)) & diff & Simd::splat(1))
+            }
+
             #[inline]
             fn is_positive(self) -> Self::Mask {
                 self.simd_gt(Self::splat(0))
@@ -249,6 +521,52 @@ fn signum(self) -> Self {
                 )
             }
 
+            #[inline]
+            fn leading_zeros(self) -> Self::Unsigned {
+                self.cast::<$unsigned>().leading_zeros()
+            }
+
+            #[inline]
+            fn trailing_zeros(self) -> Self::Unsigned {
+                self.cast::<$unsigned>().trailing_zeros()
+            }
+
+            #[inline]
+            fn count_ones(self) -> Self::Unsigned {
+                self.cast::<$unsigned>().count_ones()
+            }
+
+            #[inline]
+            fn prefix_sum(self) -> Self {
+                // Each step adds the lanes `OFFSET` positions earlier, doubling the number of
+                // lanes summed into each lane.
+                let mut sum = self;
+                if LANES > 1 {
+                    sum += sum.shift_elements_right::<1>(0);
+                }
+                if LANES > 2 {
+                    sum += sum.shift_elements_right::<2>(0);
+                }
+                if LANES > 4 {
+                    sum += sum.shift_elements_right::<4>(0);
+                }
+                if LANES > 8 {
+                    sum += sum.shift_elements_right::<8>(0);
+                }
+                if LANES > 16 {
+                    sum += sum.shift_elements_right::<16>(0);
+                }
+                if LANES > 32 {
+                    sum += sum.shift_elements_right::<32>(0);
+                }
+                sum
+            }
+
+            #[inline]
+            fn exclusive_prefix_sum(self) -> Self {
+                self.prefix_sum().shift_elements_right::<1>(0)
+            }
+
             #[inline]
             fn reduce_sum(self) -> Self::Scalar {
                 // Safety: `self` is an integer vector
@@ -295,4 +613,49 @@ fn reduce_xor(self) -> Self::Scalar {
     }
 }
 
-impl_trait! { i8, i16, i32, i64, isize }
+impl_trait! { i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize }
+
+macro_rules! impl_widening_mul {
+    { $($ty:ty => $unsigned:ty, $wide:ty);* } => {
+        $(
+        impl<const LANES: usize> Simd<$ty, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            /// Lanewise widening multiply.
+            ///
+            /// Computes the full double-width product of each pair of lanes, without overflow,
+            /// and returns its low and high halves as two vectors, in that order.
+            /// Like the scalar integer methods, the low half is unsigned and the high half
+            /// carries the sign.
+            ///
+            /// # Examples
+            /// ```
+            /// # #![feature(portable_simd)]
+            /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+            /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+            /// # use simd::Simd;
+            /// use core::i32::{MIN, MAX};
+            /// let x = Simd::from_array([2, -3, MIN, MAX]);
+            /// let (low, high) = x.widening_mul(Simd::from_array([3, 5, MIN, -1]));
+            /// assert_eq!(low, Simd::from_array([6, -15i32 as u32, 0, MIN as u32 + 1]));
+            /// assert_eq!(high, Simd::from_array([0, -1, 1 << 30, -1]));
+            /// ```
+            #[inline]
+            #[must_use = "method returns new vectors and does not mutate the original inputs"]
+            pub fn widening_mul(self, rhs: Self) -> (Simd<$unsigned, LANES>, Self) {
+                let product = self.cast::<$wide>() * rhs.cast::<$wide>();
+                (product.cast(), (product >> <$ty>::BITS).cast())
+            }
+        }
+        )*
+    }
+}
+
+impl_widening_mul! {
+    i8 => u8, i16;
+    i16 => u16, i32;
+    i32 => u32, i64;
+    i64 => u64, i128;
+    isize => usize, i128
+}
@@ -1,8 +1,14 @@
 use super::sealed::Sealed;
-use crate::simd::{intrinsics, LaneCount, Simd, SupportedLaneCount};
+use crate::simd::{
+    intrinsics, LaneCount, Mask, Simd, SimdElement, SimdPartialEq, SimdPartialOrd,
+    SupportedLaneCount,
+};
 
 /// Operations on SIMD vectors of unsigned integers.
 pub trait SimdUint: Copy + Sealed {
+    /// Mask type used for manipulating this SIMD vector type.
+    type Mask;
+
     /// Scalar type contained by this SIMD vector type.
     type Scalar;
 
@@ -41,6 +47,204 @@ pub trait SimdUint: Copy + Sealed {
     /// assert_eq!(sat, Simd::splat(0));
     fn saturating_sub(self, second: Self) -> Self;
 
+    /// Lanewise overflowing add.
+    ///
+    /// Returns the wrapped result along with a mask indicating which lanes overflowed.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Mask, Simd, SimdUint};
+    /// use core::u32::MAX;
+    /// let x = Simd::from_array([2, 1, 0, MAX]);
+    /// let (sum, overflowed) = x.overflowing_add(Simd::splat(MAX));
+    /// assert_eq!(sum, Simd::from_array([1, 0, MAX, MAX - 1]));
+    /// assert_eq!(overflowed, Mask::from_array([true, true, false, true]));
+    /// ```
+    fn overflowing_add(self, second: Self) -> (Self, Self::Mask);
+
+    /// Lanewise overflowing subtract.
+    ///
+    /// Returns the wrapped result along with a mask indicating which lanes overflowed.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Mask, Simd, SimdUint};
+    /// use core::u32::MAX;
+    /// let x = Simd::from_array([2, 1, 0, MAX]);
+    /// let (diff, overflowed) = x.overflowing_sub(Simd::splat(1));
+    /// assert_eq!(diff, Simd::from_array([1, 0, MAX, MAX - 1]));
+    /// assert_eq!(overflowed, Mask::from_array([false, false, true, false]));
+    /// ```
+    fn overflowing_sub(self, second: Self) -> (Self, Self::Mask);
+
+    /// Lanewise overflowing multiply.
+    ///
+    /// Returns the wrapped result along with a mask indicating which lanes overflowed.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Mask, Simd, SimdUint};
+    /// use core::u32::MAX;
+    /// let x = Simd::from_array([0, 1, 1 << 31, MAX]);
+    /// let (product, overflowed) = x.overflowing_mul(Simd::splat(2));
+    /// assert_eq!(product, Simd::from_array([0, 2, 0, MAX - 1]));
+    /// assert_eq!(overflowed, Mask::from_array([false, false, true, true]));
+    /// ```
+    fn overflowing_mul(self, second: Self) -> (Self, Self::Mask);
+
+    /// Lanewise wrapping add.
+    ///
+    /// This is the same as the `+` operator, which always wraps for integer vectors.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// use core::u32::MAX;
+    /// let x = Simd::from_array([2, 1, 0, MAX]);
+    /// assert_eq!(x.wrapping_add(Simd::splat(MAX)), Simd::from_array([1, 0, MAX, MAX - 1]));
+    /// ```
+    fn wrapping_add(self, second: Self) -> Self;
+
+    /// Lanewise wrapping subtract.
+    ///
+    /// This is the same as the `-` operator, which always wraps for integer vectors.
+    fn wrapping_sub(self, second: Self) -> Self;
+
+    /// Lanewise wrapping multiply.
+    ///
+    /// This is the same as the `*` operator, which always wraps for integer vectors.
+    fn wrapping_mul(self, second: Self) -> Self;
+
+    /// Lanewise wrapping negation.
+    ///
+    /// Computes `0 - x` for each lane `x`, wrapping around for every nonzero lane.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// use core::u32::MAX;
+    /// let x = Simd::from_array([0, 1, 2, MAX]);
+    /// assert_eq!(x.wrapping_neg(), Simd::from_array([0, MAX, MAX - 1, 1]));
+    /// ```
+    fn wrapping_neg(self) -> Self;
+
+    /// Lanewise absolute difference.
+    ///
+    /// This is the lanewise step of sum of absolute differences instructions like x86's
+    /// `psadbw`, and never overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::<u8, 4>::from_array([10, 3, 0, 255]);
+    /// let y = Simd::from_array([3, 10, 255, 255]);
+    /// assert_eq!(x.abs_diff(y), Simd::from_array([7, 7, 255, 0]));
+    /// ```
+    fn abs_diff(self, second: Self) -> Self;
+
+    /// Lanewise midpoint, `(self + second) / 2`, rounded down and without overflowing.
+    ///
+    /// This is useful for blending pixels. Note that x86's `pavgb` rounds up instead.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::<u8, 4>::from_array([0, 1, 200, 255]);
+    /// let y = Simd::from_array([10, 2, 100, 255]);
+    /// assert_eq!(x.midpoint(y), Simd::from_array([5, 1, 150, 255]));
+    /// ```
+    fn midpoint(self, second: Self) -> Self;
+
+    /// Returns the number of leading zeros in the binary representation of each lane.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::<u8, 4>::from_array([0, 1, 0b0110_0000, u8::MAX]);
+    /// assert_eq!(x.leading_zeros(), Simd::from_array([8, 7, 1, 0]));
+    /// ```
+    fn leading_zeros(self) -> Self;
+
+    /// Returns the number of trailing zeros in the binary representation of each lane.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::<u8, 4>::from_array([0, 1, 0b0110_0000, u8::MAX]);
+    /// assert_eq!(x.trailing_zeros(), Simd::from_array([8, 0, 5, 0]));
+    /// ```
+    fn trailing_zeros(self) -> Self;
+
+    /// Returns the number of ones in the binary representation of each lane.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::<u8, 4>::from_array([0, 1, 0b0110_0000, u8::MAX]);
+    /// assert_eq!(x.count_ones(), Simd::from_array([0, 1, 2, 8]));
+    /// ```
+    fn count_ones(self) -> Self;
+
+    /// Returns the running sum of the lanes, with wrapping addition: lane `i` of the result is
+    /// the sum of lanes `0..=i` of `self`.
+    ///
+    /// This takes `log2(LANES)` shifts and additions.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::<u8, 4>::from_array([1, 2, 3, 250]);
+    /// assert_eq!(x.prefix_sum(), Simd::from_array([1, 3, 6, 0]));
+    /// ```
+    fn prefix_sum(self) -> Self;
+
+    /// Returns the running sum of the lanes before each lane, with wrapping addition: lane `i`
+    /// of the result is the sum of lanes `0..i` of `self`, so the first lane is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdUint};
+    /// let x = Simd::<u8, 4>::from_array([1, 2, 3, 250]);
+    /// assert_eq!(x.exclusive_prefix_sum(), Simd::from_array([0, 1, 3, 6]));
+    /// ```
+    fn exclusive_prefix_sum(self) -> Self;
+
     /// Returns the sum of the lanes of the vector, with wrapping addition.
     fn reduce_sum(self) -> Self::Scalar;
 
@@ -76,6 +280,7 @@ impl<const LANES: usize> SimdUint for Simd<$ty, LANES>
         where
             LaneCount<LANES>: SupportedLaneCount,
         {
+            type Mask = Mask<<$ty as SimdElement>::Mask, LANES>;
             type Scalar = $ty;
 
             #[inline]
@@ -90,6 +295,119 @@ fn saturating_sub(self, second: Self) -> Self {
                 unsafe { intrinsics::simd_saturating_sub(self, second) }
             }
 
+            #[inline]
+            fn overflowing_add(self, second: Self) -> (Self, Self::Mask) {
+                let sum = self + second;
+                (sum, sum.simd_lt(self))
+            }
+
+            #[inline]
+            fn overflowing_sub(self, second: Self) -> (Self, Self::Mask) {
+                (self - second, self.simd_lt(second))
+            }
+
+            #[inline]
+            fn overflowing_mul(self, second: Self) -> (Self, Self::Mask) {
+                let product = self * second;
+                // The product wrapped iff dividing it by a nonzero `self` doesn't give back
+                // `second`. This works for every lane width, unlike widening.
+                let nonzero = self.simd_ne(Self::splat(0));
+                let divisor = nonzero.select(self, Self::splat(1));
+                (product, nonzero & (product / divisor).simd_ne(second))
+            }
+
+            #[inline]
+            fn wrapping_add(self, second: Self) -> Self {
+                self + second
+            }
+
+            #[inline]
+            fn wrapping_sub(self, second: Self) -> Self {
+                self - second
+            }
+
+            #[inline]
+            fn wrapping_mul(self, second: Self) -> Self {
+                self * second
+            }
+
+            #[inline]
+            fn wrapping_neg(self) -> Self {
+                Self::splat(0) - self
+            }
+
+            #[inline]
+            fn abs_diff(self, second: Self) -> Self {
+                self.simd_lt(second).select(second - self, self - second)
+            }
+
+            #[inline]
+            fn midpoint(self, second: Self) -> Self {
+                // The common bits plus half of the differing bits is the average rounded down.
+                (self & second) + ((self ^ second) >> Simd::splat(1))
+            }
+
+            #[inline]
+            fn leading_zeros(self) -> Self {
+                #[cfg(not(bootstrap))]
+                // Safety: `self` is an integer vector
+                let counts = unsafe { intrinsics::simd_ctlz(self) };
+                #[cfg(bootstrap)]
+                let counts = Self::from_array(self.to_array().map(|x| x.leading_zeros() as $ty));
+                counts
+            }
+
+            #[inline]
+            fn trailing_zeros(self) -> Self {
+                #[cfg(not(bootstrap))]
+                // Safety: `self` is an integer vector
+                let counts = unsafe { intrinsics::simd_cttz(self) };
+                #[cfg(bootstrap)]
+                let counts = Self::from_array(self.to_array().map(|x| x.trailing_zeros() as $ty));
+                counts
+            }
+
+            #[inline]
+            fn count_ones(self) -> Self {
+                #[cfg(not(bootstrap))]
+                // Safety: `self` is an integer vector
+                let counts = unsafe { intrinsics::simd_ctpop(self) };
+                #[cfg(bootstrap)]
+                let counts = Self::from_array(self.to_array().map(|x| x.count_ones() as $ty));
+                counts
+            }
+
+            #[inline]
+            fn prefix_sum(self) -> Self {
+                // Each step adds the lanes `OFFSET` positions earlier, doubling the number of
+                // lanes summed into each lane.
+                let mut sum = self;
+                if LANES > 1 {
+                    sum += sum.shift_elements_right::<1>(0);
+                }
+                if LANES > 2 {
+                    sum += sum.shift_elements_right::<2>(0);
+                }
+                if LANES > 4 {
+                    sum += sum.shift_elements_right::<4>(0);
+                }
+                if LANES > 8 {
+                    sum += sum.shift_elements_right::<8>(0);
+                }
+                if LANES > 16 {
+                    sum += sum.shift_elements_right::<16>(0);
+                }
+                if LANES > 32 {
+                    sum += sum.shift_elements_right::<32>(0);
+                }
+                sum
+            }
+
+            #[inline]
+            fn exclusive_prefix_sum(self) -> Self {
+                self.prefix_sum().shift_elements_right::<1>(0)
+            }
+
             #[inline]
             fn reduce_sum(self) -> Self::Scalar {
                 // Safety: `self` is an integer vector
@@ -136,4 +454,41 @@ fn reduce_xor(self) -> Self::Scalar {
     }
 }
 
-impl_trait! { u8, u16, u32, u64, usize }
+impl_trait! { u8, u16, u32, u64, u128, usize }
+
+macro_rules! impl_widening_mul {
+    { $($ty:ty => $wide:ty),* } => {
+        $(
+        impl<const LANES: usize> Simd<$ty, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            /// Lanewise widening multiply.
+            ///
+            /// Computes the full double-width product of each pair of lanes, without overflow,
+            /// and returns its low and high halves as two vectors, in that order.
+            ///
+            /// # Examples
+            /// ```
+            /// # #![feature(portable_simd)]
+            /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+            /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+            /// # use simd::Simd;
+            /// use core::u32::MAX;
+            /// let x = Simd::from_array([2, 3, 1 << 31, MAX]);
+            /// let (low, high) = x.widening_mul(Simd::from_array([3, MAX, 4, MAX]));
+            /// assert_eq!(low, Simd::from_array([6, MAX - 2, 0, 1]));
+            /// assert_eq!(high, Simd::from_array([0, 2, 2, MAX - 1]));
+            /// ```
+            #[inline]
+            #[must_use = "method returns new vectors and does not mutate the original inputs"]
+            pub fn widening_mul(self, rhs: Self) -> (Self, Self) {
+                let product = self.cast::<$wide>() * rhs.cast::<$wide>();
+                (product.cast(), (product >> <$ty>::BITS).cast())
+            }
+        }
+        )*
+    }
+}
+
+impl_widening_mul! { u8 => u16, u16 => u32, u32 => u64, u64 => u128, usize => u128 }
@@ -0,0 +1,91 @@
+use crate::simd::{
+    LaneCount, Simd, SimdCast, SimdFloat, SimdPartialOrd, SimdUint, SupportedLaneCount,
+};
+use core::convert::FloatToInt;
+use core::mem;
+
+macro_rules! impl_float_cast {
+    { $($float:ty),* } => {
+        $(
+        impl<const LANES: usize> Simd<$float, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            /// Converts each lane to an integer, rounding toward zero and saturating at the
+            /// bounds of the integer type. `NAN` lanes become `0`.
+            ///
+            /// This is the same conversion as the scalar `as` and [`Simd::cast`], written out so
+            /// that code relying on the saturation says so.
+            ///
+            /// # Examples
+            /// ```
+            /// # #![feature(portable_simd)]
+            /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+            /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+            /// # use simd::Simd;
+            #[doc = concat!("let x = Simd::<", stringify!($float), ", 4>::from_array([-1.9, 300.0, ", stringify!($float), "::NEG_INFINITY, ", stringify!($float), "::NAN]);")]
+            /// assert_eq!(x.cast_saturating::<u8>().to_array(), [0, 255, 0, 0]);
+            /// ```
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            pub fn cast_saturating<I>(self) -> Simd<I, LANES>
+            where
+                $float: FloatToInt<I>,
+                I: SimdCast,
+            {
+                self.cast()
+            }
+
+            /// Converts each lane to an integer, rounding toward zero and wrapping around
+            /// at the bounds of the integer type. `NAN` and infinite lanes become `0`.
+            ///
+            /// The result is the integer that is congruent to the lane, after rounding toward
+            /// zero, modulo `2^BITS`, where `BITS` is the width of the integer type. This is
+            /// the conversion JavaScript uses for bitwise operations, and is what you get from
+            /// casting to a wide enough integer first, like `x as i64 as i32`.
+            ///
+            /// # Examples
+            /// ```
+            /// # #![feature(portable_simd)]
+            /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+            /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+            /// # use simd::Simd;
+            #[doc = concat!("let x = Simd::<", stringify!($float), ", 4>::from_array([-1.9, 300.0, ", stringify!($float), "::NEG_INFINITY, ", stringify!($float), "::NAN]);")]
+            /// assert_eq!(x.cast_wrapping::<u8>().to_array(), [255, 44, 0, 0]);
+            /// ```
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            pub fn cast_wrapping<I>(self) -> Simd<I, LANES>
+            where
+                $float: FloatToInt<I>,
+                I: SimdCast,
+            {
+                let bits = mem::size_of::<I>() * 8;
+                // Powers of two are exact, except that 2^128 overflows to infinity in `f32`.
+                let modulus = Self::splat(2.0 * (1u128 << (bits - 1)) as $float);
+                let magnitude = self.trunc().abs();
+                // Dividing and multiplying by a power of two is exact, and the difference only
+                // keeps low bits of `magnitude`, so this is exact too. Lanes that are already in
+                // range are kept as-is, so that an infinite `modulus` can't make them `NAN`.
+                // Infinite lanes become `NAN`, which the casts below turn into 0.
+                let low = magnitude.simd_lt(modulus).select(
+                    magnitude,
+                    magnitude - (magnitude / modulus).floor() * modulus,
+                );
+                let negative = self.is_sign_negative();
+                // `low` fits in the unsigned intermediate type, and the `as` cast to `I` keeps the
+                // low `bits` bits, which is the wrapping conversion for any integer type.
+                if bits <= 64 {
+                    let low = low.cast::<u64>();
+                    negative.cast().select(low.wrapping_neg(), low).cast()
+                } else {
+                    let low = low.cast::<u128>();
+                    negative.cast().select(low.wrapping_neg(), low).cast()
+                }
+            }
+        }
+        )*
+    }
+}
+
+impl_float_cast! { f32, f64 }
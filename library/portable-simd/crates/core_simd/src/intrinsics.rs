@@ -73,6 +73,13 @@
     /// which amounts to the same as `simd_cast` for many cases
     pub(crate) fn simd_as<T, U>(x: T) -> U;
 
+    /// casts between vectors of thin pointers, like `ptr as *const U`
+    pub(crate) fn simd_cast_ptr<T, U>(ptr: T) -> U;
+    /// ptrtoint, exposing the provenance of each pointer
+    pub(crate) fn simd_expose_addr<T, U>(ptr: T) -> U;
+    /// inttoptr, picking up a previously exposed provenance
+    pub(crate) fn simd_from_exposed_addr<T, U>(addr: T) -> U;
+
     /// neg/fneg
     /// ints: ultimately becomes a call to cg_ssa's BuilderMethods::neg. cg_llvm equates this to `simd_sub(Simd::splat(0), x)`.
     /// floats: LLVM's fneg, which changes the floating point sign bit. Some arches have instructions for it.
@@ -86,6 +93,34 @@
     pub(crate) fn simd_fmin<T>(x: T, y: T) -> T;
     pub(crate) fn simd_fmax<T>(x: T, y: T) -> T;
 
+    /// fma: computes `x * y + z` with a single rounding.
+    /// Without a hardware instruction for it, this is scalarized into calls to libm's `fmaf` or
+    /// `fma`, which `no_std` crates have to provide themselves.
+    pub(crate) fn simd_fma<T>(x: T, y: T, z: T) -> T;
+
+    /// fsqrt
+    pub(crate) fn simd_fsqrt<T>(x: T) -> T;
+
+    // ceil/floor/round/trunc
+    // these round each lane to an integral value, and are lowered to instructions like SSE4.1's
+    // `roundps` where available. Otherwise they are scalarized into calls to libm's `ceilf`,
+    // `floor` and so on, which `no_std` crates have to provide themselves.
+    pub(crate) fn simd_ceil<T>(x: T) -> T;
+    pub(crate) fn simd_floor<T>(x: T) -> T;
+    pub(crate) fn simd_round<T>(x: T) -> T;
+    pub(crate) fn simd_trunc<T>(x: T) -> T;
+
+    // ctlz/cttz/ctpop
+    // these count bits within each lane; a zero lane has as many leading or trailing zeros as it
+    // has bits.
+    // The bootstrap compiler doesn't know these yet.
+    #[cfg(not(bootstrap))]
+    pub(crate) fn simd_ctlz<T>(x: T) -> T;
+    #[cfg(not(bootstrap))]
+    pub(crate) fn simd_cttz<T>(x: T) -> T;
+    #[cfg(not(bootstrap))]
+    pub(crate) fn simd_ctpop<T>(x: T) -> T;
+
     // these return Simd<int, N> with the same BITS size as the inputs
     pub(crate) fn simd_eq<T, U>(x: T, y: T) -> U;
     pub(crate) fn simd_ne<T, U>(x: T, y: T) -> U;
@@ -110,6 +145,20 @@
     /// like gather, but more spicy, as it writes instead of reads
     pub(crate) fn simd_scatter<T, U, V>(val: T, ptr: U, mask: V);
 
+    /// llvm.masked.load
+    /// like a contiguous read of consecutive lanes starting at ptr
+    /// mask: a "wide" mask of integers, selects as if simd_select(mask, read(ptr.add(i)), val)
+    /// ptr: pointer to the first lane
+    /// val: vector of values to select if a lane is masked
+    /// masked-off lanes are never read, so they may point out of bounds
+    /// the bootstrap compiler doesn't know this or `simd_masked_store` yet
+    #[cfg(not(bootstrap))]
+    pub(crate) fn simd_masked_load<V, U, T>(mask: V, ptr: U, val: T) -> T;
+    /// llvm.masked.store
+    /// like masked load, but writes the enabled lanes instead of reading them
+    #[cfg(not(bootstrap))]
+    pub(crate) fn simd_masked_store<V, U, T>(mask: V, ptr: U, val: T);
+
     // {s,u}add.sat
     pub(crate) fn simd_saturating_add<T>(x: T, y: T) -> T;
 
@@ -50,9 +50,11 @@ fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
 impl_traits! { u16 }
 impl_traits! { u32 }
 impl_traits! { u64 }
+impl_traits! { u128 }
 impl_traits! { usize }
 impl_traits! { i8 }
 impl_traits! { i16 }
 impl_traits! { i32 }
 impl_traits! { i64 }
+impl_traits! { i128 }
 impl_traits! { isize }
@@ -1,6 +1,8 @@
 #![no_std]
 #![feature(
+    const_eval_select,
     convert_float_to_int,
+    core_intrinsics,
     decl_macro,
     intra_doc_pointers,
     platform_intrinsics,
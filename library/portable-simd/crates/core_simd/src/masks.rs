@@ -18,7 +18,9 @@
 #[cfg(feature = "generic_const_exprs")]
 pub use to_bitmask::{bitmask_len, ToBitMaskArray};
 
-use crate::simd::{intrinsics, LaneCount, Simd, SimdElement, SimdPartialEq, SupportedLaneCount};
+use crate::simd::{
+    intrinsics, LaneCount, Simd, SimdElement, SimdPartialEq, SimdUint, SupportedLaneCount,
+};
 use core::cmp::Ordering;
 use core::{fmt, mem};
 
@@ -77,6 +79,7 @@ unsafe impl MaskElement for $ty {}
 impl_element! { i16 }
 impl_element! { i32 }
 impl_element! { i64 }
+impl_element! { i128 }
 impl_element! { isize }
 
 /// A SIMD vector mask for `LANES` elements of width specified by `Element`.
@@ -253,6 +256,140 @@ pub fn any(self) -> bool {
     pub fn all(self) -> bool {
         self.0.all()
     }
+
+    /// Returns the index of the first set lane, or `None` if no lane is set.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::mask32x4;
+    /// let mask = mask32x4::from_array([false, true, false, true]);
+    /// assert_eq!(mask.first_set(), Some(1));
+    /// assert_eq!(mask32x4::splat(false).first_set(), None);
+    /// ```
+    #[inline]
+    #[must_use = "method returns the index and does not mutate the original value"]
+    pub fn first_set(self) -> Option<usize> {
+        if self.any() {
+            Some(
+                self.cast::<isize>()
+                    .select(lane_indices(), Simd::splat(usize::MAX))
+                    .reduce_min(),
+            )
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index of the last set lane, or `None` if no lane is set.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::mask32x4;
+    /// let mask = mask32x4::from_array([false, true, false, true]);
+    /// assert_eq!(mask.last_set(), Some(3));
+    /// assert_eq!(mask32x4::splat(false).last_set(), None);
+    /// ```
+    #[inline]
+    #[must_use = "method returns the index and does not mutate the original value"]
+    pub fn last_set(self) -> Option<usize> {
+        if self.any() {
+            Some(
+                self.cast::<isize>()
+                    .select(lane_indices(), Simd::splat(0))
+                    .reduce_max(),
+            )
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of set lanes.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::mask32x4;
+    /// let mask = mask32x4::from_array([false, true, false, true]);
+    /// assert_eq!(mask.count_set(), 2);
+    /// ```
+    #[inline]
+    #[must_use = "method returns the count and does not mutate the original value"]
+    pub fn count_set(self) -> usize {
+        self.cast::<isize>()
+            .select(Simd::<usize, LANES>::splat(1), Simd::<usize, LANES>::splat(0))
+            .reduce_sum()
+    }
+
+    /// Converts the mask to a bitmask stored in a vector of bytes.
+    ///
+    /// Each bit of the bitmask corresponds to a mask lane, starting with the LSB of the first
+    /// byte. Only the first `(LANES + 7) / 8` bytes are used, and the remaining bits are unset.
+    /// Unlike [`ToBitMask`], this works for any number of lanes without needing an integer
+    /// type wide enough to hold them all.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::mask32x4;
+    /// let mask = mask32x4::from_array([true, false, true, true]);
+    /// assert_eq!(mask.to_bitmask_vector().to_array(), [0b1101, 0, 0, 0]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    pub fn to_bitmask_vector(self) -> Simd<u8, LANES> {
+        let mut bitmask = [0; LANES];
+        for (lane, set) in self.to_array().into_iter().enumerate() {
+            bitmask[lane / 8] |= (set as u8) << (lane % 8);
+        }
+        Simd::from_array(bitmask)
+    }
+
+    /// Converts a bitmask stored in a vector of bytes to a mask.
+    ///
+    /// Each bit of the bitmask corresponds to a mask lane, starting with the LSB of the first
+    /// byte. Bits past the last lane are ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{mask32x4, u8x4};
+    /// let mask = mask32x4::from_bitmask_vector(u8x4::from_array([0b1111_1101, 0, 0, 0]));
+    /// assert_eq!(mask.to_array(), [true, false, true, true]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original value"]
+    pub fn from_bitmask_vector(bitmask: Simd<u8, LANES>) -> Self {
+        let mut array = [false; LANES];
+        for (lane, set) in array.iter_mut().enumerate() {
+            *set = bitmask[lane / 8] & (1 << (lane % 8)) != 0;
+        }
+        Self::from_array(array)
+    }
+}
+
+/// Returns a vector containing the index of each lane.
+#[inline]
+pub(crate) fn lane_indices<const LANES: usize>() -> Simd<usize, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let mut index = [0; LANES];
+    for (i, lane) in index.iter_mut().enumerate() {
+        *lane = i;
+    }
+    Simd::from_array(index)
 }
 
 // vector/array conversion
@@ -312,15 +449,40 @@ fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
     }
 }
 
+impl<T, const LANES: usize> core::hash::Hash for Mask<T, LANES>
+where
+    T: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    #[inline]
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: core::hash::Hasher,
+    {
+        // Hash the lanes rather than the internal representation, which differs between targets
+        self.to_array().hash(state)
+    }
+}
+
 impl<T, const LANES: usize> fmt::Debug for Mask<T, LANES>
 where
     T: MaskElement + fmt::Debug,
     LaneCount<LANES>: SupportedLaneCount,
 {
+    /// Formats the mask as its lanes in order, written as `1` for set and `0` for unset, like
+    /// `Mask(1011)`. The alternate flag (`{:#?}`) lists each lane as a `bool` instead.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_list()
-            .entries((0..LANES).map(|lane| self.test(lane)))
-            .finish()
+        if f.alternate() {
+            return f
+                .debug_list()
+                .entries((0..LANES).map(|lane| self.test(lane)))
+                .finish();
+        }
+        f.write_str("Mask(")?;
+        for lane in 0..LANES {
+            f.write_str(if self.test(lane) { "1" } else { "0" })?;
+        }
+        f.write_str(")")
     }
 }
 
@@ -565,6 +727,12 @@ fn bitxor_assign(&mut self, rhs: bool) {
 /// A mask for SIMD vectors with eight elements of 64 bits.
 pub type mask64x8 = Mask<i64, 8>;
 
+/// A mask for SIMD vectors with two elements of 128 bits.
+pub type mask128x2 = Mask<i128, 2>;
+
+/// A mask for SIMD vectors with four elements of 128 bits.
+pub type mask128x4 = Mask<i128, 4>;
+
 /// A mask for SIMD vectors with two elements of pointer width.
 pub type masksizex2 = Mask<isize, 2>;
 
@@ -588,8 +756,9 @@ fn from(value: Mask<$from, LANES>) -> Self {
         )*
     }
 }
-impl_from! { i8 => i16, i32, i64, isize }
-impl_from! { i16 => i32, i64, isize, i8 }
-impl_from! { i32 => i64, isize, i8, i16 }
-impl_from! { i64 => isize, i8, i16, i32 }
-impl_from! { isize => i8, i16, i32, i64 }
+impl_from! { i8 => i16, i32, i64, i128, isize }
+impl_from! { i16 => i32, i64, i128, isize, i8 }
+impl_from! { i32 => i64, i128, isize, i8, i16 }
+impl_from! { i64 => i128, isize, i8, i16, i32 }
+impl_from! { i128 => isize, i8, i16, i32, i64 }
+impl_from! { isize => i8, i16, i32, i64, i128 }
@@ -0,0 +1,102 @@
+use crate::simd::{LaneCount, Simd, SimdPartialEq, SupportedLaneCount};
+
+impl<const N: usize> Simd<u8, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    /// Returns the index of the first lane equal to `byte`, or `None` if no lane is.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # use core::simd::u8x8;
+    /// let line = u8x8::from_array(*b"key=val;");
+    /// assert_eq!(line.find_first_eq(b'='), Some(3));
+    /// assert_eq!(line.find_first_eq(b'\n'), None);
+    /// ```
+    #[inline]
+    #[must_use = "method returns the index and does not mutate the original value"]
+    pub fn find_first_eq(self, byte: u8) -> Option<usize> {
+        self.simd_eq(Self::splat(byte)).first_set()
+    }
+
+    /// Returns the index of the last lane equal to `byte`, or `None` if no lane is.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # use core::simd::u8x8;
+    /// let path = u8x8::from_array(*b"a/b/c.rs");
+    /// assert_eq!(path.find_last_eq(b'/'), Some(3));
+    /// assert_eq!(path.find_last_eq(b'\\'), None);
+    /// ```
+    #[inline]
+    #[must_use = "method returns the index and does not mutate the original value"]
+    pub fn find_last_eq(self, byte: u8) -> Option<usize> {
+        self.simd_eq(Self::splat(byte)).last_set()
+    }
+}
+
+/// Number of bytes compared at once by [`memchr`] and [`memrchr`].
+const LANES: usize = 32;
+
+/// Returns the index of the first byte in `text` equal to `x`, or `None` if there is none.
+///
+/// This has the same behavior as `text.iter().position(|&b| b == x)`, but compares many bytes at
+/// once with [`Simd::find_first_eq`].
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::memchr;
+/// let csv = b"name,size,modified";
+/// assert_eq!(memchr(b',', csv), Some(4));
+/// assert_eq!(memchr(b';', csv), None);
+/// ```
+#[must_use]
+#[inline]
+pub fn memchr(x: u8, text: &[u8]) -> Option<usize> {
+    let mut chunks = text.chunks_exact(LANES);
+    for (i, chunk) in chunks.by_ref().enumerate() {
+        if let Some(lane) = Simd::<u8, LANES>::from_slice(chunk).find_first_eq(x) {
+            return Some(i * LANES + lane);
+        }
+    }
+    let tail = chunks.remainder();
+    // The padding lanes of the tail are zero, so they can only match if `x` is zero.
+    Simd::<u8, LANES>::load_or_default(tail)
+        .find_first_eq(x)
+        .filter(|&lane| lane < tail.len())
+        .map(|lane| text.len() - tail.len() + lane)
+}
+
+/// Returns the index of the last byte in `text` equal to `x`, or `None` if there is none.
+///
+/// This has the same behavior as `text.iter().rposition(|&b| b == x)`, but compares many bytes at
+/// once with [`Simd::find_last_eq`].
+///
+/// # Examples
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::memrchr;
+/// let path = b"/usr/lib/rustlib";
+/// assert_eq!(memrchr(b'/', path), Some(8));
+/// assert_eq!(memrchr(b'.', path), None);
+/// ```
+#[must_use]
+#[inline]
+pub fn memrchr(x: u8, text: &[u8]) -> Option<usize> {
+    let mut chunks = text.rchunks_exact(LANES);
+    for (i, chunk) in chunks.by_ref().enumerate() {
+        if let Some(lane) = Simd::<u8, LANES>::from_slice(chunk).find_last_eq(x) {
+            return Some(text.len() - (i + 1) * LANES + lane);
+        }
+    }
+    // Padding the head would put the zeroed lanes after it, where they would win over a real
+    // match when searching for zero, so search it one byte at a time instead.
+    chunks.remainder().iter().rposition(|&b| b == x)
+}
@@ -3,18 +3,27 @@
 
 pub(crate) mod intrinsics;
 
+#[cfg(feature = "generic_const_exprs")]
+mod dot;
+#[cfg(feature = "generic_const_exprs")]
+mod pack;
 #[cfg(feature = "generic_const_exprs")]
 mod to_bytes;
 
+mod cast;
+mod compress;
 mod elements;
 mod eq;
+mod float_cast;
 mod fmt;
 mod iter;
 mod lane_count;
 mod masks;
+mod memchr;
 mod ops;
 mod ord;
 mod select;
+mod swizzle_dyn;
 mod vector;
 mod vendor;
 
@@ -22,10 +31,12 @@
 pub mod simd {
     pub(crate) use crate::core_simd::intrinsics;
 
+    pub use crate::core_simd::cast::*;
     pub use crate::core_simd::elements::*;
     pub use crate::core_simd::eq::*;
     pub use crate::core_simd::lane_count::{LaneCount, SupportedLaneCount};
     pub use crate::core_simd::masks::*;
+    pub use crate::core_simd::memchr::{memchr, memrchr};
     pub use crate::core_simd::ord::*;
     pub use crate::core_simd::swizzle::*;
     pub use crate::core_simd::vector::*;
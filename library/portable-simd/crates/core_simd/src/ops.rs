@@ -166,7 +166,7 @@ impl $op::$call
 // Integers can always accept add, mul, sub, bitand, bitor, and bitxor.
 // For all of these operations, simd_* intrinsics apply wrapping logic.
 for_base_ops! {
-    T = (i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+    T = (i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
     type Lhs = Simd<T, N>;
     type Rhs = Simd<T, N>;
     type Output = Self;
@@ -252,3 +252,125 @@ impl Rem::rem {
         unsafe_base { simd_rem }
     }
 }
+
+// Like `deref_ops` in `deref.rs`, forwards an operator with either or both operands behind a
+// reference to the impl for values.
+macro_rules! deref_scalar_op {
+    (impl $op:ident::$call:ident for $lhs:ty, $rhs:ty) => {
+        impl<const N: usize> $op<$rhs> for &$lhs
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            type Output = <$lhs as $op<$rhs>>::Output;
+
+            #[inline]
+            #[must_use = "operator returns a new vector without mutating the inputs"]
+            fn $call(self, rhs: $rhs) -> Self::Output {
+                (*self).$call(rhs)
+            }
+        }
+
+        impl<const N: usize> $op<&$rhs> for $lhs
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            type Output = <$lhs as $op<$rhs>>::Output;
+
+            #[inline]
+            #[must_use = "operator returns a new vector without mutating the inputs"]
+            fn $call(self, rhs: &$rhs) -> Self::Output {
+                self.$call(*rhs)
+            }
+        }
+
+        impl<const N: usize> $op<&$rhs> for &$lhs
+        where
+            LaneCount<N>: SupportedLaneCount,
+        {
+            type Output = <$lhs as $op<$rhs>>::Output;
+
+            #[inline]
+            #[must_use = "operator returns a new vector without mutating the inputs"]
+            fn $call(self, rhs: &$rhs) -> Self::Output {
+                (*self).$call(*rhs)
+            }
+        }
+    };
+}
+
+// Operations between a vector and a scalar splat the scalar across all lanes,
+// then defer to the vector-vector impls above, so `v + 1` means `v + Simd::splat(1)`.
+macro_rules! scalar_ops {
+    ($(impl $op:ident::$call:ident for ($($scalar:ident),*);)*) => {
+        $($(
+            impl<const N: usize> $op<$scalar> for Simd<$scalar, N>
+            where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                type Output = Self;
+
+                #[inline]
+                #[must_use = "operator returns a new vector without mutating the inputs"]
+                fn $call(self, rhs: $scalar) -> Self::Output {
+                    self.$call(Self::splat(rhs))
+                }
+            }
+
+            impl<const N: usize> $op<Simd<$scalar, N>> for $scalar
+            where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                type Output = Simd<$scalar, N>;
+
+                #[inline]
+                #[must_use = "operator returns a new vector without mutating the inputs"]
+                fn $call(self, rhs: Simd<$scalar, N>) -> Self::Output {
+                    Simd::splat(self).$call(rhs)
+                }
+            }
+
+            deref_scalar_op! { impl $op::$call for Simd<$scalar, N>, $scalar }
+            deref_scalar_op! { impl $op::$call for $scalar, Simd<$scalar, N> }
+        )*)*
+    }
+}
+
+scalar_ops! {
+    impl Add::add for (i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+    impl Mul::mul for (i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+    impl Sub::sub for (i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+    impl Div::div for (i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+    impl Rem::rem for (i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+    impl BitAnd::bitand for (i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    impl BitOr::bitor for (i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    impl BitXor::bitxor for (i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+}
+
+// Shifting every lane by the same amount is common enough to accept a `u32` shift amount,
+// like the scalar integer types do. Truncating it to the lane type keeps the low bits,
+// so the shift amount wraps exactly like in the vector-vector impls.
+macro_rules! scalar_shift_ops {
+    ($(impl $op:ident::$call:ident for ($($int:ident),*);)*) => {
+        $($(
+            impl<const N: usize> $op<u32> for Simd<$int, N>
+            where
+                LaneCount<N>: SupportedLaneCount,
+            {
+                type Output = Self;
+
+                #[inline]
+                #[must_use = "operator returns a new vector without mutating the inputs"]
+                fn $call(self, rhs: u32) -> Self::Output {
+                    self.$call(Self::splat(rhs as $int))
+                }
+            }
+
+            deref_scalar_op! { impl $op::$call for Simd<$int, N>, u32 }
+        )*)*
+    }
+}
+
+scalar_shift_ops! {
+    impl Shl::shl for (i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    impl Shr::shr for (i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+}
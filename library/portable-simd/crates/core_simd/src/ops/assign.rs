@@ -1,4 +1,7 @@
 //! Assignment operators
+//!
+//! These are generic over the right-hand side, so they also cover the scalar operators in
+//! `ops.rs`: `v += 1` is `v = v + 1`, and `v <<= 3` is `v = v << 3u32`.
 use super::*;
 use core::ops::{AddAssign, MulAssign}; // commutative binary op-assignment
 use core::ops::{BitAndAssign, BitOrAssign, BitXorAssign}; // commutative bit binary op-assignment
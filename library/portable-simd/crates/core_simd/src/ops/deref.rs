@@ -3,6 +3,7 @@
 //! and method calls usually handle the LHS implicitly.
 //! But this is not the case with arithmetic ops.
 use super::*;
+use core::ops::{Neg, Not};
 
 macro_rules! deref_lhs {
     (impl<T, const LANES: usize> $trait:ident for $simd:ty {
@@ -122,3 +123,36 @@ impl<T, const LANES: usize> Shr for Simd<T, LANES> {
         fn shr
     }
 }
+
+macro_rules! deref_unary {
+    ($(impl<T, const LANES: usize> $trait:ident for $simd:ty {
+            fn $call:ident
+        })*) => {
+        $(
+            impl<T, const LANES: usize> $trait for &$simd
+            where
+                T: SimdElement,
+                $simd: $trait<Output = $simd>,
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                type Output = $simd;
+
+                #[inline]
+                #[must_use = "operator returns a new vector without mutating the input"]
+                fn $call(self) -> Self::Output {
+                    (*self).$call()
+                }
+            }
+        )*
+    }
+}
+
+deref_unary! {
+    impl<T, const LANES: usize> Neg for Simd<T, LANES> {
+        fn neg
+    }
+
+    impl<T, const LANES: usize> Not for Simd<T, LANES> {
+        fn not
+    }
+}
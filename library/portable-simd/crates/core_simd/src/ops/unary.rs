@@ -34,6 +34,8 @@ impl<const LANES: usize> Neg for Simd<i32, LANES>
 
     impl<const LANES: usize> Neg for Simd<i64, LANES>
 
+    impl<const LANES: usize> Neg for Simd<i128, LANES>
+
     impl<const LANES: usize> Neg for Simd<isize, LANES>
 }
 
@@ -64,6 +66,8 @@ impl<const LANES: usize> Not for Simd<i32, LANES>
 
     impl<const LANES: usize> Not for Simd<i64, LANES>
 
+    impl<const LANES: usize> Not for Simd<i128, LANES>
+
     impl<const LANES: usize> Not for Simd<isize, LANES>
 
     impl<const LANES: usize> Not for Simd<u8, LANES>
@@ -74,5 +78,7 @@ impl<const LANES: usize> Not for Simd<u32, LANES>
 
     impl<const LANES: usize> Not for Simd<u64, LANES>
 
+    impl<const LANES: usize> Not for Simd<u128, LANES>
+
     impl<const LANES: usize> Not for Simd<usize, LANES>
 }
@@ -104,7 +104,7 @@ fn simd_clamp(self, min: Self, max: Self) -> Self {
     }
 }
 
-impl_integer! { u8, u16, u32, u64, usize, i8, i16, i32, i64, isize }
+impl_integer! { u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize }
 
 macro_rules! impl_float {
     { $($float:ty),* } => {
@@ -210,4 +210,4 @@ fn simd_clamp(self, min: Self, max: Self) -> Self {
     }
 }
 
-impl_mask! { i8, i16, i32, i64, isize }
+impl_mask! { i8, i16, i32, i64, i128, isize }
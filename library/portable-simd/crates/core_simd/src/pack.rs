@@ -0,0 +1,98 @@
+use crate::simd::{LaneCount, Simd, SimdElement, SimdOrd, SupportedLaneCount};
+
+/// Concatenates the lanes of `lo` and `hi` into a vector of twice the length.
+#[inline]
+fn concat<T, const LANES: usize>(lo: Simd<T, LANES>, hi: Simd<T, LANES>) -> Simd<T, { 2 * LANES }>
+where
+    T: SimdElement + Default,
+    LaneCount<LANES>: SupportedLaneCount,
+    LaneCount<{ 2 * LANES }>: SupportedLaneCount,
+{
+    let mut array = [T::default(); 2 * LANES];
+    array[..LANES].copy_from_slice(lo.as_array());
+    array[LANES..].copy_from_slice(hi.as_array());
+    Simd::from_array(array)
+}
+
+macro_rules! impl_pack_signed {
+    { $($from:ty => $to:ty, $to_unsigned:ty);* } => {
+        $(
+        impl<const LANES: usize> Simd<$from, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+            LaneCount<{ 2 * LANES }>: SupportedLaneCount,
+        {
+            /// Narrows the lanes of `self` and `other` to the signed half-width integer type,
+            /// saturating at its bounds, and concatenates them with the lanes of `self` first.
+            ///
+            /// This matches the signed saturating pack instructions, like x86's `packsswb`.
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original inputs"]
+            pub fn saturating_pack(self, other: Self) -> Simd<$to, { 2 * LANES }> {
+                let min = Simd::splat(<$to>::MIN as $from);
+                let max = Simd::splat(<$to>::MAX as $from);
+                concat(
+                    self.simd_clamp(min, max).cast(),
+                    other.simd_clamp(min, max).cast(),
+                )
+            }
+
+            /// Narrows the lanes of `self` and `other` to the unsigned half-width integer type,
+            /// saturating at its bounds, and concatenates them with the lanes of `self` first.
+            /// Negative lanes become zero.
+            ///
+            /// This matches the unsigned saturating pack instructions, like x86's `packuswb`.
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original inputs"]
+            pub fn saturating_pack_unsigned(self, other: Self) -> Simd<$to_unsigned, { 2 * LANES }> {
+                let min = Simd::splat(0);
+                let max = Simd::splat(<$to_unsigned>::MAX as $from);
+                concat(
+                    self.simd_clamp(min, max).cast(),
+                    other.simd_clamp(min, max).cast(),
+                )
+            }
+
+            /// Narrows the lanes of `self` and `other` to the half-width integer type, keeping only
+            /// the low bits like an `as` cast, and concatenates them with the lanes of `self` first.
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original inputs"]
+            pub fn truncating_pack(self, other: Self) -> Simd<$to, { 2 * LANES }> {
+                concat(self.cast(), other.cast())
+            }
+        }
+        )*
+    }
+}
+
+macro_rules! impl_pack_unsigned {
+    { $($from:ty => $to:ty);* } => {
+        $(
+        impl<const LANES: usize> Simd<$from, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+            LaneCount<{ 2 * LANES }>: SupportedLaneCount,
+        {
+            /// Narrows the lanes of `self` and `other` to the half-width integer type, saturating at
+            /// its maximum value, and concatenates them with the lanes of `self` first.
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original inputs"]
+            pub fn saturating_pack(self, other: Self) -> Simd<$to, { 2 * LANES }> {
+                let max = Simd::splat(<$to>::MAX as $from);
+                concat(self.simd_min(max).cast(), other.simd_min(max).cast())
+            }
+
+            /// Narrows the lanes of `self` and `other` to the half-width integer type, keeping only
+            /// the low bits like an `as` cast, and concatenates them with the lanes of `self` first.
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original inputs"]
+            pub fn truncating_pack(self, other: Self) -> Simd<$to, { 2 * LANES }> {
+                concat(self.cast(), other.cast())
+            }
+        }
+        )*
+    }
+}
+
+impl_pack_signed! { i16 => i8, u8; i32 => i16, u16; i64 => i32, u32 }
+impl_pack_unsigned! { u16 => u8; u32 => u16; u64 => u32 }
@@ -211,12 +211,23 @@ impl<const LANES: usize> Swizzle<LANES, LANES> for Reverse {
         Reverse::swizzle(self)
     }
 
-    /// Rotates the vector such that the first `OFFSET` elements of the slice move to the end
-    /// while the last `LANES - OFFSET` elements move to the front. After calling `rotate_lanes_left`,
-    /// the element previously in lane `OFFSET` will become the first element in the slice.
+    /// Rotates the vector such that the first `OFFSET` elements of the vector move to the end
+    /// while the last `LANES - OFFSET` elements move to the front. After calling `rotate_elements_left`,
+    /// the element previously in lane `OFFSET` will become the first element in the vector.
+    ///
+    /// The rotation is resolved at compile time into a single swizzle. `OFFSET` may be larger
+    /// than `LANES`, in which case it wraps around.
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    /// # use core::simd::Simd;
+    /// let a = Simd::from_array([0, 1, 2, 3]);
+    /// assert_eq!(a.rotate_elements_left::<1>().to_array(), [1, 2, 3, 0]);
+    /// assert_eq!(a.rotate_elements_left::<6>().to_array(), [2, 3, 0, 1]);
+    /// ```
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn rotate_lanes_left<const OFFSET: usize>(self) -> Self {
+    pub fn rotate_elements_left<const OFFSET: usize>(self) -> Self {
         const fn rotate_index<const OFFSET: usize, const LANES: usize>() -> [usize; LANES] {
             let offset = OFFSET % LANES;
             let mut index = [0; LANES];
@@ -238,11 +249,22 @@ impl<const OFFSET: usize, const LANES: usize> Swizzle<LANES, LANES> for Rotate<O
     }
 
     /// Rotates the vector such that the first `LANES - OFFSET` elements of the vector move to
-    /// the end while the last `OFFSET` elements move to the front. After calling `rotate_lanes_right`,
-    /// the element previously at index `LANES - OFFSET` will become the first element in the slice.
+    /// the end while the last `OFFSET` elements move to the front. After calling `rotate_elements_right`,
+    /// the element previously at index `LANES - OFFSET` will become the first element in the vector.
+    ///
+    /// The rotation is resolved at compile time into a single swizzle. `OFFSET` may be larger
+    /// than `LANES`, in which case it wraps around.
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    /// # use core::simd::Simd;
+    /// let a = Simd::from_array([0, 1, 2, 3]);
+    /// assert_eq!(a.rotate_elements_right::<1>().to_array(), [3, 0, 1, 2]);
+    /// assert_eq!(a.rotate_elements_right::<6>().to_array(), [2, 3, 0, 1]);
+    /// ```
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn rotate_lanes_right<const OFFSET: usize>(self) -> Self {
+    pub fn rotate_elements_right<const OFFSET: usize>(self) -> Self {
         const fn rotate_index<const OFFSET: usize, const LANES: usize>() -> [usize; LANES] {
             let offset = LANES - OFFSET % LANES;
             let mut index = [0; LANES];
@@ -263,6 +285,74 @@ impl<const OFFSET: usize, const LANES: usize> Swizzle<LANES, LANES> for Rotate<O
         Rotate::<OFFSET>::swizzle(self)
     }
 
+    /// Concatenates `self` and `next`, and extracts a vector of `LANES` lanes starting at lane
+    /// `OFFSET` of the concatenation. The result is the last `LANES - OFFSET` lanes of `self`
+    /// followed by the first `OFFSET` lanes of `next`.
+    ///
+    /// This matches instructions like Arm's `vext` and x86's `palignr`, and is useful for
+    /// sliding a window across consecutive vectors of a stream.
+    ///
+    /// `OFFSET` must be at most `LANES`; larger offsets fail to compile.
+    ///
+    /// ```
+    /// #![feature(portable_simd)]
+    /// # use core::simd::Simd;
+    /// let a = Simd::from_array([0, 1, 2, 3]);
+    /// let b = Simd::from_array([4, 5, 6, 7]);
+    /// assert_eq!(a.extract_concat::<1>(b).to_array(), [1, 2, 3, 4]);
+    /// assert_eq!(a.extract_concat::<3>(b).to_array(), [3, 4, 5, 6]);
+    /// assert_eq!(a.extract_concat::<4>(b), b);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original inputs"]
+    pub fn extract_concat<const OFFSET: usize>(self, next: Self) -> Self {
+        const fn extract_index<const OFFSET: usize, const LANES: usize>() -> [Which; LANES] {
+            let mut index = [Which::First(0); LANES];
+            let mut i = 0;
+            while i < LANES {
+                index[i] = if i + OFFSET < LANES {
+                    Which::First(i + OFFSET)
+                } else {
+                    Which::Second(i + OFFSET - LANES)
+                };
+                i += 1;
+            }
+            index
+        }
+
+        struct Extract<const OFFSET: usize>;
+
+        impl<const OFFSET: usize, const LANES: usize> Swizzle2<LANES, LANES> for Extract<OFFSET> {
+            const INDEX: [Which; LANES] = extract_index::<OFFSET, LANES>();
+        }
+
+        Extract::<OFFSET>::swizzle2(self, next)
+    }
+
+    /// Shifts the vector such that each element moves `OFFSET` lanes towards the end, and fills
+    /// the first `OFFSET` lanes with `padding`. Elements shifted past the end are discarded.
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original inputs"]
+    pub(crate) fn shift_elements_right<const OFFSET: usize>(self, padding: T) -> Self {
+        const fn shift_index<const OFFSET: usize, const LANES: usize>() -> [Which; LANES] {
+            let mut index = [Which::Second(0); LANES];
+            let mut i = OFFSET;
+            while i < LANES {
+                index[i] = Which::First(i - OFFSET);
+                i += 1;
+            }
+            index
+        }
+
+        struct Shift<const OFFSET: usize>;
+
+        impl<const OFFSET: usize, const LANES: usize> Swizzle2<LANES, LANES> for Shift<OFFSET> {
+            const INDEX: [Which; LANES] = shift_index::<OFFSET, LANES>();
+        }
+
+        Shift::<OFFSET>::swizzle2(self, Self::splat(padding))
+    }
+
     /// Interleave two vectors.
     ///
     /// Produces two vectors with lanes taken alternately from `self` and `other`.
@@ -273,6 +363,10 @@ impl<const OFFSET: usize, const LANES: usize> Swizzle<LANES, LANES> for Rotate<O
     /// The second result contains the last `LANES / 2` lanes from `self` and `other`,
     /// alternating, starting with the lane `LANES / 2` from the start of `self`.
     ///
+    /// This converts two vectors in "structure of arrays" form into "array of structures" form,
+    /// such as turning separate `x` and `y` coordinates into `(x, y)` pairs.
+    /// [`Simd::deinterleave`] performs the opposite conversion.
+    ///
     /// ```
     /// #![feature(portable_simd)]
     /// # use core::simd::Simd;
@@ -336,6 +430,9 @@ impl<const LANES: usize> Swizzle2<LANES, LANES> for Hi {
     /// The second result takes every other lane of `self` and then `other`, starting with
     /// the second lane.
     ///
+    /// This converts two vectors in "array of structures" form into "structure of arrays" form,
+    /// and is the inverse of [`Simd::interleave`].
+    ///
     /// ```
     /// #![feature(portable_simd)]
     /// # use core::simd::Simd;
@@ -344,6 +441,15 @@ impl<const LANES: usize> Swizzle2<LANES, LANES> for Hi {
     /// let (x, y) = a.deinterleave(b);
     /// assert_eq!(x.to_array(), [0, 1, 2, 3]);
     /// assert_eq!(y.to_array(), [4, 5, 6, 7]);
+    ///
+    /// // Split `(x, y)` points into their coordinates, and back.
+    /// let points = [(1, 10), (2, 20), (3, 30), (4, 40)];
+    /// let lo = Simd::from_array([points[0].0, points[0].1, points[1].0, points[1].1]);
+    /// let hi = Simd::from_array([points[2].0, points[2].1, points[3].0, points[3].1]);
+    /// let (xs, ys) = lo.deinterleave(hi);
+    /// assert_eq!(xs.to_array(), [1, 2, 3, 4]);
+    /// assert_eq!(ys.to_array(), [10, 20, 30, 40]);
+    /// assert_eq!(xs.interleave(ys), (lo, hi));
     /// ```
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
@@ -26,6 +26,7 @@ pub fn from_ne_bytes(bytes: crate::simd::Simd<u8, {{ $size * LANES }}>) -> Self
 impl_to_bytes! { u16, 2 }
 impl_to_bytes! { u32, 4 }
 impl_to_bytes! { u64, 8 }
+impl_to_bytes! { u128, 16 }
 #[cfg(target_pointer_width = "32")]
 impl_to_bytes! { usize, 4 }
 #[cfg(target_pointer_width = "64")]
@@ -35,6 +36,7 @@ pub fn from_ne_bytes(bytes: crate::simd::Simd<u8, {{ $size * LANES }}>) -> Self
 impl_to_bytes! { i16, 2 }
 impl_to_bytes! { i32, 4 }
 impl_to_bytes! { i64, 8 }
+impl_to_bytes! { i128, 16 }
 #[cfg(target_pointer_width = "32")]
 impl_to_bytes! { isize, 4 }
 #[cfg(target_pointer_width = "64")]
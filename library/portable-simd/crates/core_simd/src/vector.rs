@@ -6,13 +6,13 @@
 pub use int::*;
 pub use uint::*;
 
-// Vectors of pointers are not for public use at the current time.
-pub(crate) mod ptr;
-
 use crate::simd::{
-    intrinsics, LaneCount, Mask, MaskElement, SimdPartialOrd, SupportedLaneCount, Swizzle,
+    intrinsics, LaneCount, Mask, MaskElement, SimdCast, SimdConstPtr, SimdMutPtr, SimdPartialOrd,
+    SupportedLaneCount, Swizzle,
 };
 
+use super::masks::lane_indices;
+
 /// A SIMD vector of `LANES` elements of type `T`. `Simd<T, N>` has the same shape as [`[T; N]`](array), but operates like `T`.
 ///
 /// Two vectors of the same type and length will, by convention, support the operators (+, *, etc.) that `T` does.
@@ -123,15 +123,42 @@ pub const fn lanes(&self) -> usize {
     /// # use core::simd::u32x4;
     /// let v = u32x4::splat(8);
     /// assert_eq!(v.as_array(), &[8, 8, 8, 8]);
+    ///
+    /// // Vectors can also be built in constants.
+    /// const ONES: u32x4 = u32x4::splat(1);
+    /// assert_eq!(ONES.as_array(), &[1, 1, 1, 1]);
     /// ```
-    pub fn splat(value: T) -> Self {
-        // This is preferred over `[value; LANES]`, since it's explicitly a splat:
-        // https://github.com/rust-lang/rust/issues/97804
-        struct Splat;
-        impl<const LANES: usize> Swizzle<1, LANES> for Splat {
-            const INDEX: [usize; LANES] = [0; LANES];
+    pub const fn splat(value: T) -> Self {
+        const fn splat_const<T, const LANES: usize>(value: T) -> Simd<T, LANES>
+        where
+            T: SimdElement,
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            Simd::from_array([value; LANES])
+        }
+
+        fn splat_rt<T, const LANES: usize>(value: T) -> Simd<T, LANES>
+        where
+            T: SimdElement,
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            // This is preferred over `[value; LANES]`, since it's explicitly a splat:
+            // https://github.com/rust-lang/rust/issues/97804
+            struct Splat;
+            impl<const LANES: usize> Swizzle<1, LANES> for Splat {
+                const INDEX: [usize; LANES] = [0; LANES];
+            }
+            Splat::swizzle(Simd::<T, 1>::from([value]))
+        }
+
+        // Safety: both functions return a vector with every lane set to `value`
+        unsafe {
+            core::intrinsics::const_eval_select(
+                (value,),
+                splat_const::<T, LANES>,
+                splat_rt::<T, LANES>,
+            )
         }
-        Splat::swizzle(Simd::<T, 1>::from([value]))
     }
 
     /// Returns an array reference containing the entire SIMD vector.
@@ -154,6 +181,21 @@ impl<const LANES: usize> Swizzle<1, LANES> for Splat {
     }
 
     /// Converts an array to a SIMD vector.
+    ///
+    /// This is a `const fn`, so it can build lookup tables of vectors at compile time.
+    /// Lanes can be read in constants through [`Self::as_array`] or [`Self::to_array`], since
+    /// indexing a vector directly is not yet `const`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # use core::simd::u8x4;
+    /// const MASKS: [u8x4; 2] = [u8x4::from_array([1, 2, 4, 8]), u8x4::splat(0xff)];
+    /// const FIRST: u8 = MASKS[0].as_array()[3];
+    /// assert_eq!(FIRST, 8);
+    /// assert_eq!(MASKS[1][2], 0xff);
+    /// ```
     pub const fn from_array(array: [T; LANES]) -> Self {
         Self(array)
     }
@@ -190,13 +232,16 @@ pub const fn from_slice(slice: &[T]) -> Self {
         Self(array)
     }
 
-    /// Performs lanewise conversion of a SIMD vector's elements to another SIMD-valid type.
+    /// Performs lanewise conversion of a SIMD vector's elements to another numeric type.
     ///
     /// This follows the semantics of Rust's `as` conversion for casting
     /// integers to unsigned integers (interpreting as the other type, so `-1` to `MAX`),
     /// and from floats to integers (truncating, or saturating at the limits) for each lane,
     /// or vice versa.
     ///
+    /// Vectors of pointers can't be cast with this method, see `cast_ptr`,
+    /// [`SimdConstPtr::expose_addr`] and [`SimdConstPtr::from_exposed_addr`] instead.
+    ///
     /// # Examples
     /// ```
     /// # #![feature(portable_simd)]
@@ -215,8 +260,11 @@ pub const fn from_slice(slice: &[T]) -> Self {
     /// ```
     #[must_use]
     #[inline]
-    pub fn cast<U: SimdElement>(self) -> Simd<U, LANES> {
-        // Safety: The input argument is a vector of a valid SIMD element type.
+    pub fn cast<U: SimdCast>(self) -> Simd<U, LANES>
+    where
+        T: SimdCast,
+    {
+        // Safety: `SimdCast` is only implemented for numeric types, which `simd_as` supports.
         unsafe { intrinsics::simd_as(self) }
     }
 
@@ -351,11 +399,72 @@ pub unsafe fn gather_select_unchecked(
         idxs: Simd<usize, LANES>,
         or: Self,
     ) -> Self {
-        let base_ptr = crate::simd::ptr::SimdConstPtr::splat(slice.as_ptr());
+        let base_ptr = Simd::<*const T, LANES>::splat(slice.as_ptr());
         // Ferris forgive me, I have done pointer arithmetic here.
         let ptrs = base_ptr.wrapping_add(idxs);
         // Safety: The ptrs have been bounds-masked to prevent memory-unsafe reads insha'allah
-        unsafe { intrinsics::simd_gather(or, ptrs, enable.to_int()) }
+        unsafe { Self::gather_select_ptr(ptrs, enable, or) }
+    }
+
+    /// Read pointers elementwise into a SIMD vector.
+    ///
+    /// # Safety
+    ///
+    /// Each read must satisfy the same conditions as [`core::ptr::read`].
+    ///
+    /// # Example
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdConstPtr};
+    /// let values = [6, 2, 4, 9];
+    /// let offsets = Simd::from_array([1, 0, 0, 3]);
+    /// let source = Simd::splat(values.as_ptr()).wrapping_add(offsets);
+    /// let gathered = unsafe { Simd::gather_ptr(source) };
+    /// assert_eq!(gathered, Simd::from_array([2, 6, 6, 9]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub unsafe fn gather_ptr(source: Simd<*const T, LANES>) -> Self
+    where
+        T: Default,
+    {
+        // TODO: add an intrinsic that doesn't use a passthru vector, and remove the T: Default bound
+        // Safety: The caller is responsible for upholding all invariants
+        unsafe { Self::gather_select_ptr(source, Mask::splat(true), Self::default()) }
+    }
+
+    /// Conditionally read pointers elementwise into a SIMD vector.
+    /// The mask `enable`s all `true` lanes and disables all `false` lanes.
+    /// If a lane is disabled, the lane is selected from the `or` vector.
+    ///
+    /// # Safety
+    ///
+    /// Enabled lanes must satisfy the same conditions as [`core::ptr::read`].
+    ///
+    /// # Example
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Mask, Simd, SimdConstPtr};
+    /// let values = [6, 2, 4, 9];
+    /// let enable = Mask::from_array([true, true, false, true]);
+    /// let offsets = Simd::from_array([1, 0, 0, 3]);
+    /// let source = Simd::splat(values.as_ptr()).wrapping_add(offsets);
+    /// let gathered = unsafe { Simd::gather_select_ptr(source, enable, Simd::splat(0)) };
+    /// assert_eq!(gathered, Simd::from_array([2, 6, 0, 9]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub unsafe fn gather_select_ptr(
+        source: Simd<*const T, LANES>,
+        enable: Mask<isize, LANES>,
+        or: Self,
+    ) -> Self {
+        // Safety: The caller is responsible for upholding all invariants
+        unsafe { intrinsics::simd_gather(or, source, enable.to_int()) }
     }
 
     /// Writes the values in a SIMD vector to potentially discontiguous indices in `slice`.
@@ -458,14 +567,385 @@ pub unsafe fn scatter_select_unchecked(
         // 3. &mut [T] which will become our base ptr.
         unsafe {
             // Now Entering ☢️ *mut T Zone
-            let base_ptr = crate::simd::ptr::SimdMutPtr::splat(slice.as_mut_ptr());
+            let base_ptr = Simd::<*mut T, LANES>::splat(slice.as_mut_ptr());
             // Ferris forgive me, I have done pointer arithmetic here.
             let ptrs = base_ptr.wrapping_add(idxs);
             // The ptrs have been bounds-masked to prevent memory-unsafe writes insha'allah
-            intrinsics::simd_scatter(self, ptrs, enable.to_int())
+            self.scatter_select_ptr(ptrs, enable);
             // Cleared ☢️ *mut T Zone
         }
     }
+
+    /// Write pointers elementwise into a SIMD vector.
+    ///
+    /// # Safety
+    ///
+    /// Each write must satisfy the same conditions as [`core::ptr::write`].
+    ///
+    /// # Example
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, SimdMutPtr};
+    /// let mut values = [0; 4];
+    /// let offset = Simd::from_array([3, 2, 1, 0]);
+    /// let ptrs = Simd::splat(values.as_mut_ptr()).wrapping_add(offset);
+    /// unsafe { Simd::from_array([6, 3, 5, 7]).scatter_ptr(ptrs); }
+    /// assert_eq!(values, [7, 5, 3, 6]);
+    /// ```
+    #[inline]
+    pub unsafe fn scatter_ptr(self, dest: Simd<*mut T, LANES>) {
+        // Safety: The caller is responsible for upholding all invariants
+        unsafe { self.scatter_select_ptr(dest, Mask::splat(true)) }
+    }
+
+    /// Conditionally write pointers elementwise into a SIMD vector.
+    /// The mask `enable`s all `true` lanes and disables all `false` lanes.
+    /// If a lane is disabled, the write to that lane is skipped.
+    ///
+    /// # Safety
+    ///
+    /// Enabled lanes must satisfy the same conditions as [`core::ptr::write`].
+    ///
+    /// # Example
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Mask, Simd, SimdMutPtr};
+    /// let mut values = [0; 4];
+    /// let offset = Simd::from_array([3, 2, 1, 0]);
+    /// let ptrs = Simd::splat(values.as_mut_ptr()).wrapping_add(offset);
+    /// let enable = Mask::from_array([true, true, false, false]);
+    /// unsafe { Simd::from_array([6, 3, 5, 7]).scatter_select_ptr(ptrs, enable); }
+    /// assert_eq!(values, [0, 0, 3, 6]);
+    /// ```
+    #[inline]
+    pub unsafe fn scatter_select_ptr(self, dest: Simd<*mut T, LANES>, enable: Mask<isize, LANES>) {
+        // Safety: The caller is responsible for upholding all invariants
+        unsafe { intrinsics::simd_scatter(self, dest, enable.to_int()) }
+    }
+
+    /// Reads the first `LANES` elements of `slice` to construct a SIMD vector.
+    /// If `slice` is shorter than that, the lanes past its end are selected from the `or` vector.
+    ///
+    /// Unlike [`Self::from_slice`], this does not panic on short slices, so it can load the tail
+    /// left over after splitting a slice with [`slice::as_simd`].
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # use core::simd::Simd;
+    /// let vec: Vec<i32> = vec![10, 11];
+    /// let alt = Simd::from_array([-5, -4, -3, -2]);
+    ///
+    /// let result = Simd::load_or(&vec, alt);
+    /// assert_eq!(result, Simd::from_array([10, 11, -3, -2]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn load_or(slice: &[T], or: Self) -> Self {
+        Self::load_select(slice, Mask::splat(true), or)
+    }
+
+    /// Reads the first `LANES` elements of `slice` to construct a SIMD vector.
+    /// If `slice` is shorter than that, the lanes past its end are set to the default value for
+    /// the type.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # use core::simd::Simd;
+    /// let vec: Vec<i32> = vec![10, 11];
+    ///
+    /// let result = Simd::load_or_default(&vec);
+    /// assert_eq!(result, Simd::from_array([10, 11, 0, 0]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn load_or_default(slice: &[T]) -> Self
+    where
+        T: Default,
+    {
+        Self::load_or(slice, Self::default())
+    }
+
+    /// Reads contiguous lanes from the start of `slice` to construct a SIMD vector.
+    /// The mask `enable`s all `true` lanes and disables all `false` lanes.
+    /// If a lane is disabled or is past the end of `slice`, it is set to the default value for
+    /// the type.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, Mask};
+    /// let vec: Vec<i32> = vec![10, 11, 12];
+    /// let enable = Mask::from_array([true, false, true, true]); // Note the lane past the end.
+    ///
+    /// let result = Simd::load_select_or_default(&vec, enable);
+    /// assert_eq!(result, Simd::from_array([10, 0, 12, 0]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn load_select_or_default(
+        slice: &[T],
+        enable: Mask<<T as SimdElement>::Mask, LANES>,
+    ) -> Self
+    where
+        T: Default,
+    {
+        Self::load_select(slice, enable, Self::default())
+    }
+
+    /// Reads contiguous lanes from the start of `slice` to construct a SIMD vector.
+    /// The mask `enable`s all `true` lanes and disables all `false` lanes.
+    /// If a lane is disabled or is past the end of `slice`, it is selected from the `or` vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, Mask};
+    /// let vec: Vec<i32> = vec![10, 11, 12];
+    /// let alt = Simd::from_array([-5, -4, -3, -2]);
+    /// let enable = Mask::from_array([true, false, true, true]); // Note the lane past the end.
+    ///
+    /// let result = Simd::load_select(&vec, enable, alt);
+    /// assert_eq!(result, Simd::from_array([10, -4, 12, -2]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn load_select(
+        slice: &[T],
+        enable: Mask<<T as SimdElement>::Mask, LANES>,
+        or: Self,
+    ) -> Self {
+        let enable = enable & mask_up_to(slice.len());
+        // Safety: We have masked-off out-of-bounds lanes.
+        unsafe { Self::load_select_unchecked(slice, enable, or) }
+    }
+
+    /// Reads contiguous lanes from the start of `slice` to construct a SIMD vector.
+    /// The mask `enable`s all `true` lanes and disables all `false` lanes.
+    /// If a lane is disabled, it is selected from the `or` vector.
+    ///
+    /// # Safety
+    ///
+    /// Enabling a lane past the end of `slice` is *[undefined behavior]*
+    /// even if the resulting value is not used.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    #[must_use]
+    #[inline]
+    pub unsafe fn load_select_unchecked(
+        slice: &[T],
+        enable: Mask<<T as SimdElement>::Mask, LANES>,
+        or: Self,
+    ) -> Self {
+        // Safety: The caller is responsible for keeping enabled lanes in bounds
+        unsafe { Self::load_select_ptr(slice.as_ptr(), enable, or) }
+    }
+
+    /// Conditionally reads contiguous lanes starting at `ptr` into a SIMD vector.
+    /// The mask `enable`s all `true` lanes and disables all `false` lanes.
+    /// If a lane is disabled, it is selected from the `or` vector and its address is not read.
+    ///
+    /// # Safety
+    ///
+    /// Each enabled lane `i` must satisfy the same conditions as [`core::ptr::read`] for
+    /// `ptr.wrapping_add(i)`.
+    #[must_use]
+    #[inline]
+    pub unsafe fn load_select_ptr(
+        ptr: *const T,
+        enable: Mask<<T as SimdElement>::Mask, LANES>,
+        or: Self,
+    ) -> Self {
+        #[cfg(not(bootstrap))]
+        // Safety: The caller is responsible for upholding all invariants
+        let loaded = unsafe { intrinsics::simd_masked_load(enable.to_int(), ptr, or) };
+        // The bootstrap compiler doesn't know `simd_masked_load` yet.
+        #[cfg(bootstrap)]
+        // Safety: The caller is responsible for upholding all invariants
+        let loaded = unsafe {
+            Self::gather_select_ptr(
+                Simd::splat(ptr).wrapping_add(lane_indices()),
+                enable.cast(),
+                or,
+            )
+        };
+        loaded
+    }
+
+    /// Writes the lanes of a SIMD vector to the start of `slice`.
+    /// The mask `enable`s all `true` lanes and disables all `false` lanes.
+    /// Disabled lanes and lanes past the end of `slice` are not written.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, Mask};
+    /// let mut vec: Vec<i32> = vec![10, 11, 12];
+    /// let vals = Simd::from_array([-27, 82, -41, 124]);
+    /// let enable = Mask::from_array([true, false, true, true]); // Note the lane past the end.
+    ///
+    /// vals.store_select(&mut vec, enable);
+    /// assert_eq!(vec, vec![-27, 11, -41]);
+    /// ```
+    #[inline]
+    pub fn store_select(self, slice: &mut [T], enable: Mask<<T as SimdElement>::Mask, LANES>) {
+        let enable = enable & mask_up_to(slice.len());
+        // Safety: We have masked-off out-of-bounds lanes.
+        unsafe { self.store_select_unchecked(slice, enable) }
+    }
+
+    /// Writes the lanes of a SIMD vector to the start of `slice`.
+    /// The mask `enable`s all `true` lanes and disables all `false` lanes.
+    /// Disabled lanes are not written.
+    ///
+    /// # Safety
+    ///
+    /// Enabling a lane past the end of `slice` is *[undefined behavior]*,
+    /// and may lead to memory corruption.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    #[inline]
+    pub unsafe fn store_select_unchecked(
+        self,
+        slice: &mut [T],
+        enable: Mask<<T as SimdElement>::Mask, LANES>,
+    ) {
+        // Safety: The caller is responsible for keeping enabled lanes in bounds
+        unsafe { self.store_select_ptr(slice.as_mut_ptr(), enable) }
+    }
+
+    /// Conditionally writes the lanes of a SIMD vector to contiguous memory starting at `ptr`.
+    /// The mask `enable`s all `true` lanes and disables all `false` lanes.
+    /// If a lane is disabled, its address is not written.
+    ///
+    /// # Safety
+    ///
+    /// Each enabled lane `i` must satisfy the same conditions as [`core::ptr::write`] for
+    /// `ptr.wrapping_add(i)`.
+    #[inline]
+    pub unsafe fn store_select_ptr(
+        self,
+        ptr: *mut T,
+        enable: Mask<<T as SimdElement>::Mask, LANES>,
+    ) {
+        // Safety: The caller is responsible for upholding all invariants
+        unsafe {
+            #[cfg(not(bootstrap))]
+            intrinsics::simd_masked_store(enable.to_int(), ptr, self);
+            // The bootstrap compiler doesn't know `simd_masked_store` yet.
+            #[cfg(bootstrap)]
+            self.scatter_select_ptr(Simd::splat(ptr).wrapping_add(lane_indices()), enable.cast());
+        }
+    }
+
+    /// Reads a vector from the contiguous lanes starting at `ptr`, which does not need to be
+    /// aligned.
+    ///
+    /// This is the vector version of [`core::ptr::read_unaligned`].
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of `LANES` elements, as for `ptr::read_unaligned`.
+    ///
+    /// # Example
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # use core::simd::Simd;
+    /// let bytes = [0u8, 1, 2, 3, 4, 5];
+    /// // Safety: there are four bytes from `bytes[1]` onwards
+    /// let v = unsafe { Simd::<u8, 4>::read_unaligned(bytes.as_ptr().add(1)) };
+    /// assert_eq!(v.to_array(), [1, 2, 3, 4]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub unsafe fn read_unaligned(ptr: *const T) -> Self {
+        // Safety: The caller is responsible for upholding all invariants
+        unsafe { ptr.cast::<Self>().read_unaligned() }
+    }
+
+    /// Writes the vector to the contiguous lanes starting at `ptr`, which does not need to be
+    /// aligned.
+    ///
+    /// This is the vector version of [`core::ptr::write_unaligned`].
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for writes of `LANES` elements, as for `ptr::write_unaligned`.
+    ///
+    /// # Example
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # use core::simd::Simd;
+    /// let mut bytes = [0u8; 6];
+    /// // Safety: there are four bytes from `bytes[1]` onwards
+    /// unsafe { Simd::from_array([1, 2, 3, 4]).write_unaligned(bytes.as_mut_ptr().add(1)) };
+    /// assert_eq!(bytes, [0, 1, 2, 3, 4, 0]);
+    /// ```
+    #[inline]
+    pub unsafe fn write_unaligned(self, ptr: *mut T) {
+        // Safety: The caller is responsible for upholding all invariants
+        unsafe { ptr.cast::<Self>().write_unaligned(self) }
+    }
+
+    /// Writes the vector to `ptr` with a non-temporal store, which hints that the memory will not
+    /// be read again soon and does not need to be brought into the cache.
+    ///
+    /// This can speed up writing large buffers that are not read back right away, at the cost of
+    /// slower reads if they are.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid for writes of `Self` and aligned to `align_of::<Self>()`.
+    ///
+    /// On some targets, including x86, non-temporal stores are not ordered with other memory
+    /// accesses, and [`core::sync::atomic::fence`] does not order them either. Before any other
+    /// thread may read the written memory, for example before releasing a lock or setting an
+    /// atomic flag, the writing thread must execute a store fence like x86's `_mm_sfence`.
+    /// Otherwise the other thread may not see the written values.
+    ///
+    /// # Example
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # use core::simd::Simd;
+    /// let mut buffer = [Simd::splat(0u32); 4];
+    /// for chunk in buffer.iter_mut() {
+    ///     let ptr = chunk.as_mut_array().as_mut_ptr();
+    ///     // Safety: `ptr` points to a valid and aligned `Simd<u32, 4>`
+    ///     unsafe { Simd::from_array([1, 2, 3, 4]).store_nontemporal(ptr) };
+    /// }
+    /// // Safety: SSE is always available on x86_64
+    /// #[cfg(target_arch = "x86_64")]
+    /// unsafe {
+    ///     core::arch::x86_64::_mm_sfence()
+    /// };
+    /// assert_eq!(buffer[3].to_array(), [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub unsafe fn store_nontemporal(self, ptr: *mut T) {
+        // Safety: The caller is responsible for upholding all invariants
+        unsafe { core::intrinsics::nontemporal_store(ptr.cast::<Self>(), self) }
+    }
+}
+
+/// Returns a mask that enables the first `len` lanes.
+#[inline]
+fn mask_up_to<M, const LANES: usize>(len: usize) -> Mask<M, LANES>
+where
+    M: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    lane_indices::<LANES>().simd_lt(Simd::splat(len)).cast()
 }
 
 impl<T, const LANES: usize> Copy for Simd<T, LANES>
@@ -685,6 +1165,13 @@ unsafe impl SimdElement for u64 {
     type Mask = i64;
 }
 
+impl Sealed for u128 {}
+
+// Safety: u128 is a valid SIMD element type, and is supported by this API
+unsafe impl SimdElement for u128 {
+    type Mask = i128;
+}
+
 impl Sealed for usize {}
 
 // Safety: usize is a valid SIMD element type, and is supported by this API
@@ -720,6 +1207,13 @@ unsafe impl SimdElement for i64 {
     type Mask = i64;
 }
 
+impl Sealed for i128 {}
+
+// Safety: i128 is a valid SIMD element type, and is supported by this API
+unsafe impl SimdElement for i128 {
+    type Mask = i128;
+}
+
 impl Sealed for isize {}
 
 // Safety: isize is a valid SIMD element type, and is supported by this API
@@ -740,3 +1234,21 @@ impl Sealed for f64 {}
 unsafe impl SimdElement for f64 {
     type Mask = i64;
 }
+
+impl<T> Sealed for *const T {}
+
+// Safety: (thin) const pointers are valid SIMD element types, and are supported by this API
+//
+// Fat pointers may be supported in the future.
+unsafe impl<T> SimdElement for *const T {
+    type Mask = isize;
+}
+
+impl<T> Sealed for *mut T {}
+
+// Safety: (thin) mut pointers are valid SIMD element types, and are supported by this API
+//
+// Fat pointers may be supported in the future.
+unsafe impl<T> SimdElement for *mut T {
+    type Mask = isize;
+}
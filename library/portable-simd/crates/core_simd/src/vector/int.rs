@@ -47,6 +47,12 @@
 /// A 512-bit SIMD vector with eight elements of type `i64`.
 pub type i64x8 = Simd<i64, 8>;
 
+/// A 256-bit SIMD vector with two elements of type `i128`.
+pub type i128x2 = Simd<i128, 2>;
+
+/// A 512-bit SIMD vector with four elements of type `i128`.
+pub type i128x4 = Simd<i128, 4>;
+
 /// A 32-bit SIMD vector with four elements of type `i8`.
 pub type i8x4 = Simd<i8, 4>;
 
@@ -47,6 +47,12 @@
 /// A 512-bit SIMD vector with eight elements of type `u64`.
 pub type u64x8 = Simd<u64, 8>;
 
+/// A 256-bit SIMD vector with two elements of type `u128`.
+pub type u128x2 = Simd<u128, 2>;
+
+/// A 512-bit SIMD vector with four elements of type `u128`.
+pub type u128x4 = Simd<u128, 4>;
+
 /// A 32-bit SIMD vector with four elements of type `u8`.
 pub type u8x4 = Simd<u8, 4>;
 
@@ -1,6 +1,6 @@
 // Test that we handle all our "auto-deref" cases correctly.
 #![feature(portable_simd)]
-use core_simd::f32x4;
+use core_simd::{f32x4, u32x4};
 
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen_test::*;
@@ -20,3 +20,44 @@ fn deref() {
     assert_eq!(f32x4::splat(3.0), a + y);
     assert_eq!(f32x4::splat(3.0), a + b);
 }
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deref_scalar() {
+    let x = f32x4::splat(1.0);
+    let a = &x;
+    let s = &2.0;
+    assert_eq!(f32x4::splat(3.0), a + 2.0);
+    assert_eq!(f32x4::splat(3.0), x + s);
+    assert_eq!(f32x4::splat(3.0), a + s);
+    assert_eq!(f32x4::splat(1.0), 2.0 - a);
+    assert_eq!(f32x4::splat(1.0), s - x);
+    assert_eq!(f32x4::splat(1.0), s - a);
+
+    let y = u32x4::splat(1);
+    let b = &y;
+    assert_eq!(u32x4::splat(4), b << 2);
+    assert_eq!(u32x4::splat(4), y << &2);
+    assert_eq!(u32x4::splat(4), b << &2);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deref_unary() {
+    let x = f32x4::splat(1.0);
+    assert_eq!(f32x4::splat(-1.0), -&x);
+    let y = u32x4::splat(0);
+    assert_eq!(u32x4::splat(u32::MAX), !&y);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sum_product() {
+    let xs = [f32x4::splat(1.0), f32x4::splat(2.0), f32x4::splat(3.0)];
+    assert_eq!(f32x4::splat(6.0), xs.iter().sum::<f32x4>());
+    assert_eq!(f32x4::splat(6.0), xs.iter().product::<f32x4>());
+    assert_eq!(
+        f32x4::splat(12.0),
+        xs.iter().map(|x| x * 2.0).sum::<f32x4>()
+    );
+}
@@ -35,3 +35,45 @@ fn cast_as<const N: usize>() {
 cast_types!(u64, i64, i8, u8, isize, usize, f32, f64);
 cast_types!(isize, usize, i8, u8, f32, f64);
 cast_types!(usize, isize, i8, u8, f32, f64);
+
+macro_rules! float_to_int_casts {
+    ($start:ident, $($target:ident),*) => {
+        mod $start {
+            use core_simd::simd::Simd;
+            type Vector<const N: usize> = Simd<$start, N>;
+            $(
+                mod $target {
+                    use super::*;
+                    test_helpers::test_lanes! {
+                        fn cast_saturating<const N: usize>() {
+                            test_helpers::test_unary_elementwise(
+                                &Vector::<N>::cast_saturating::<$target>,
+                                &|x| x as $target,
+                                &|_| true,
+                            )
+                        }
+
+                        fn cast_wrapping<const N: usize>() {
+                            test_helpers::test_unary_elementwise(
+                                &Vector::<N>::cast_wrapping::<$target>,
+                                // `%` is exact for floats, and 2^64 is a multiple of the modulus
+                                // for every target type.
+                                &|x| if x.is_finite() {
+                                    (x.trunc() % 18446744073709551616.0) as i128 as $target
+                                } else {
+                                    0
+                                },
+                                &|_| true,
+                            )
+                        }
+                    }
+                }
+            )*
+        }
+    };
+}
+
+mod float_to_int {
+    float_to_int_casts!(f32, i8, u8, i32, u32, i64, u64, isize, usize);
+    float_to_int_casts!(f64, i8, u8, i32, u32, i64, u64, isize, usize);
+}
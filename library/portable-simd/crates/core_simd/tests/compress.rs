@@ -0,0 +1,62 @@
+#![feature(portable_simd)]
+use core_simd::{Mask, Simd};
+
+fn compress_scalar_ver<const N: usize>(values: [i32; N], enable: [bool; N]) -> [i32; N] {
+    let mut array = [0; N];
+    let mut kept = 0;
+    for i in 0..N {
+        if enable[i] {
+            array[kept] = values[i];
+            kept += 1;
+        }
+    }
+    array
+}
+
+fn expand_scalar_ver<const N: usize>(values: [i32; N], enable: [bool; N]) -> [i32; N] {
+    let mut array = [0; N];
+    let mut taken = 0;
+    for i in 0..N {
+        if enable[i] {
+            array[i] = values[taken];
+            taken += 1;
+        }
+    }
+    array
+}
+
+test_helpers::test_lanes! {
+    fn compress<const N: usize>() {
+        test_helpers::test_2(&|values: [i32; N], selectors: [i32; N]| {
+            let enable = selectors.map(|x| x < 0);
+            test_helpers::prop_assert_biteq!(
+                Simd::from_array(values).compress(Mask::from_array(enable)).to_array(),
+                compress_scalar_ver(values, enable),
+            );
+            Ok(())
+        });
+    }
+
+    fn expand<const N: usize>() {
+        test_helpers::test_2(&|values: [i32; N], selectors: [i32; N]| {
+            let enable = selectors.map(|x| x < 0);
+            test_helpers::prop_assert_biteq!(
+                Simd::from_array(values).expand(Mask::from_array(enable)).to_array(),
+                expand_scalar_ver(values, enable),
+            );
+            Ok(())
+        });
+    }
+
+    fn expand_undoes_compress<const N: usize>() {
+        test_helpers::test_2(&|values: [i64; N], selectors: [i64; N]| {
+            let enable = Mask::from_array(selectors.map(|x| x < 0));
+            let values = Simd::from_array(values);
+            test_helpers::prop_assert_biteq!(
+                values.compress(enable).expand(enable).to_array(),
+                enable.select(values, Simd::splat(0)).to_array(),
+            );
+            Ok(())
+        });
+    }
+}
@@ -0,0 +1,21 @@
+#![feature(portable_simd)]
+use core_simd::Simd;
+
+const TABLE: [Simd<u16, 8>; 3] = [
+    Simd::splat(7),
+    Simd::from_array([0, 1, 2, 3, 4, 5, 6, 7]),
+    Simd::from_slice(&[9, 8, 7, 6, 5, 4, 3, 2, 1]),
+];
+const LAST_LANES: [u16; 3] = [
+    TABLE[0].as_array()[7],
+    TABLE[1].to_array()[7],
+    TABLE[2].as_array()[7],
+];
+
+#[test]
+fn const_matches_runtime() {
+    assert_eq!(TABLE[0], Simd::splat(7));
+    assert_eq!(TABLE[1].to_array(), [0, 1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(TABLE[2].to_array(), [9, 8, 7, 6, 5, 4, 3, 2]);
+    assert_eq!(LAST_LANES, [7, 7, 2]);
+}
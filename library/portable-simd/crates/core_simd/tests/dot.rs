@@ -0,0 +1,47 @@
+#![feature(portable_simd, generic_const_exprs, adt_const_params)]
+#![allow(incomplete_features)]
+#![cfg(feature = "generic_const_exprs")]
+
+use core_simd::Simd;
+
+macro_rules! dot_widening_tests {
+    { $($scalar:ident => $wide:ty;)* } => {
+        $(
+        mod $scalar {
+            use super::*;
+
+            fn check<const LANES: usize>()
+            where
+                core_simd::LaneCount<LANES>: core_simd::SupportedLaneCount,
+                core_simd::LaneCount<{ LANES / 4 }>: core_simd::SupportedLaneCount,
+            {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    let dot = Simd::from_array(x).dot_widening(Simd::from_array(y));
+                    for i in 0..LANES / 4 {
+                        let mut expected: $wide = 0;
+                        for j in 4 * i..4 * i + 4 {
+                            expected += x[j] as $wide * y[j] as $wide;
+                        }
+                        test_helpers::prop_assert_biteq!(dot[i], expected);
+                    }
+                    Ok(())
+                });
+            }
+
+            #[test]
+            fn dot_widening() {
+                check::<4>();
+                check::<8>();
+                check::<16>();
+                check::<32>();
+                check::<64>();
+            }
+        }
+        )*
+    }
+}
+
+dot_widening_tests! {
+    i8 => i32;
+    u8 => u32;
+}
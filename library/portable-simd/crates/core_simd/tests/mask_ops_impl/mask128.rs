@@ -0,0 +1,2 @@
+mask_tests! { mask128x2, 2 }
+mask_tests! { mask128x4, 4 }
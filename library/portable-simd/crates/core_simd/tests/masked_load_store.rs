@@ -0,0 +1,72 @@
+#![feature(portable_simd)]
+use core_simd::{Mask, Simd};
+
+/// Enables every lane except each third one.
+fn enable_pattern<const N: usize>() -> [bool; N] {
+    let mut enable = [false; N];
+    for (i, lane) in enable.iter_mut().enumerate() {
+        *lane = i % 3 != 1;
+    }
+    enable
+}
+
+test_helpers::test_lanes! {
+    fn load_or<const N: usize>() {
+        test_helpers::test_2(&|values: [i32; N], or: [i32; N]| {
+            for len in 0..=N {
+                let loaded = Simd::load_or(&values[..len], Simd::from_array(or));
+                let mut expected = or;
+                expected[..len].copy_from_slice(&values[..len]);
+                test_helpers::prop_assert_biteq!(loaded.to_array(), expected);
+            }
+            Ok(())
+        });
+    }
+
+    fn load_select<const N: usize>() {
+        test_helpers::test_2(&|values: [i32; N], or: [i32; N]| {
+            let enable = enable_pattern::<N>();
+            for len in 0..=N {
+                let loaded = Simd::load_select(
+                    &values[..len],
+                    Mask::from_array(enable),
+                    Simd::from_array(or),
+                );
+                let mut expected = or;
+                for i in 0..len {
+                    if enable[i] {
+                        expected[i] = values[i];
+                    }
+                }
+                test_helpers::prop_assert_biteq!(loaded.to_array(), expected);
+            }
+            Ok(())
+        });
+    }
+
+    fn store_select<const N: usize>() {
+        test_helpers::test_2(&|values: [i32; N], init: [i32; N]| {
+            let enable = enable_pattern::<N>();
+            for len in 0..=N {
+                let mut stored = init;
+                Simd::from_array(values).store_select(&mut stored[..len], Mask::from_array(enable));
+                let mut expected = init;
+                for i in 0..len {
+                    if enable[i] {
+                        expected[i] = values[i];
+                    }
+                }
+                test_helpers::prop_assert_biteq!(stored, expected);
+            }
+            Ok(())
+        });
+    }
+}
+
+#[test]
+fn load_select_or_default() {
+    let values = [1u8, 2, 3];
+    let enable = Mask::from_array([true, true, false, true]);
+    let loaded = Simd::<u8, 4>::load_select_or_default(&values, enable);
+    assert_eq!(loaded.to_array(), [1, 2, 0, 0]);
+}
@@ -59,6 +59,46 @@ fn all() {
                 assert!(!v.all());
             }
 
+            #[test]
+            fn first_last_count_set() {
+                let none = core_simd::Mask::<$type, 8>::splat(false);
+                assert_eq!(none.first_set(), None);
+                assert_eq!(none.last_set(), None);
+                assert_eq!(none.count_set(), 0);
+                let all = core_simd::Mask::<$type, 8>::splat(true);
+                assert_eq!(all.first_set(), Some(0));
+                assert_eq!(all.last_set(), Some(7));
+                assert_eq!(all.count_set(), 8);
+                let values = [false, false, true, false, true, true, false, false];
+                let mask = core_simd::Mask::<$type, 8>::from_array(values);
+                assert_eq!(mask.first_set(), Some(2));
+                assert_eq!(mask.last_set(), Some(5));
+                assert_eq!(mask.count_set(), 3);
+            }
+
+            #[test]
+            fn debug_format() {
+                let values = [true, false, true, true];
+                let mask = core_simd::Mask::<$type, 4>::from_array(values);
+                assert_eq!(format!("{:?}", mask), "Mask(1011)");
+                assert_eq!(format!("{:#?}", mask), format!("{:#?}", values));
+            }
+
+            #[test]
+            fn hash_matches_array() {
+                use std::collections::hash_map::DefaultHasher;
+                use std::hash::{Hash, Hasher};
+                fn hash_of(value: impl Hash) -> u64 {
+                    let mut hasher = DefaultHasher::new();
+                    value.hash(&mut hasher);
+                    hasher.finish()
+                }
+                let values = [true, false, false, true, true, false, true, false];
+                let mask = core_simd::Mask::<$type, 8>::from_array(values);
+                assert_eq!(hash_of(mask), hash_of(values));
+                assert_ne!(hash_of(mask), hash_of(!mask));
+            }
+
             #[test]
             fn roundtrip_int_conversion() {
                 let values = [true, false, false, true, false, false, true, false];
@@ -100,6 +140,27 @@ fn roundtrip_bitmask_conversion_short() {
                 assert_eq!(core_simd::Mask::<$type, 2>::from_bitmask(bitmask), mask);
             }
 
+            #[test]
+            fn roundtrip_bitmask_vector_conversion() {
+                let values = [
+                    true, false, false, true, false, false, true, false,
+                    true, true, false, false, false, false, false, true,
+                ];
+                let mask = core_simd::Mask::<$type, 16>::from_array(values);
+                let bitmask = mask.to_bitmask_vector();
+                assert_eq!(bitmask[..2], [0b01001001, 0b10000011]);
+                assert!(bitmask[2..].iter().all(|&byte| byte == 0));
+                assert_eq!(core_simd::Mask::<$type, 16>::from_bitmask_vector(bitmask), mask);
+
+                let mut values = [false; 64];
+                values[0] = true;
+                values[63] = true;
+                let mask = core_simd::Mask::<$type, 64>::from_array(values);
+                let bitmask = mask.to_bitmask_vector();
+                assert_eq!(bitmask[..8], [1, 0, 0, 0, 0, 0, 0, 0x80]);
+                assert_eq!(core_simd::Mask::<$type, 64>::from_bitmask_vector(bitmask), mask);
+            }
+
             #[test]
             fn cast() {
                 fn cast_impl<T: core_simd::MaskElement>()
@@ -120,6 +181,7 @@ fn cast_impl<T: core_simd::MaskElement>()
                 cast_impl::<i16>();
                 cast_impl::<i32>();
                 cast_impl::<i64>();
+                cast_impl::<i128>();
                 cast_impl::<isize>();
             }
 
@@ -145,6 +207,7 @@ mod mask_api {
     test_mask_api! { i16 }
     test_mask_api! { i32 }
     test_mask_api! { i64 }
+    test_mask_api! { i128 }
     test_mask_api! { isize }
 }
 
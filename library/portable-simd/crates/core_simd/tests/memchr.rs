@@ -0,0 +1,43 @@
+#![feature(portable_simd)]
+use core_simd::{memchr, memrchr, Simd};
+
+test_helpers::test_lanes! {
+    fn find_eq<const N: usize>() {
+        test_helpers::test_2(&|bytes: [u8; N], needle: u8| {
+            // Also look for a byte that is known to be present.
+            for needle in [needle, bytes[N / 2]] {
+                let v = Simd::from_array(bytes);
+                proptest::prop_assert_eq!(
+                    v.find_first_eq(needle),
+                    bytes.iter().position(|&b| b == needle),
+                );
+                proptest::prop_assert_eq!(
+                    v.find_last_eq(needle),
+                    bytes.iter().rposition(|&b| b == needle),
+                );
+            }
+            Ok(())
+        });
+    }
+}
+
+#[test]
+fn memchr_matches_iter() {
+    // Cover empty input, partial chunks on either end, and several full chunks.
+    let text: Vec<u8> = (0..200u32).map(|i| (i * 7 % 23) as u8).collect();
+    for len in 0..text.len() {
+        let text = &text[..len];
+        for x in 0..24 {
+            assert_eq!(
+                memchr(x, text),
+                text.iter().position(|&b| b == x),
+                "len {len}, x {x}"
+            );
+            assert_eq!(
+                memrchr(x, text),
+                text.iter().rposition(|&b| b == x),
+                "len {len}, x {x}"
+            );
+        }
+    }
+}
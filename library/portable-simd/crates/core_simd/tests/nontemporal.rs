@@ -0,0 +1,15 @@
+#![feature(portable_simd)]
+use core_simd::Simd;
+
+test_helpers::test_lanes! {
+    fn store_nontemporal<const N: usize>() {
+        test_helpers::test_1(&|values: [u64; N]| {
+            let mut dest = Simd::<u64, N>::splat(0);
+            let ptr = dest.as_mut_array().as_mut_ptr();
+            // Safety: `ptr` points to a valid and aligned vector
+            unsafe { Simd::from_array(values).store_nontemporal(ptr) };
+            test_helpers::prop_assert_biteq!(dest.to_array(), values);
+            Ok(())
+        });
+    }
+}
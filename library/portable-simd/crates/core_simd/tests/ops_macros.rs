@@ -45,6 +45,30 @@ fn assign<const LANES: usize>() {
                         &|_, _| true,
                     );
                 }
+
+                fn scalar_rhs<const LANES: usize>() {
+                    test_helpers::test_binary_scalar_rhs_elementwise(
+                        &<Simd<$scalar, LANES> as core::ops::$trait<$scalar>>::$fn,
+                        &$scalar_fn,
+                        &|_, _| true,
+                    );
+                }
+
+                fn scalar_lhs<const LANES: usize>() {
+                    test_helpers::test_binary_scalar_lhs_elementwise(
+                        &<$scalar as core::ops::$trait<Simd<$scalar, LANES>>>::$fn,
+                        &$scalar_fn,
+                        &|_, _| true,
+                    );
+                }
+
+                fn assign_scalar<const LANES: usize>() {
+                    test_helpers::test_binary_scalar_rhs_elementwise(
+                        &|mut a, b| { <Simd<$scalar, LANES> as core::ops::$trait_assign<$scalar>>::$fn_assign(&mut a, b); a },
+                        &$scalar_fn,
+                        &|_, _| true,
+                    );
+                }
             }
         }
     };
@@ -82,6 +106,30 @@ fn assign<const LANES: usize>() {
                         &|x, y| x.iter().zip(y.iter()).all(|(x, y)| $check_fn(*x, *y)),
                     )
                 }
+
+                fn scalar_rhs<const LANES: usize>() {
+                    test_helpers::test_binary_scalar_rhs_elementwise(
+                        &<Simd<$scalar, LANES> as core::ops::$trait<$scalar>>::$fn,
+                        &$scalar_fn,
+                        &|x, y| x.iter().all(|x| $check_fn(*x, y)),
+                    );
+                }
+
+                fn scalar_lhs<const LANES: usize>() {
+                    test_helpers::test_binary_scalar_lhs_elementwise(
+                        &<$scalar as core::ops::$trait<Simd<$scalar, LANES>>>::$fn,
+                        &$scalar_fn,
+                        &|x, y| y.iter().all(|y| $check_fn(x, *y)),
+                    );
+                }
+
+                fn assign_scalar<const LANES: usize>() {
+                    test_helpers::test_binary_scalar_rhs_elementwise(
+                        &|mut a, b| { <Simd<$scalar, LANES> as core::ops::$trait_assign<$scalar>>::$fn_assign(&mut a, b); a },
+                        &$scalar_fn,
+                        &|x, y| x.iter().all(|x| $check_fn(*x, y)),
+                    )
+                }
             }
         }
     };
@@ -163,6 +211,177 @@ fn reduce_min<const LANES: usize>() {
                     Ok(())
                 });
             }
+
+            fn wrapping_add<const LANES: usize>() {
+                test_helpers::test_binary_elementwise(
+                    &$vector::<LANES>::wrapping_add,
+                    &$scalar::wrapping_add,
+                    &|_, _| true,
+                );
+            }
+
+            fn wrapping_sub<const LANES: usize>() {
+                test_helpers::test_binary_elementwise(
+                    &$vector::<LANES>::wrapping_sub,
+                    &$scalar::wrapping_sub,
+                    &|_, _| true,
+                );
+            }
+
+            fn wrapping_mul<const LANES: usize>() {
+                test_helpers::test_binary_elementwise(
+                    &$vector::<LANES>::wrapping_mul,
+                    &$scalar::wrapping_mul,
+                    &|_, _| true,
+                );
+            }
+
+            fn wrapping_neg<const LANES: usize>() {
+                test_helpers::test_unary_elementwise(
+                    &$vector::<LANES>::wrapping_neg,
+                    &$scalar::wrapping_neg,
+                    &|_| true,
+                );
+            }
+
+            fn abs_diff<const LANES: usize>() {
+                test_helpers::test_binary_elementwise(
+                    &$vector::<LANES>::abs_diff,
+                    &$scalar::abs_diff,
+                    &|_, _| true,
+                );
+            }
+
+            fn midpoint<const LANES: usize>() {
+                test_helpers::test_binary_elementwise(
+                    &$vector::<LANES>::midpoint,
+                    // The sum only overflows if both lanes have the same sign, and then halving
+                    // each remainder separately rounds the same way as halving the sum.
+                    &|a: $scalar, b: $scalar| {
+                        a.checked_add(b).map_or(a / 2 + b / 2 + (a % 2 + b % 2) / 2, |sum| sum / 2)
+                    },
+                    &|_, _| true,
+                );
+            }
+
+            fn overflowing_add<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    let (result, overflowed) =
+                        $vector::<LANES>::from_array(x).overflowing_add($vector::<LANES>::from_array(y));
+                    let mut expected = [(0 as $scalar, false); LANES];
+                    for i in 0..LANES {
+                        expected[i] = x[i].overflowing_add(y[i]);
+                    }
+                    test_helpers::prop_assert_biteq!(result.to_array(), expected.map(|e| e.0));
+                    test_helpers::prop_assert_biteq!(overflowed.to_array(), expected.map(|e| e.1));
+                    Ok(())
+                });
+            }
+
+            fn overflowing_sub<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    let (result, overflowed) =
+                        $vector::<LANES>::from_array(x).overflowing_sub($vector::<LANES>::from_array(y));
+                    let mut expected = [(0 as $scalar, false); LANES];
+                    for i in 0..LANES {
+                        expected[i] = x[i].overflowing_sub(y[i]);
+                    }
+                    test_helpers::prop_assert_biteq!(result.to_array(), expected.map(|e| e.0));
+                    test_helpers::prop_assert_biteq!(overflowed.to_array(), expected.map(|e| e.1));
+                    Ok(())
+                });
+            }
+
+            fn overflowing_mul<const LANES: usize>() {
+                test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                    let (result, overflowed) =
+                        $vector::<LANES>::from_array(x).overflowing_mul($vector::<LANES>::from_array(y));
+                    let mut expected = [(0 as $scalar, false); LANES];
+                    for i in 0..LANES {
+                        expected[i] = x[i].overflowing_mul(y[i]);
+                    }
+                    test_helpers::prop_assert_biteq!(result.to_array(), expected.map(|e| e.0));
+                    test_helpers::prop_assert_biteq!(overflowed.to_array(), expected.map(|e| e.1));
+                    Ok(())
+                });
+            }
+
+            fn leading_zeros<const LANES: usize>() {
+                test_helpers::test_1(&|x| {
+                    test_helpers::prop_assert_biteq! (
+                        $vector::<LANES>::from_array(x).leading_zeros().cast::<u32>().to_array(),
+                        x.map($scalar::leading_zeros),
+                    );
+                    Ok(())
+                });
+            }
+
+            fn trailing_zeros<const LANES: usize>() {
+                test_helpers::test_1(&|x| {
+                    test_helpers::prop_assert_biteq! (
+                        $vector::<LANES>::from_array(x).trailing_zeros().cast::<u32>().to_array(),
+                        x.map($scalar::trailing_zeros),
+                    );
+                    Ok(())
+                });
+            }
+
+            fn count_ones<const LANES: usize>() {
+                test_helpers::test_1(&|x| {
+                    test_helpers::prop_assert_biteq! (
+                        $vector::<LANES>::from_array(x).count_ones().cast::<u32>().to_array(),
+                        x.map($scalar::count_ones),
+                    );
+                    Ok(())
+                });
+            }
+
+            fn prefix_sum<const LANES: usize>() {
+                test_helpers::test_1(&|x: [$scalar; LANES]| {
+                    let mut inclusive = x;
+                    let mut exclusive = [0 as $scalar; LANES];
+                    for i in 1..LANES {
+                        inclusive[i] = inclusive[i - 1].wrapping_add(x[i]);
+                        exclusive[i] = inclusive[i - 1];
+                    }
+                    let v = $vector::<LANES>::from_array(x);
+                    test_helpers::prop_assert_biteq!(v.prefix_sum().to_array(), inclusive);
+                    test_helpers::prop_assert_biteq!(v.exclusive_prefix_sum().to_array(), exclusive);
+                    Ok(())
+                });
+            }
+
+            fn shl_scalar<const LANES: usize>() {
+                test_helpers::test_binary_scalar_rhs_elementwise(
+                    &<$vector<LANES> as core::ops::Shl<u32>>::shl,
+                    &$scalar::wrapping_shl,
+                    &|_, _| true,
+                );
+            }
+
+            fn shr_scalar<const LANES: usize>() {
+                test_helpers::test_binary_scalar_rhs_elementwise(
+                    &<$vector<LANES> as core::ops::Shr<u32>>::shr,
+                    &$scalar::wrapping_shr,
+                    &|_, _| true,
+                );
+            }
+
+            fn shl_assign_scalar<const LANES: usize>() {
+                test_helpers::test_binary_scalar_rhs_elementwise(
+                    &|mut a, b| { <$vector<LANES> as core::ops::ShlAssign<u32>>::shl_assign(&mut a, b); a },
+                    &$scalar::wrapping_shl,
+                    &|_, _| true,
+                );
+            }
+
+            fn shr_assign_scalar<const LANES: usize>() {
+                test_helpers::test_binary_scalar_rhs_elementwise(
+                    &|mut a, b| { <$vector<LANES> as core::ops::ShrAssign<u32>>::shr_assign(&mut a, b); a },
+                    &$scalar::wrapping_shr,
+                    &|_, _| true,
+                );
+            }
         }
     }
 }
@@ -203,6 +422,22 @@ fn is_negative<const LANES: usize>() {
                     );
                 }
 
+                fn saturating_add<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &Vector::<LANES>::saturating_add,
+                        &Scalar::saturating_add,
+                        &|_, _| true,
+                    );
+                }
+
+                fn saturating_sub<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &Vector::<LANES>::saturating_sub,
+                        &Scalar::saturating_sub,
+                        &|_, _| true,
+                    );
+                }
+
                 fn signum<const LANES: usize>() {
                     test_helpers::test_unary_elementwise(
                         &Vector::<LANES>::signum,
@@ -276,6 +511,13 @@ fn rem_zero_panic<const LANES: usize>() {
                     let b = Vector::<LANES>::splat(0);
                     let _ = a % b;
                 }
+
+                fn simd_clamp_min_greater_than_max_panics<const LANES: usize>() {
+                    use core_simd::simd::SimdOrd;
+                    let mut min = Vector::<LANES>::splat(0);
+                    min[LANES - 1] = 2;
+                    let _ = Vector::<LANES>::splat(1).simd_clamp(min, Vector::splat(1));
+                }
             }
 
             test_helpers::test_lanes! {
@@ -327,6 +569,24 @@ fn rem_zero_panic<const LANES: usize>() {
                 }
             }
 
+            test_helpers::test_lanes! {
+                fn saturating_add<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &Vector::<LANES>::saturating_add,
+                        &Scalar::saturating_add,
+                        &|_, _| true,
+                    );
+                }
+
+                fn saturating_sub<const LANES: usize>() {
+                    test_helpers::test_binary_elementwise(
+                        &Vector::<LANES>::saturating_sub,
+                        &Scalar::saturating_sub,
+                        &|_, _| true,
+                    );
+                }
+            }
+
             impl_binary_op_test!(Scalar, Add::add, AddAssign::add_assign, Scalar::wrapping_add);
             impl_binary_op_test!(Scalar, Sub::sub, SubAssign::sub_assign, Scalar::wrapping_sub);
             impl_binary_op_test!(Scalar, Mul::mul, MulAssign::mul_assign, Scalar::wrapping_mul);
@@ -359,7 +619,58 @@ mod $scalar {
             impl_binary_op_test!(Scalar, Div::div, DivAssign::div_assign);
             impl_binary_op_test!(Scalar, Rem::rem, RemAssign::rem_assign);
 
+            test_helpers::test_lanes_panic! {
+                fn simd_clamp_nan_bound_panics<const LANES: usize>() {
+                    let mut max = Vector::<LANES>::splat(1.);
+                    max[0] = Scalar::NAN;
+                    let _ = Vector::<LANES>::splat(0.).simd_clamp(Vector::splat(-1.), max);
+                }
+            }
+
             test_helpers::test_lanes! {
+                fn midpoint<const LANES: usize>() {
+                    test_helpers::test_2(&|x: [Scalar; LANES], y: [Scalar; LANES]| {
+                        let result = Vector::<LANES>::from_array(x).midpoint(Vector::from_array(y));
+                        for i in 0..LANES {
+                            let (a, b, m) = (x[i], y[i], result[i]);
+                            let sum = a + b;
+                            if sum.is_finite() && (sum / 2.).is_normal() {
+                                // Halving is exact here, so there is only one right answer.
+                                test_helpers::prop_assert_biteq!(m, sum / 2.);
+                            } else if a.is_finite() && b.is_finite() {
+                                // The sum overflowed, or the average is tiny.
+                                proptest::prop_assert!(m.is_finite());
+                                proptest::prop_assert!(m >= a.min(b) && m <= a.max(b));
+                            }
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn total_lt<const LANES: usize>() {
+                    test_helpers::test_2(&|x: [Scalar; LANES], y: [Scalar; LANES]| {
+                        let result = Vector::<LANES>::from_array(x).total_lt(Vector::from_array(y));
+                        let mut expected = [false; LANES];
+                        for i in 0..LANES {
+                            expected[i] = x[i].total_cmp(&y[i]).is_lt();
+                        }
+                        test_helpers::prop_assert_biteq!(result.to_array(), expected);
+                        Ok(())
+                    });
+                }
+
+                fn total_gt<const LANES: usize>() {
+                    test_helpers::test_2(&|x: [Scalar; LANES], y: [Scalar; LANES]| {
+                        let result = Vector::<LANES>::from_array(x).total_gt(Vector::from_array(y));
+                        let mut expected = [false; LANES];
+                        for i in 0..LANES {
+                            expected[i] = x[i].total_cmp(&y[i]).is_gt();
+                        }
+                        test_helpers::prop_assert_biteq!(result.to_array(), expected);
+                        Ok(())
+                    });
+                }
+
                 fn is_sign_positive<const LANES: usize>() {
                     test_helpers::test_unary_mask_elementwise(
                         &Vector::<LANES>::is_sign_positive,
@@ -416,6 +727,23 @@ fn is_subnormal<const LANES: usize>() {
                     );
                 }
 
+                fn classify<const LANES: usize>() {
+                    use core::num::FpCategory;
+                    for category in [
+                        FpCategory::Nan,
+                        FpCategory::Infinite,
+                        FpCategory::Zero,
+                        FpCategory::Subnormal,
+                        FpCategory::Normal,
+                    ] {
+                        test_helpers::test_unary_mask_elementwise(
+                            &|x: Vector<LANES>| x.classify(category),
+                            &|x: Scalar| x.classify() == category,
+                            &|_| true,
+                        );
+                    }
+                }
+
                 fn abs<const LANES: usize>() {
                     test_helpers::test_unary_elementwise(
                         &Vector::<LANES>::abs,
@@ -464,6 +792,78 @@ fn copysign<const LANES: usize>() {
                     )
                 }
 
+                fn recip_fast<const LANES: usize>() {
+                    test_helpers::test_1(&|x: [Scalar; LANES]| {
+                        let approx = Vector::<LANES>::from_array(x).recip_fast().to_array();
+                        for (x, approx) in x.iter().zip(approx.iter()) {
+                            let exact = x.recip();
+                            // Estimates may flush subnormal inputs and results to zero.
+                            if x.is_normal() && exact.is_normal() {
+                                proptest::prop_assert!(((approx - exact) / exact).abs() <= 1.5 / 4096.);
+                            }
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn rsqrt_fast<const LANES: usize>() {
+                    test_helpers::test_1(&|x: [Scalar; LANES]| {
+                        let approx = Vector::<LANES>::from_array(x).rsqrt_fast().to_array();
+                        for (x, approx) in x.iter().zip(approx.iter()) {
+                            if x.is_normal() && *x > 0. {
+                                let exact = x.sqrt().recip();
+                                proptest::prop_assert!(((approx - exact) / exact).abs() <= 1.5 / 4096.);
+                            }
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn estimates_of_zero_and_infinity<const LANES: usize>() {
+                    let mut x = [0.; LANES];
+                    for (i, x) in x.iter_mut().enumerate() {
+                        *x = [0., -0., Scalar::INFINITY, 4.][i % 4];
+                    }
+                    let recip = Vector::<LANES>::from_array(x).recip_fast().to_array();
+                    let rsqrt = Vector::<LANES>::from_array(x).rsqrt_fast().to_array();
+                    for i in 0..LANES {
+                        match i % 4 {
+                            0 => {
+                                assert_eq!(recip[i], Scalar::INFINITY);
+                                assert_eq!(rsqrt[i], Scalar::INFINITY);
+                            }
+                            1 => {
+                                assert_eq!(recip[i], Scalar::NEG_INFINITY);
+                                assert_eq!(rsqrt[i], Scalar::NEG_INFINITY);
+                            }
+                            2 => {
+                                assert_eq!(recip[i].to_bits(), (0. as Scalar).to_bits());
+                                assert_eq!(rsqrt[i].to_bits(), (0. as Scalar).to_bits());
+                            }
+                            _ => {
+                                assert!((recip[i] - 0.25).abs() <= 1.5 / 4096. * 0.25);
+                                assert!((rsqrt[i] - 0.5).abs() <= 1.5 / 4096. * 0.5);
+                            }
+                        }
+                    }
+                }
+
+                fn mul_add<const LANES: usize>() {
+                    test_helpers::test_ternary_elementwise(
+                        &Vector::<LANES>::mul_add,
+                        &Scalar::mul_add,
+                        &|_, _, _| true,
+                    )
+                }
+
+                fn relaxed_mul_add<const LANES: usize>() {
+                    test_helpers::test_ternary_elementwise(
+                        &Vector::<LANES>::relaxed_mul_add,
+                        &|a: Scalar, b: Scalar, c: Scalar| a * b + c,
+                        &|_, _, _| true,
+                    )
+                }
+
                 fn simd_min<const LANES: usize>() {
                     // Regular conditions (both values aren't zero)
                     test_helpers::test_binary_elementwise(
@@ -554,6 +954,44 @@ fn reduce_product<const LANES: usize>() {
                     });
                 }
 
+                fn reduce_sum_tree<const LANES: usize>() {
+                    test_helpers::test_1(&|x| {
+                        // Combine the upper half into the lower half until one lane is left.
+                        let mut lanes = x;
+                        let mut width = LANES;
+                        while width > 1 {
+                            width /= 2;
+                            for i in 0..width {
+                                lanes[i] = lanes[i] + lanes[i + width];
+                            }
+                        }
+                        test_helpers::prop_assert_biteq! (
+                            Vector::<LANES>::from_array(x).reduce_sum_tree(),
+                            lanes[0],
+                        );
+                        Ok(())
+                    });
+                }
+
+                fn reduce_product_tree<const LANES: usize>() {
+                    test_helpers::test_1(&|x| {
+                        // Combine the upper half into the lower half until one lane is left.
+                        let mut lanes = x;
+                        let mut width = LANES;
+                        while width > 1 {
+                            width /= 2;
+                            for i in 0..width {
+                                lanes[i] = lanes[i] * lanes[i + width];
+                            }
+                        }
+                        test_helpers::prop_assert_biteq! (
+                            Vector::<LANES>::from_array(x).reduce_product_tree(),
+                            lanes[0],
+                        );
+                        Ok(())
+                    });
+                }
+
                 fn reduce_max<const LANES: usize>() {
                     test_helpers::test_1(&|x| {
                         let vmax = Vector::<LANES>::from_array(x).reduce_max();
@@ -592,14 +1030,6 @@ fn sqrt<const LANES: usize>() {
                             &|_| true,
                         )
                     }
-
-                    fn mul_add<const LANES: usize>() {
-                        test_helpers::test_ternary_elementwise(
-                            &Vector::<LANES>::mul_add,
-                            &Scalar::mul_add,
-                            &|_, _, _| true,
-                        )
-                    }
                 }
             }
         }
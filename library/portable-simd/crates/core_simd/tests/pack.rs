@@ -0,0 +1,33 @@
+#![feature(portable_simd, generic_const_exprs, adt_const_params)]
+#![allow(incomplete_features)]
+#![cfg(feature = "generic_const_exprs")]
+
+use core_simd::Simd;
+
+#[test]
+fn saturating_pack() {
+    let a = Simd::<i16, 4>::from_array([-300, -128, 127, 300]);
+    let b = Simd::<i16, 4>::from_array([0, -1, 1, i16::MIN]);
+    assert_eq!(
+        a.saturating_pack(b).to_array(),
+        [-128, -128, 127, 127, 0, -1, 1, -128]
+    );
+    assert_eq!(
+        a.saturating_pack_unsigned(b).to_array(),
+        [0, 0, 127, 255, 0, 0, 1, 0]
+    );
+
+    let c = Simd::<u32, 2>::from_array([70000, 65535]);
+    let d = Simd::<u32, 2>::from_array([1, u32::MAX]);
+    assert_eq!(c.saturating_pack(d).to_array(), [65535, 65535, 1, 65535]);
+}
+
+#[test]
+fn truncating_pack() {
+    let a = Simd::<i16, 2>::from_array([0x1234, -1]);
+    let b = Simd::<i16, 2>::from_array([0x00ff, 0x0180]);
+    assert_eq!(a.truncating_pack(b).to_array(), [0x34, -1, -1, -128]);
+
+    let c = Simd::<u64, 2>::from_array([1 << 32 | 7, u64::MAX]);
+    assert_eq!(c.truncating_pack(c).to_array(), [7, u32::MAX, 7, u32::MAX]);
+}
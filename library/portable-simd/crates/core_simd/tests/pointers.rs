@@ -0,0 +1,124 @@
+#![feature(portable_simd)]
+
+macro_rules! common_tests {
+    { $constness:ident } => {
+        test_helpers::test_lanes! {
+            fn is_null<const LANES: usize>() {
+                test_helpers::test_1(&|addrs: [usize; LANES]| {
+                    let ptrs = addrs.map(|addr| addr as *$constness u32);
+                    let is_null = Simd::from_array(ptrs).is_null();
+                    test_helpers::prop_assert_biteq!(is_null.to_array(), ptrs.map(|p| p.is_null()));
+                    Ok(())
+                });
+            }
+
+            fn addr<const LANES: usize>() {
+                test_helpers::test_1(&|addrs: [usize; LANES]| {
+                    let ptrs = addrs.map(|addr| addr as *$constness u32);
+                    test_helpers::prop_assert_biteq!(Simd::from_array(ptrs).addr().to_array(), addrs);
+                    Ok(())
+                });
+            }
+
+            fn with_addr<const LANES: usize>() {
+                test_helpers::test_2(&|addrs: [usize; LANES], new_addrs: [usize; LANES]| {
+                    let ptrs = Simd::from_array(addrs.map(|addr| addr as *$constness u32));
+                    let moved = ptrs.with_addr(Simd::from_array(new_addrs));
+                    test_helpers::prop_assert_biteq!(moved.addr().to_array(), new_addrs);
+                    Ok(())
+                });
+            }
+
+            fn cast_ptr<const LANES: usize>() {
+                test_helpers::test_1(&|addrs: [usize; LANES]| {
+                    let ptrs = addrs.map(|addr| addr as *$constness u32);
+                    let cast = Simd::from_array(ptrs).cast_ptr::<u8>();
+                    test_helpers::prop_assert_biteq!(cast.addr().to_array(), addrs);
+                    Ok(())
+                });
+            }
+
+            fn expose_addr<const LANES: usize>() {
+                test_helpers::test_1(&|addrs: [usize; LANES]| {
+                    let ptrs = Simd::from_array(addrs.map(|addr| addr as *$constness u32));
+                    let exposed = ptrs.expose_addr();
+                    test_helpers::prop_assert_biteq!(exposed.to_array(), addrs);
+                    let from_exposed = Simd::<*$constness u32, LANES>::from_exposed_addr(exposed);
+                    test_helpers::prop_assert_biteq!(from_exposed.addr().to_array(), addrs);
+                    Ok(())
+                });
+            }
+
+            fn wrapping_offset<const LANES: usize>() {
+                test_helpers::test_2(&|addrs: [usize; LANES], offsets: [isize; LANES]| {
+                    let ptrs = addrs.map(|addr| addr as *$constness u32);
+                    let moved = Simd::from_array(ptrs).wrapping_offset(Simd::from_array(offsets));
+                    let mut expected = [0; LANES];
+                    for i in 0..LANES {
+                        expected[i] = ptrs[i].wrapping_offset(offsets[i]) as usize;
+                    }
+                    test_helpers::prop_assert_biteq!(moved.addr().to_array(), expected);
+                    Ok(())
+                });
+            }
+
+            fn wrapping_add<const LANES: usize>() {
+                test_helpers::test_2(&|addrs: [usize; LANES], counts: [usize; LANES]| {
+                    let ptrs = addrs.map(|addr| addr as *$constness u32);
+                    let moved = Simd::from_array(ptrs).wrapping_add(Simd::from_array(counts));
+                    let mut expected = [0; LANES];
+                    for i in 0..LANES {
+                        expected[i] = ptrs[i].wrapping_add(counts[i]) as usize;
+                    }
+                    test_helpers::prop_assert_biteq!(moved.addr().to_array(), expected);
+                    Ok(())
+                });
+            }
+
+            fn wrapping_sub<const LANES: usize>() {
+                test_helpers::test_2(&|addrs: [usize; LANES], counts: [usize; LANES]| {
+                    let ptrs = addrs.map(|addr| addr as *$constness u32);
+                    let moved = Simd::from_array(ptrs).wrapping_sub(Simd::from_array(counts));
+                    let mut expected = [0; LANES];
+                    for i in 0..LANES {
+                        expected[i] = ptrs[i].wrapping_sub(counts[i]) as usize;
+                    }
+                    test_helpers::prop_assert_biteq!(moved.addr().to_array(), expected);
+                    Ok(())
+                });
+            }
+        }
+    }
+}
+
+mod const_ptr {
+    use core_simd::{Simd, SimdConstPtr};
+    common_tests! { const }
+}
+
+mod mut_ptr {
+    use core_simd::{Simd, SimdMutPtr};
+    common_tests! { mut }
+}
+
+mod gather_scatter {
+    use core_simd::{Mask, Simd, SimdConstPtr, SimdMutPtr};
+
+    #[test]
+    fn gather_select_ptr() {
+        let values = [10u32, 11, 12, 13];
+        let ptrs = Simd::splat(values.as_ptr()).wrapping_add(Simd::from_array([3, 0, 2, 1]));
+        let enable = Mask::from_array([true, false, true, true]);
+        let gathered = unsafe { Simd::gather_select_ptr(ptrs, enable, Simd::splat(0)) };
+        assert_eq!(gathered.to_array(), [13, 0, 12, 11]);
+    }
+
+    #[test]
+    fn scatter_select_ptr() {
+        let mut values = [0u32; 4];
+        let ptrs = Simd::splat(values.as_mut_ptr()).wrapping_add(Simd::from_array([3, 0, 2, 1]));
+        let enable = Mask::from_array([true, false, true, true]);
+        unsafe { Simd::from_array([1, 2, 3, 4]).scatter_select_ptr(ptrs, enable) };
+        assert_eq!(values, [0, 4, 3, 1]);
+    }
+}
@@ -3,7 +3,7 @@
 macro_rules! float_rounding_test {
     { $scalar:tt, $int_scalar:tt } => {
         mod $scalar {
-            use std_float::StdFloat;
+            use core_simd::SimdFloat;
 
             type Vector<const LANES: usize> = core_simd::Simd<$scalar, LANES>;
             type Scalar = $scalar;
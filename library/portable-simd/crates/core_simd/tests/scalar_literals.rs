@@ -0,0 +1,41 @@
+// Test that integer and float literals infer as the lane type when used with vectors.
+#![feature(portable_simd)]
+use core_simd::{f32x4, i32x4, u8x16};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn binary() {
+    let x = i32x4::from_array([1, 2, 3, 4]);
+    assert_eq!(x + 1, i32x4::from_array([2, 3, 4, 5]));
+    assert_eq!(10 - x, i32x4::from_array([9, 8, 7, 6]));
+    assert_eq!(x << 2, i32x4::from_array([4, 8, 12, 16]));
+    assert_eq!(f32x4::splat(1.5) * 2.0, f32x4::splat(3.0));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn assign() {
+    let mut x = i32x4::from_array([1, 2, 3, 4]);
+    x += 1;
+    x *= 2;
+    x <<= 3;
+    x >>= 1;
+    x &= 0xff;
+    assert_eq!(x, i32x4::from_array([16, 24, 32, 40]));
+
+    let mut bytes = u8x16::splat(0x0f);
+    bytes <<= 4;
+    bytes |= 1;
+    assert_eq!(bytes, u8x16::splat(0xf1));
+
+    let mut y = f32x4::splat(1.0);
+    y += 0.5;
+    y /= 2.0;
+    assert_eq!(y, f32x4::splat(0.75));
+}
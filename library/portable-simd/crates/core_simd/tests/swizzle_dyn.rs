@@ -0,0 +1,41 @@
+#![feature(portable_simd)]
+use core_simd::Simd;
+
+fn swizzle_dyn_scalar_ver<const N: usize>(values: [u8; N], idxs: [u8; N]) -> [u8; N] {
+    let mut array = [0; N];
+    for (i, k) in idxs.into_iter().enumerate() {
+        if (k as usize) < N {
+            array[i] = values[k as usize];
+        };
+    }
+    array
+}
+
+test_helpers::test_lanes! {
+    fn swizzle_dyn<const N: usize>() {
+        test_helpers::test_2(&|values: [u8; N], idxs: [u8; N]| {
+            test_helpers::prop_assert_biteq!(
+                Simd::from_array(values).swizzle_dyn(Simd::from_array(idxs)).to_array(),
+                swizzle_dyn_scalar_ver(values, idxs),
+            );
+            Ok(())
+        });
+    }
+
+    fn swizzle_dyn_in_bounds<const N: usize>() {
+        // Random indices are mostly out of bounds for small vectors, so also check a reversal.
+        test_helpers::test_1(&|values: [u8; N]| {
+            let mut idxs = [0; N];
+            for (i, idx) in idxs.iter_mut().enumerate() {
+                *idx = (N - 1 - i) as u8;
+            }
+            let mut reversed = values;
+            reversed.reverse();
+            test_helpers::prop_assert_biteq!(
+                Simd::from_array(values).swizzle_dyn(Simd::from_array(idxs)).to_array(),
+                reversed,
+            );
+            Ok(())
+        });
+    }
+}
@@ -0,0 +1,21 @@
+#![feature(portable_simd)]
+use core_simd::Simd;
+
+test_helpers::test_lanes! {
+    fn read_write_unaligned<const N: usize>() {
+        test_helpers::test_1(&|values: [u32; N]| {
+            // Every offset into a byte buffer, so most accesses are misaligned.
+            for offset in 0..4 {
+                let mut buffer = [0u8; 256 + 4];
+                let ptr = buffer[offset..].as_mut_ptr().cast::<u32>();
+                // Safety: the buffer has room for `N` `u32`s from `offset`
+                let read = unsafe {
+                    Simd::from_array(values).write_unaligned(ptr);
+                    Simd::<u32, N>::read_unaligned(ptr)
+                };
+                test_helpers::prop_assert_biteq!(read.to_array(), values);
+            }
+            Ok(())
+        });
+    }
+}
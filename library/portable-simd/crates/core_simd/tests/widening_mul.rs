@@ -0,0 +1,39 @@
+#![feature(portable_simd)]
+
+macro_rules! widening_mul_tests {
+    { $($scalar:tt => $low:ty, $wide:ty;)* } => {
+        $(
+        mod $scalar {
+            use core_simd::Simd;
+            type Scalar = $scalar;
+
+            test_helpers::test_lanes! {
+                fn widening_mul<const LANES: usize>() {
+                    test_helpers::test_2(&|x: [Scalar; LANES], y: [Scalar; LANES]| {
+                        let (low, high) = Simd::from_array(x).widening_mul(Simd::from_array(y));
+                        for i in 0..LANES {
+                            let product = x[i] as $wide * y[i] as $wide;
+                            test_helpers::prop_assert_biteq!(low[i], product as $low);
+                            test_helpers::prop_assert_biteq!(high[i], (product >> Scalar::BITS) as Scalar);
+                        }
+                        Ok(())
+                    });
+                }
+            }
+        }
+        )*
+    }
+}
+
+widening_mul_tests! {
+    u8 => u8, u16;
+    u16 => u16, u32;
+    u32 => u32, u64;
+    u64 => u64, u128;
+    usize => usize, u128;
+    i8 => u8, i16;
+    i16 => u16, i32;
+    i32 => u32, i64;
+    i64 => u64, i128;
+    isize => usize, i128;
+}
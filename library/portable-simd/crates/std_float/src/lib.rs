@@ -1,11 +1,12 @@
 #![cfg_attr(feature = "as_crate", no_std)] // We are std!
 #![cfg_attr(feature = "as_crate", feature(platform_intrinsics), feature(portable_simd))]
+#![cfg_attr(feature = "as_crate", feature(decl_macro))]
 #[cfg(not(feature = "as_crate"))]
 use core::simd;
 #[cfg(feature = "as_crate")]
 use core_simd::simd;
 
-use simd::{LaneCount, Simd, SupportedLaneCount};
+use simd::{LaneCount, Simd, SimdFloat, SupportedLaneCount};
 
 #[cfg(feature = "as_crate")]
 mod experimental {
@@ -15,28 +16,42 @@ pub trait Sealed {}
 #[cfg(feature = "as_crate")]
 use experimental as sealed;
 
+// `simd_multiversion` detects CPU features through `$crate::arch`, which is `std::arch` when this
+// is part of `std`. As a crate, that needs the `std` feature.
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use std::arch;
+
 use crate::sealed::Sealed;
 
 // "platform intrinsics" are essentially "codegen intrinsics"
 // each of these may be scalarized and lowered to a libm call
 extern "platform-intrinsic" {
-    // ceil
-    fn simd_ceil<T>(x: T) -> T;
+    // fsqrt
+    fn simd_fsqrt<T>(x: T) -> T;
 
-    // floor
-    fn simd_floor<T>(x: T) -> T;
+    // log
+    fn simd_flog<T>(x: T) -> T;
 
-    // round
-    fn simd_round<T>(x: T) -> T;
+    // log2
+    fn simd_flog2<T>(x: T) -> T;
 
-    // trunc
-    fn simd_trunc<T>(x: T) -> T;
+    // log10
+    fn simd_flog10<T>(x: T) -> T;
 
-    // fsqrt
-    fn simd_fsqrt<T>(x: T) -> T;
+    // exp
+    fn simd_fexp<T>(x: T) -> T;
+
+    // exp2
+    fn simd_fexp2<T>(x: T) -> T;
+
+    // sin
+    fn simd_fsin<T>(x: T) -> T;
 
-    // fma
-    fn simd_fma<T>(x: T, y: T, z: T) -> T;
+    // cos
+    fn simd_fcos<T>(x: T) -> T;
 }
 
 /// This trait provides a possibly-temporary implementation of float functions
@@ -57,60 +72,176 @@ pub trait Sealed {}
 /// It is possible this trait will be replaced in some manner in the future,
 /// when either the compiler or its supporting runtime functions are improved.
 /// For now this trait is available to permit experimentation with SIMD float
-/// operations that may lack hardware support, such as `mul_add`.
+/// operations that may lack hardware support, such as `sqrt`.
 pub trait StdFloat: Sealed + Sized {
-    /// Fused multiply-add.  Computes `(self * a) + b` with only one rounding error,
-    /// yielding a more accurate result than an unfused multiply-add.
+    /// Produces a vector where every lane has the square root value
+    /// of the equivalently-indexed lane in `self`
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn sqrt(self) -> Self {
+        unsafe { simd_fsqrt(self) }
+    }
+
+    /// Produces a vector where every lane has the natural logarithm of the
+    /// equivalently-indexed lane in `self`.
     ///
-    /// Using `mul_add` *may* be more performant than an unfused multiply-add if the target
-    /// architecture has a dedicated `fma` CPU instruction.  However, this is not always
-    /// true, and will be heavily dependent on designing algorithms with specific target
-    /// hardware in mind.
+    /// Like the scalar `ln`, the accuracy depends on the target's math
+    /// library, which computes each lane if the target has no vector instruction.
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original value"]
-    fn mul_add(self, a: Self, b: Self) -> Self {
-        unsafe { simd_fma(self, a, b) }
+    fn ln(self) -> Self {
+        unsafe { simd_flog(self) }
     }
 
-    /// Produces a vector where every lane has the square root value
-    /// of the equivalently-indexed lane in `self`
+    /// Produces a vector where every lane has the base 2 logarithm of the
+    /// equivalently-indexed lane in `self`.
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original value"]
-    fn sqrt(self) -> Self {
-        unsafe { simd_fsqrt(self) }
+    fn log2(self) -> Self {
+        unsafe { simd_flog2(self) }
     }
 
-    /// Returns the smallest integer greater than or equal to each lane.
+    /// Produces a vector where every lane has the base 10 logarithm of the
+    /// equivalently-indexed lane in `self`.
+    #[inline]
     #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn log10(self) -> Self {
+        unsafe { simd_flog10(self) }
+    }
+
+    /// Produces a vector where every lane has `e` raised to the power of the
+    /// equivalently-indexed lane in `self`.
     #[inline]
-    fn ceil(self) -> Self {
-        unsafe { simd_ceil(self) }
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn exp(self) -> Self {
+        unsafe { simd_fexp(self) }
     }
 
-    /// Returns the largest integer value less than or equal to each lane.
+    /// Produces a vector where every lane has 2 raised to the power of the
+    /// equivalently-indexed lane in `self`.
+    #[inline]
     #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn exp2(self) -> Self {
+        unsafe { simd_fexp2(self) }
+    }
+
+    /// Produces a vector where every lane has the sine of the
+    /// equivalently-indexed lane in `self`, in radians.
+    ///
+    /// Like the scalar `sin`, the accuracy depends on the target's math
+    /// library, which computes each lane if the target has no vector instruction.
     #[inline]
-    fn floor(self) -> Self {
-        unsafe { simd_floor(self) }
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn sin(self) -> Self {
+        unsafe { simd_fsin(self) }
     }
 
-    /// Rounds to the nearest integer value. Ties round toward zero.
+    /// Produces a vector where every lane has the cosine of the
+    /// equivalently-indexed lane in `self`, in radians.
+    ///
+    /// The accuracy is the same as for [`sin`](Self::sin).
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn cos(self) -> Self {
+        unsafe { simd_fcos(self) }
+    }
+
+    /// Produces a vector where every lane has the tangent of the
+    /// equivalently-indexed lane in `self`, in radians.
+    ///
+    /// This is computed as `sin / cos`, so it has up to one more rounding
+    /// error than the scalar `tan`, and its relative error grows near the
+    /// poles at odd multiples of `π / 2`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn tan(self) -> Self;
+
+    /// Produces the sine and cosine of every lane of `self`, in radians,
+    /// as a vector of sines and a vector of cosines.
+    ///
+    /// The accuracy is the same as for [`sin`](Self::sin) and [`cos`](Self::cos).
+    #[inline]
     #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    /// Rounds to the nearest integer value. Ties round away from zero.
+    ///
+    /// Forwards to [`SimdFloat::round`], which should be used instead. This method will be
+    /// removed once code had a release cycle to import `SimdFloat`.
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn round(&self) -> Self
+    where
+        Self: SimdFloat + Copy,
+    {
+        SimdFloat::round(*self)
+    }
+
+    /// Returns the largest integer value less than or equal to each lane.
+    ///
+    /// Forwards to [`SimdFloat::floor`], which should be used instead. This method will be
+    /// removed once code had a release cycle to import `SimdFloat`.
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn floor(&self) -> Self
+    where
+        Self: SimdFloat + Copy,
+    {
+        SimdFloat::floor(*self)
+    }
+
+    /// Returns the smallest integer value greater than or equal to each lane.
+    ///
+    /// Forwards to [`SimdFloat::ceil`], which should be used instead. This method will be
+    /// removed once code had a release cycle to import `SimdFloat`.
     #[inline]
-    fn round(self) -> Self {
-        unsafe { simd_round(self) }
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn ceil(&self) -> Self
+    where
+        Self: SimdFloat + Copy,
+    {
+        SimdFloat::ceil(*self)
     }
 
-    /// Returns the floating point's integer value, with its fractional part removed.
+    /// Returns the integer part of each lane, rounding toward zero.
+    ///
+    /// Forwards to [`SimdFloat::trunc`], which should be used instead. This method will be
+    /// removed once code had a release cycle to import `SimdFloat`.
+    #[inline]
     #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn trunc(&self) -> Self
+    where
+        Self: SimdFloat + Copy,
+    {
+        SimdFloat::trunc(*self)
+    }
+
+    /// Returns the fractional part of each lane, `self - self.trunc()`.
+    ///
+    /// Forwards to [`SimdFloat::fract`], which should be used instead. This method will be
+    /// removed once code had a release cycle to import `SimdFloat`.
     #[inline]
-    fn trunc(self) -> Self {
-        unsafe { simd_trunc(self) }
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn fract(&self) -> Self
+    where
+        Self: SimdFloat + Copy,
+    {
+        SimdFloat::fract(*self)
     }
 
-    /// Returns the floating point's fractional value, with its integer part removed.
+    /// Fused multiply-add. Computes `(self * a) + b` with only one rounding error.
+    ///
+    /// Forwards to [`SimdFloat::mul_add`], which should be used instead. This method will be
+    /// removed once code had a release cycle to import `SimdFloat`.
+    #[inline]
     #[must_use = "method returns a new vector and does not mutate the original value"]
-    fn fract(self) -> Self;
+    fn mul_add(&self, a: Self, b: Self) -> Self
+    where
+        Self: SimdFloat + Copy,
+    {
+        SimdFloat::mul_add(*self, a, b)
+    }
 }
 
 impl<const N: usize> Sealed for Simd<f32, N> where LaneCount<N>: SupportedLaneCount {}
@@ -121,11 +252,10 @@ impl<const N: usize> StdFloat for Simd<f32, N>
 where
     LaneCount<N>: SupportedLaneCount,
 {
-    /// Returns the floating point's fractional value, with its integer part removed.
-    #[must_use = "method returns a new vector and does not mutate the original value"]
     #[inline]
-    fn fract(self) -> Self {
-        self - self.trunc()
+    fn tan(self) -> Self {
+        let (sin, cos) = self.sin_cos();
+        sin / cos
     }
 }
 
@@ -133,14 +263,141 @@ impl<const N: usize> StdFloat for Simd<f64, N>
 where
     LaneCount<N>: SupportedLaneCount,
 {
-    /// Returns the floating point's fractional value, with its integer part removed.
-    #[must_use = "method returns a new vector and does not mutate the original value"]
     #[inline]
-    fn fract(self) -> Self {
-        self - self.trunc()
+    fn tan(self) -> Self {
+        let (sin, cos) = self.sin_cos();
+        sin / cos
     }
 }
 
+/// Defines a function that is compiled once for each of several sets of CPU features, and
+/// picks the best version for the running CPU each time it is called.
+///
+/// Each entry in `targets` is a list of features, named as in `#[target_feature(enable)]`.
+/// The entries are tried in order, so the most capable set should come first. The version for
+/// the first entry whose features are all detected at runtime is called, and if there is none,
+/// the function body runs as compiled for the baseline target. In each version the body is
+/// compiled with that entry's features, so for example [`Simd`] operations on 8 `f32`s can use
+/// AVX registers.
+///
+/// Detection is only done on x86 and x86-64, with [`is_x86_feature_detected`]; on other targets
+/// only the baseline version is compiled. When this is built as a crate, detection also needs the
+/// `std` feature, without it only the baseline version is compiled as well.
+///
+/// The function cannot be generic, and its arguments have to be plain names, optionally `mut`,
+/// as the versions pass them on to each other.
+///
+/// [`is_x86_feature_detected`]: ../arch/macro.is_x86_feature_detected.html
+///
+/// # Examples
+///
+/// ```
+/// #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use std_float::simd_multiversion;
+/// # #[cfg(not(feature = "as_crate"))] use std::simd::simd_multiversion;
+/// use std::simd::{f32x8, SimdFloat};
+///
+/// simd_multiversion! {
+///     targets = [["avx2", "fma"], ["avx"]];
+///
+///     /// Sums the elements of `x`.
+///     fn sum(x: &[f32]) -> f32 {
+///         let (prefix, middle, suffix) = x.as_simd::<8>();
+///         let sums = middle.iter().fold(f32x8::splat(0.), |acc, v| acc + v);
+///         sums.reduce_sum() + prefix.iter().sum::<f32>() + suffix.iter().sum::<f32>()
+///     }
+/// }
+///
+/// let numbers: Vec<f32> = (1..=100).map(|x| x as f32).collect();
+/// assert_eq!(sum(&numbers), 5050.);
+/// ```
+pub macro simd_multiversion {
+    (
+        targets = [$([$($feature:tt),+ $(,)?]),+ $(,)?];
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident($($args:tt)*) $(-> $ret:ty)? $body:block
+    ) => {
+        simd_multiversion!(
+            @args [$([$($feature),+])+] [$(#[$attr])* $vis fn $name] [$(-> $ret)?] $body []
+            $($args)*
+        );
+    },
+    // Turn each argument into `[mut] name [Type]` or `[] name [Type]`.
+    (
+        @args $targets:tt $head:tt $ret:tt $body:tt [$($done:tt)*]
+        mut $arg:ident: $ty:ty $(, $($rest:tt)*)?
+    ) => {
+        simd_multiversion!(
+            @args $targets $head $ret $body [$($done)* [mut] $arg [$ty]] $($($rest)*)?
+        );
+    },
+    (
+        @args $targets:tt $head:tt $ret:tt $body:tt [$($done:tt)*]
+        $arg:ident: $ty:ty $(, $($rest:tt)*)?
+    ) => {
+        simd_multiversion!(@args $targets $head $ret $body [$($done)* [] $arg [$ty]] $($($rest)*)?);
+    },
+    (@args $targets:tt $head:tt $ret:tt $body:tt [$($done:tt)*]) => {
+        simd_multiversion!(@function $targets $head $ret $body $($done)*);
+    },
+    (@args $($_:tt)*) => {
+        compile_error!(
+            "the arguments of a `simd_multiversion!` function must be of the form `name: Type` or \
+            `mut name: Type`"
+        );
+    },
+    (
+        @function $targets:tt [$($head:tt)*] [$($ret:tt)*] $body:block
+        $([$($mut:tt)?] $arg:ident [$ty:ty])*
+    ) => {
+        $($head)*($($arg: $ty),*) $($ret)* {
+            #[inline(always)]
+            fn baseline($($($mut)? $arg: $ty),*) $($ret)* $body
+
+            simd_multiversion!(@dispatch $targets baseline($($arg: $ty),*) $($ret)*);
+            baseline($($arg),*)
+        }
+    },
+    (
+        @dispatch [[$($feature:tt),+] $($rest:tt)*]
+        $baseline:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)?
+    ) => {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        if features_detected!($($feature),+) {
+            // `baseline` is always inlined, so its body is compiled with these features
+            $(#[target_feature(enable = $feature)])+
+            unsafe fn version($($arg: $ty),*) $(-> $ret)? {
+                $baseline($($arg),*)
+            }
+            // Safety: all of the features `version` is compiled for were detected
+            return unsafe { version($($arg),*) };
+        }
+        simd_multiversion!(@dispatch [$($rest)*] $baseline($($arg: $ty),*) $(-> $ret)?);
+    },
+    (@dispatch [] $($_:tt)*) => {},
+}
+
+/// Whether all of the given CPU features are available, for `simd_multiversion`.
+#[allow(unused_macros)] // only used by the expansions of `simd_multiversion`
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    any(feature = "std", not(feature = "as_crate"))
+))]
+macro features_detected($($feature:tt),+) {
+    true $(&& $crate::arch::is_x86_feature_detected!($feature))+
+}
+
+/// Without `std` there is no way to detect CPU features, so only the baseline version is used.
+#[allow(unused_macros)]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "as_crate",
+    not(feature = "std")
+))]
+macro features_detected($($feature:tt),+) {
+    false
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -158,4 +415,77 @@ fn everything_works() {
         let _xsqrt = x.sqrt();
         let _ = x2.abs() * x2;
     }
+
+    #[test]
+    fn exp_log() {
+        use core::f64::consts::{LN_10, LN_2, LOG10_2, LOG2_10};
+        let x = f64x4::from_array([1., 2., 8., 1000.]);
+        let close = |a: f64x4, b: [f64; 4]| (a - f64x4::from_array(b)).abs().reduce_max() < 1e-12;
+        assert!(close(x.ln(), [0., LN_2, 3. * LN_2, 3. * LN_10]));
+        assert!(close(x.log2(), [0., 1., 3., 3. * LOG2_10]));
+        assert!(close(x.log10(), [0., LOG10_2, 3. * LOG10_2, 3.]));
+        assert!(close(x.ln().exp(), x.to_array()));
+        assert!(close(x.log2().exp2(), x.to_array()));
+        assert!(close(f64x4::splat(0.).exp(), [1.; 4]));
+    }
+
+    simd_multiversion! {
+        targets = [["avx2", "fma"], ["sse4.1"]];
+
+        fn dot(x: f32x4, y: f32x4) -> f32 {
+            x.mul_add(y, f32x4::splat(0.)).reduce_sum()
+        }
+    }
+
+    simd_multiversion! {
+        targets = [["avx"]];
+
+        fn sum(mut acc: f32x4, xs: &[f32x4],) -> f32 {
+            for x in xs {
+                acc += x;
+            }
+            acc.reduce_sum()
+        }
+    }
+
+    // Without the `std` feature, this only tests the baseline versions, as nothing is detected.
+    #[test]
+    fn multiversion() {
+        let x = f32x4::from_array([1., 2., 3., 4.]);
+        assert_eq!(dot(x, x), 30.);
+        assert_eq!(sum(x, &[x, x]), 30.);
+    }
+
+    #[test]
+    fn trig() {
+        use core::f64::consts::{FRAC_1_SQRT_2, FRAC_PI_2, FRAC_PI_4, PI};
+        let x = f64x4::from_array([0., FRAC_PI_4, FRAC_PI_2, PI]);
+        let close = |a: f64x4, b: [f64; 4]| (a - f64x4::from_array(b)).abs().reduce_max() < 1e-12;
+        let (sin, cos) = x.sin_cos();
+        assert!(close(sin, [0., FRAC_1_SQRT_2, 1., 0.]));
+        assert!(close(cos, [1., FRAC_1_SQRT_2, 0., -1.]));
+        assert!(close(x.sin(), sin.to_array()));
+        assert!(close(x.cos(), cos.to_array()));
+        let y = f64x4::from_array([0., FRAC_PI_4, -FRAC_PI_4, PI]);
+        assert!(close(y.tan(), [0., 1., -1., 0.]));
+    }
+}
+
+#[cfg(test)]
+mod forwarding_tests {
+    // Code that only imports `StdFloat` still finds the methods that moved to `SimdFloat`.
+    use super::StdFloat;
+    use super::simd::f32x4;
+
+    #[test]
+    fn forwarding_methods() {
+        let x = f32x4::from_array([0.1, 0.5, 1.6, -1.5]);
+        assert_eq!(x.round().to_array(), [0., 1., 2., -2.]);
+        assert_eq!(x.floor().to_array(), [0., 0., 1., -2.]);
+        assert_eq!(x.ceil().to_array(), [1., 1., 2., -1.]);
+        assert_eq!(x.trunc().to_array(), [0., 0., 1., -1.]);
+        let y = f32x4::from_array([1., 2., 3., 4.]);
+        assert_eq!(y.fract().to_array(), [0.; 4]);
+        assert_eq!(y.mul_add(y, y).to_array(), [2., 6., 12., 20.]);
+    }
 }
@@ -544,6 +544,8 @@
 #[doc = include_str!("../../portable-simd/crates/core_simd/src/core_simd_docs.md")]
 #[unstable(feature = "portable_simd", issue = "86656")]
 pub mod simd {
+    #[doc(inline)]
+    pub use crate::std_float::simd_multiversion;
     #[doc(inline)]
     pub use crate::std_float::StdFloat;
     #[doc(inline)]
@@ -0,0 +1,39 @@
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+
+#![feature(repr_simd, platform_intrinsics)]
+#![allow(non_camel_case_types)]
+
+#[repr(simd)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Vec2<T>(pub T, pub T);
+
+#[repr(simd)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Vec4<T>(pub T, pub T, pub T, pub T);
+
+extern "platform-intrinsic" {
+    fn simd_masked_store<M, P, T>(mask: M, pointer: P, values: T) -> ();
+}
+
+// CHECK-LABEL: @store_f32x2
+#[no_mangle]
+pub unsafe fn store_f32x2(mask: Vec2<i32>, pointer: *mut f32, values: Vec2<f32>) {
+    // CHECK: call void @llvm.masked.store.v2f32.{{.+}}(<2 x float> {{.*}}, {{<2 x float>\*|ptr}} {{.*}}, i32 4, <2 x i1> {{.*}})
+    simd_masked_store(mask, pointer, values)
+}
+
+// CHECK-LABEL: @store_f64x4
+#[no_mangle]
+pub unsafe fn store_f64x4(mask: Vec4<i64>, pointer: *mut f64, values: Vec4<f64>) {
+    // CHECK: call void @llvm.masked.store.v4f64.{{.+}}(<4 x double> {{.*}}, {{<4 x double>\*|ptr}} {{.*}}, i32 8, <4 x i1> {{.*}})
+    simd_masked_store(mask, pointer, values)
+}
+
+// CHECK-LABEL: @store_u8x4
+#[no_mangle]
+pub unsafe fn store_u8x4(mask: Vec4<i8>, pointer: *mut u8, values: Vec4<u8>) {
+    // CHECK: call void @llvm.masked.store.v4i8.{{.+}}(<4 x i8> {{.*}}, {{<4 x i8>\*|ptr}} {{.*}}, i32 1, <4 x i1> {{.*}})
+    simd_masked_store(mask, pointer, values)
+}
@@ -0,0 +1,210 @@
+#![feature(never_type, rustc_private)]
+
+//! This program implements a rustc driver that evaluates every function of the crate it compiles
+//! with a custom `Machine`. The machine records the `before_intrinsic` and `after_intrinsic` hooks
+//! it sees, and the driver prints them together with the result of each function.
+//!
+//! Each function is evaluated twice: after the first run, the driver restores a snapshot of the
+//! interpreter taken before it and checks that the stack, memory and machine state are back.
+
+#[macro_use]
+extern crate rustc_middle;
+
+extern crate rustc_const_eval;
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_interface;
+extern crate rustc_span;
+extern crate rustc_target;
+
+use rustc_const_eval::interpret::{
+    compile_time_machine, Frame, ImmTy, InterpCx, InterpResult, MemoryKind, OpTy, PlaceTy,
+    Pointer, Scalar, StackPopCleanup, StackPopUnwind,
+};
+use rustc_driver::Compilation;
+use rustc_hir::def::DefKind;
+use rustc_interface::interface::Compiler;
+use rustc_interface::Queries;
+use rustc_middle::mir;
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_middle::ty::{self, Ty, TyCtxt};
+use rustc_span::def_id::DefId;
+use rustc_span::Symbol;
+use rustc_target::spec::abi::Abi as CallAbi;
+
+fn main() {
+    let exit_code = rustc_driver::catch_with_exit_code(move || {
+        let rustc_args: Vec<_> = std::env::args().collect();
+        rustc_driver::RunCompiler::new(&rustc_args, &mut CompilerCalls).run()
+    });
+    std::process::exit(exit_code);
+}
+
+struct CompilerCalls;
+
+impl rustc_driver::Callbacks for CompilerCalls {
+    fn after_analysis<'tcx>(
+        &mut self,
+        compiler: &Compiler,
+        queries: &'tcx Queries<'tcx>,
+    ) -> Compilation {
+        compiler.session().abort_if_errors();
+        queries.global_ctxt().unwrap().peek_mut().enter(|tcx| {
+            let mut fns: Vec<_> = tcx
+                .hir_crate_items(())
+                .items()
+                .map(|id| id.def_id)
+                .filter(|&def_id| matches!(tcx.def_kind(def_id), DefKind::Fn))
+                .collect();
+            fns.sort();
+            for def_id in fns {
+                let def_id = def_id.to_def_id();
+                let mut ecx = InterpCx::new(
+                    tcx,
+                    tcx.def_span(def_id),
+                    ty::ParamEnv::reveal_all(),
+                    TestMachine::default(),
+                );
+                match eval_fn(&mut ecx, def_id) {
+                    Ok(result) => println!("{}: {}", tcx.item_name(def_id), result),
+                    Err(_) => println!("{}: error", tcx.item_name(def_id)),
+                }
+                for event in &ecx.machine.events {
+                    println!("  {}", event);
+                }
+            }
+        });
+
+        Compilation::Stop
+    }
+}
+
+/// Evaluates a function that takes no arguments and returns a `u32`, twice.
+fn eval_fn<'mir, 'tcx>(
+    ecx: &mut InterpCx<'mir, 'tcx, TestMachine<'mir, 'tcx>>,
+    def_id: DefId,
+) -> InterpResult<'tcx, u32> {
+    let instance = ty::Instance::mono(*ecx.tcx, def_id);
+    let body = ecx.load_mir(instance.def, None)?;
+    let layout = ecx.layout_of(body.return_ty())?;
+    let ret = ecx.allocate(layout, MemoryKind::Stack)?;
+    ecx.write_scalar(Scalar::from_u32(0), &ret.into())?;
+    ecx.push_stack_frame(instance, body, &ret.into(), StackPopCleanup::Root { cleanup: false })?;
+
+    let snapshot = ecx.snapshot();
+    ecx.run()?;
+    let result = ecx.read_scalar(&ret.into())?.to_u32()?;
+    assert!(ecx.machine.stack.is_empty());
+
+    ecx.restore(&snapshot);
+    assert_eq!(ecx.machine.stack.len(), 1);
+    assert!(ecx.machine.events.is_empty());
+    assert_eq!(ecx.read_scalar(&ret.into())?.to_u32()?, 0);
+
+    ecx.run()?;
+    assert_eq!(ecx.read_scalar(&ret.into())?.to_u32()?, result);
+    Ok(result)
+}
+
+#[derive(Clone, Default)]
+struct TestMachine<'mir, 'tcx> {
+    stack: Vec<Frame<'mir, 'tcx>>,
+    events: Vec<String>,
+}
+
+impl<'mir, 'tcx> rustc_const_eval::interpret::Machine<'mir, 'tcx> for TestMachine<'mir, 'tcx> {
+    compile_time_machine!(<'mir, 'tcx>);
+
+    type MemoryKind = !;
+
+    const PANIC_ON_ALLOC_FAIL: bool = false;
+
+    fn enforce_alignment(_ecx: &InterpCx<'mir, 'tcx, Self>) -> bool {
+        true
+    }
+
+    fn enforce_validity(_ecx: &InterpCx<'mir, 'tcx, Self>) -> bool {
+        false
+    }
+
+    fn find_mir_or_eval_fn(
+        ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        instance: ty::Instance<'tcx>,
+        _abi: CallAbi,
+        _args: &[OpTy<'tcx>],
+        _destination: &PlaceTy<'tcx>,
+        _target: Option<mir::BasicBlock>,
+        _unwind: StackPopUnwind,
+    ) -> InterpResult<'tcx, Option<(&'mir mir::Body<'tcx>, ty::Instance<'tcx>)>> {
+        Ok(Some((ecx.load_mir(instance.def, None)?, instance)))
+    }
+
+    fn call_intrinsic(
+        ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        instance: ty::Instance<'tcx>,
+        args: &[OpTy<'tcx>],
+        destination: &PlaceTy<'tcx>,
+        target: Option<mir::BasicBlock>,
+        _unwind: StackPopUnwind,
+    ) -> InterpResult<'tcx> {
+        if ecx.emulate_intrinsic(instance, args, destination, target)? {
+            return Ok(());
+        }
+        Err(err_unsup_format!("intrinsic `{}` is not supported", instance).into())
+    }
+
+    fn before_intrinsic(
+        ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        intrinsic_name: Symbol,
+        _args: &[OpTy<'tcx>],
+    ) -> InterpResult<'tcx> {
+        ecx.machine.events.push(format!("before {}", intrinsic_name));
+        Ok(())
+    }
+
+    fn after_intrinsic(
+        ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        intrinsic_name: Symbol,
+        _args: &[OpTy<'tcx>],
+        _destination: &PlaceTy<'tcx>,
+    ) -> InterpResult<'tcx> {
+        ecx.machine.events.push(format!("after {}", intrinsic_name));
+        Ok(())
+    }
+
+    fn assert_panic(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        msg: &mir::AssertMessage<'tcx>,
+        _unwind: Option<mir::BasicBlock>,
+    ) -> InterpResult<'tcx> {
+        Err(err_unsup_format!("panic: {:?}", msg).into())
+    }
+
+    fn binary_ptr_op(
+        _ecx: &InterpCx<'mir, 'tcx, Self>,
+        _bin_op: mir::BinOp,
+        _left: &ImmTy<'tcx>,
+        _right: &ImmTy<'tcx>,
+    ) -> InterpResult<'tcx, (Scalar, bool, Ty<'tcx>)> {
+        Err(err_unsup_format!("pointer arithmetic and comparison are not supported").into())
+    }
+
+    fn expose_ptr(_ecx: &mut InterpCx<'mir, 'tcx, Self>, _ptr: Pointer) -> InterpResult<'tcx> {
+        Ok(())
+    }
+
+    fn init_frame_extra(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        frame: Frame<'mir, 'tcx>,
+    ) -> InterpResult<'tcx, Frame<'mir, 'tcx>> {
+        Ok(frame)
+    }
+
+    fn stack<'a>(ecx: &'a InterpCx<'mir, 'tcx, Self>) -> &'a [Frame<'mir, 'tcx>] {
+        &ecx.machine.stack
+    }
+
+    fn stack_mut<'a>(ecx: &'a mut InterpCx<'mir, 'tcx, Self>) -> &'a mut Vec<Frame<'mir, 'tcx>> {
+        &mut ecx.machine.stack
+    }
+}
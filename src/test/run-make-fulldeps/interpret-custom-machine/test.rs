@@ -0,0 +1,11 @@
+#![feature(core_intrinsics)]
+
+use std::intrinsics;
+
+pub fn count_ones() -> u32 {
+    intrinsics::ctpop(0xf0u32) + intrinsics::ctpop(0x1u32)
+}
+
+pub fn inexact_div() -> u32 {
+    unsafe { intrinsics::exact_div(7u32, 2) }
+}
@@ -0,0 +1,35 @@
+// run-pass
+#![feature(const_mut_refs)]
+
+// Copying a whole allocation shares its bytes; make sure writes to either copy stay invisible
+// to the other one.
+
+const LEN: usize = 1 << 16;
+
+const fn copies() -> ([u8; LEN], [u8; LEN], [u8; LEN]) {
+    let mut a = [1_u8; LEN];
+    let mut b = a;
+    let c = b;
+    a[0] = 2;
+    b[LEN - 1] = 3;
+    (a, b, c)
+}
+
+const COPIES: &([u8; LEN], [u8; LEN], [u8; LEN]) = &copies();
+
+const REFS: [&u32; 2] = {
+    let refs = [&1, &2];
+    let copy = refs;
+    copy
+};
+
+fn main() {
+    let (a, b, c) = COPIES;
+    assert_eq!((a[0], a[LEN - 1]), (2, 1));
+    assert_eq!((b[0], b[LEN - 1]), (1, 3));
+    assert_eq!((c[0], c[LEN - 1]), (1, 1));
+    assert!(a[1..LEN - 1].iter().chain(&b[1..LEN - 1]).chain(&c[..]).all(|&x| x == 1));
+
+    // Provenance is carried over as well.
+    assert_eq!(REFS.map(|r| *r), [1, 2]);
+}
@@ -0,0 +1,20 @@
+// normalize-stderr-test "alloc\d+" -> "allocN"
+// Long-running evaluations forget about deallocated memory, but only once nothing points to it
+// any more.
+
+const DANGLING_READ: u8 = {
+    let ptr = {
+        let x = 42u8;
+        &x as *const u8
+    };
+    let mut i = 0;
+    while i < 50_000 {
+        let y = 0u8;
+        let _ptr = &y as *const u8;
+        i += 1;
+    }
+    unsafe { *ptr }
+    //~^ ERROR evaluation of constant value failed
+};
+
+fn main() {}
@@ -0,0 +1,32 @@
+// run-pass
+// Long-running evaluations make allocations with identical contents share their bytes. Writing
+// to one of them must not affect the others.
+
+const fn table() -> [u8; 1024] {
+    let mut t = [0; 1024];
+    let mut i = 0;
+    while i < 1024 {
+        t[i] = i as u8;
+        i += 1;
+    }
+    t
+}
+
+const TABLES: [[u8; 1024]; 2] = {
+    let a = table();
+    let mut b = table();
+    // Run long enough for the bytes of `a` and `b` to get shared.
+    let mut i = 0;
+    while i < 50_000 {
+        i += 1;
+    }
+    b[0] = 42;
+    [a, b]
+};
+
+fn main() {
+    let [a, b] = TABLES;
+    assert_eq!(a, table());
+    assert_eq!(b[0], 42);
+    assert_eq!(b[1..], table()[1..]);
+}
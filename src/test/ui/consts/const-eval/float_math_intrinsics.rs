@@ -0,0 +1,32 @@
+// run-pass
+#![feature(const_float_bits_conv, intrinsics, staged_api)]
+#![stable(feature = "foo", since = "1.3.37")]
+
+extern "rust-intrinsic" {
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn sqrtf32(x: f32) -> f32;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn sqrtf64(x: f64) -> f64;
+}
+
+// `sqrt` is correctly rounded, so the exact bits of the result are known.
+const SQRT2_32: u32 = unsafe { sqrtf32(2.0) }.to_bits();
+const SQRT3_32: u32 = unsafe { sqrtf32(3.0) }.to_bits();
+const SQRT2_64: u64 = unsafe { sqrtf64(2.0) }.to_bits();
+const SQRT_SUBNORMAL: u64 = unsafe { sqrtf64(f64::from_bits(1)) }.to_bits();
+const SQRT_NEG_ZERO: u64 = unsafe { sqrtf64(-0.0) }.to_bits();
+const SQRT_INF: u32 = unsafe { sqrtf32(f32::INFINITY) }.to_bits();
+// NaN results are canonicalized, so their bit pattern is known at compile time.
+const NAN32: u32 = unsafe { sqrtf32(-1.0) }.to_bits();
+const NAN64: u64 = unsafe { sqrtf64(-f64::NAN) }.to_bits();
+
+fn main() {
+    assert_eq!(SQRT2_32, 0x3fb5_04f3);
+    assert_eq!(SQRT3_32, 0x3fdd_b3d7);
+    assert_eq!(SQRT2_64, 0x3ff6_a09e_667f_3bcd);
+    assert_eq!(SQRT_SUBNORMAL, 0x1e60_0000_0000_0000);
+    assert_eq!(SQRT_NEG_ZERO, 0x8000_0000_0000_0000);
+    assert_eq!(SQRT_INF, 0x7f80_0000);
+    assert_eq!(NAN32, 0x7fc0_0000);
+    assert_eq!(NAN64, 0x7ff8_0000_0000_0000);
+}
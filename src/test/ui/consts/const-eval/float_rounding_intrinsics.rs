@@ -0,0 +1,48 @@
+// run-pass
+#![feature(intrinsics, staged_api)]
+#![stable(feature = "foo", since = "1.3.37")]
+
+extern "rust-intrinsic" {
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn floorf32(x: f32) -> f32;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn ceilf64(x: f64) -> f64;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn truncf32(x: f32) -> f32;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn roundf64(x: f64) -> f64;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn round_ties_even_f32(x: f32) -> f32;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn round_ties_even_f64(x: f64) -> f64;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn rintf64(x: f64) -> f64;
+}
+
+const FLOOR: [f32; 3] = unsafe { [floorf32(2.7), floorf32(-2.2), floorf32(-0.5)] };
+const CEIL: [f64; 3] = unsafe { [ceilf64(2.2), ceilf64(-2.7), ceilf64(-0.5)] };
+const TRUNC: [f32; 2] = unsafe { [truncf32(2.7), truncf32(-2.7)] };
+const ROUND: [f64; 3] = unsafe { [roundf64(2.5), roundf64(-2.5), roundf64(3.5)] };
+const TIES_EVEN32: [f32; 3] =
+    unsafe { [round_ties_even_f32(2.5), round_ties_even_f32(-2.5), round_ties_even_f32(3.5)] };
+const TIES_EVEN64: [f64; 3] =
+    unsafe { [round_ties_even_f64(0.5), round_ties_even_f64(1.5), round_ties_even_f64(2.6)] };
+const RINT: [f64; 2] = unsafe { [rintf64(2.5), rintf64(3.5)] };
+const INFINITE: f64 = unsafe { round_ties_even_f64(f64::NEG_INFINITY) };
+
+fn main() {
+    assert_eq!(FLOOR, [2.0, -3.0, -1.0]);
+    assert_eq!(CEIL, [3.0, -2.0, 0.0]);
+    // `ceil(-0.5)` is negative zero.
+    assert!(CEIL[2].is_sign_negative());
+    assert_eq!(TRUNC, [2.0, -2.0]);
+    assert_eq!(ROUND, [3.0, -3.0, 4.0]);
+    assert_eq!(TIES_EVEN32, [2.0, -2.0, 4.0]);
+    assert_eq!(TIES_EVEN64, [0.0, 2.0, 3.0]);
+    assert_eq!(RINT, [2.0, 4.0]);
+    assert_eq!(INFINITE, f64::NEG_INFINITY);
+
+    // The results agree with the runtime implementations.
+    assert_eq!(TIES_EVEN32, unsafe { [2.5, -2.5, 3.5].map(|x| round_ties_even_f32(x)) });
+    assert_eq!(TIES_EVEN64, unsafe { [0.5, 1.5, 2.6].map(|x| round_ties_even_f64(x)) });
+}
@@ -0,0 +1,37 @@
+// run-pass
+#![feature(intrinsics, staged_api)]
+#![stable(feature = "foo", since = "1.3.37")]
+
+extern "rust-intrinsic" {
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn fabsf32(x: f32) -> f32;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn fabsf64(x: f64) -> f64;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn copysignf32(x: f32, y: f32) -> f32;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn copysignf64(x: f64, y: f64) -> f64;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn minnumf32(x: f32, y: f32) -> f32;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn maxnumf64(x: f64, y: f64) -> f64;
+}
+
+const ABS32: [f32; 3] = unsafe { [fabsf32(-2.5), fabsf32(2.5), fabsf32(-0.0)] };
+const ABS64: f64 = unsafe { fabsf64(f64::NEG_INFINITY) };
+const COPYSIGN32: [f32; 2] = unsafe { [copysignf32(3.0, -0.0), copysignf32(-3.0, 1.0)] };
+const COPYSIGN_NAN: f64 = unsafe { copysignf64(f64::NAN, -1.0) };
+const MIN: [f32; 3] =
+    unsafe { [minnumf32(1.0, 2.0), minnumf32(f32::NAN, 2.0), minnumf32(-1.0, f32::NAN)] };
+const MAX: [f64; 2] = unsafe { [maxnumf64(1.0, 2.0), maxnumf64(f64::NAN, -2.0)] };
+
+fn main() {
+    assert_eq!(ABS32, [2.5, 2.5, 0.0]);
+    assert!(ABS32[2].is_sign_positive());
+    assert_eq!(ABS64, f64::INFINITY);
+    assert_eq!(COPYSIGN32, [-3.0, 3.0]);
+    assert!(COPYSIGN_NAN.is_nan() && COPYSIGN_NAN.is_sign_negative());
+    // A NaN input is ignored if the other input is not NaN.
+    assert_eq!(MIN, [1.0, 2.0, -1.0]);
+    assert_eq!(MAX, [2.0, -2.0]);
+}
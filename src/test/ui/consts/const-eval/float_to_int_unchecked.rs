@@ -0,0 +1,22 @@
+// run-pass
+
+#![feature(core_intrinsics)]
+#![feature(const_float_to_int_unchecked)]
+
+use std::intrinsics::float_to_int_unchecked;
+
+const F32_TO_U8: u8 = unsafe { float_to_int_unchecked(255.9_f32) };
+const F32_TO_I8: i8 = unsafe { float_to_int_unchecked(-128.9_f32) };
+const F64_TO_U64: u64 = unsafe { float_to_int_unchecked(18446744073709549568.0_f64) };
+const F64_TO_I32: i32 = unsafe { float_to_int_unchecked(-2.5_f64) };
+const NEG_FRACTION_TO_U32: u32 = unsafe { float_to_int_unchecked(-0.9_f64) };
+const F32_TO_I128: i128 = unsafe { float_to_int_unchecked(-1e30_f32) };
+
+fn main() {
+    assert_eq!(F32_TO_U8, 255);
+    assert_eq!(F32_TO_I8, -128);
+    assert_eq!(F64_TO_U64, 18446744073709549568);
+    assert_eq!(F64_TO_I32, -2);
+    assert_eq!(NEG_FRACTION_TO_U32, 0);
+    assert_eq!(F32_TO_I128, -1e30_f32 as i128);
+}
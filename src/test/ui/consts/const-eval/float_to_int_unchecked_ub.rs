@@ -0,0 +1,11 @@
+#![feature(core_intrinsics)]
+#![feature(const_float_to_int_unchecked)]
+
+use std::intrinsics::float_to_int_unchecked;
+
+const NAN: u32 = unsafe { float_to_int_unchecked(f32::NAN) };
+//~^ ERROR evaluation of constant value failed
+const TOO_BIG: u8 = unsafe { float_to_int_unchecked(256.5_f64) };
+//~^ ERROR evaluation of constant value failed
+
+fn main() {}
@@ -0,0 +1,38 @@
+// run-pass
+#![feature(intrinsics, staged_api)]
+#![stable(feature = "foo", since = "1.3.37")]
+
+extern "rust-intrinsic" {
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn fmaf32(a: f32, b: f32, c: f32) -> f32;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn fmaf64(a: f64, b: f64, c: f64) -> f64;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn fmuladdf32(a: f32, b: f32, c: f32) -> f32;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn fmuladdf64(a: f64, b: f64, c: f64) -> f64;
+}
+
+// `X * X` is `1 + 2 * EPS + EPS * EPS`, so subtracting `1 + 2 * EPS` only leaves something
+// behind if the multiplication is not rounded before the addition.
+const X32: f32 = 1.0 + f32::EPSILON;
+const X64: f64 = 1.0 + f64::EPSILON;
+
+const FMA32: f32 = unsafe { fmaf32(X32, X32, -(1.0 + 2.0 * f32::EPSILON)) };
+const FMA64: f64 = unsafe { fmaf64(X64, X64, -(1.0 + 2.0 * f64::EPSILON)) };
+// Const evaluation always fuses `fmuladd`.
+const FMULADD32: f32 = unsafe { fmuladdf32(X32, X32, -(1.0 + 2.0 * f32::EPSILON)) };
+const FMULADD64: f64 = unsafe { fmuladdf64(X64, X64, -(1.0 + 2.0 * f64::EPSILON)) };
+const SIMPLE: f64 = unsafe { fmuladdf64(2.0, 3.0, 4.0) };
+
+fn main() {
+    assert_eq!(FMA32, f32::EPSILON * f32::EPSILON);
+    assert_eq!(FMA64, f64::EPSILON * f64::EPSILON);
+    assert_eq!(FMULADD32, FMA32);
+    assert_eq!(FMULADD64, FMA64);
+    assert_eq!(SIMPLE, 10.0);
+
+    // At runtime `fmuladd` may or may not be fused.
+    let res = unsafe { fmuladdf64(X64, X64, -(1.0 + 2.0 * f64::EPSILON)) };
+    assert!(res == 0.0 || res == FMA64);
+}
@@ -1,10 +1,11 @@
+// run-pass
 #![feature(core_intrinsics)]
 #![feature(const_heap)]
 #![feature(const_mut_refs)]
 use std::intrinsics;
 
+// Initialized heap memory can end up in the final value behind raw pointers, too.
 const FOO: *const i32 = foo();
-//~^ ERROR untyped pointers are not allowed in constant
 
 const fn foo() -> &'static i32 {
     let t = unsafe {
@@ -14,5 +15,14 @@ const fn foo() -> &'static i32 {
     };
     unsafe { &*t }
 }
+
+const BAR: *const [u8; 3] = unsafe {
+    let ptr = intrinsics::const_allocate(3, 1) as *mut [u8; 3];
+    *ptr = [1, 2, 3];
+    ptr
+};
+
 fn main() {
+    assert_eq!(unsafe { *FOO }, 20);
+    assert_eq!(unsafe { *BAR }, [1, 2, 3]);
 }
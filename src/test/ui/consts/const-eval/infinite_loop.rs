@@ -1,11 +1,15 @@
+// The `long_running_const_eval` warning would point at whichever terminator happens to run at
+// that step, which is not what this test is about.
+#![allow(long_running_const_eval)]
+
 fn main() {
     // Tests the Collatz conjecture with an incorrect base case (0 instead of 1).
     // The value of `n` will loop indefinitely (4 - 2 - 1 - 4).
     let _ = [(); {
+        //~^ ERROR constant evaluation exceeded the limit of 1000000 steps
         let mut n = 113383; // #20 in https://oeis.org/A006884
         while n != 0 {
             n = if n % 2 == 0 { n/2 } else { 3*n + 1 };
-            //~^ ERROR evaluation of constant value failed
         }
         n
     }];
@@ -0,0 +1,25 @@
+// run-pass
+
+#![feature(core_intrinsics)]
+#![feature(is_val_statically_known)]
+
+use std::intrinsics::is_val_statically_known;
+
+const fn pow2(x: u32) -> u32 {
+    if is_val_statically_known(x) && x == 2 { 4 } else { x * x }
+}
+
+const CONST_INT: bool = is_val_statically_known(0_i32);
+const CONST_FLOAT: bool = is_val_statically_known(1.5_f64);
+const CONST_REF: bool = is_val_statically_known("hello");
+const POW: u32 = pow2(2);
+
+fn main() {
+    // Const evaluation never claims to know a value.
+    assert!(!CONST_INT);
+    assert!(!CONST_FLOAT);
+    assert!(!CONST_REF);
+    // Either way, the result must not change behavior.
+    assert_eq!(POW, 4);
+    assert_eq!(pow2(std::hint::black_box(3)), 9);
+}
@@ -1,9 +1,14 @@
+// The `long_running_const_eval` warning would point at whichever terminator happens to run at
+// that step, which is not what this test is about.
+#![allow(long_running_const_eval)]
+
 fn main() {
     let _ = [(); {
+        //~^ ERROR constant evaluation exceeded the limit of 1000000 steps
         let mut x = &0;
         let mut n = 0;
         while n < 5 {
-            n = (n + 1) % 5; //~ ERROR evaluation of constant value failed
+            n = (n + 1) % 5;
             x = &0; // Materialize a new AllocId
         }
         0
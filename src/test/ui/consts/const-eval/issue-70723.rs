@@ -1,3 +1,3 @@
-static _X: () = loop {}; //~ ERROR could not evaluate static initializer
+static _X: () = loop {}; //~ ERROR constant evaluation exceeded the limit of 1000000 steps
 
 fn main() {}
@@ -0,0 +1,36 @@
+// run-pass
+
+#![feature(core_intrinsics)]
+#![feature(const_ptr_mask)]
+
+use std::intrinsics::ptr_mask;
+
+#[repr(align(16))]
+struct Aligned([u8; 32]);
+
+// Clearing bits below the alignment of the allocation is fine at compile-time: the result keeps
+// pointing into the same allocation.
+const ROUND_DOWN: usize = {
+    let data = Aligned([0; 32]);
+    let base = &data.0 as *const u8;
+    let masked = ptr_mask(unsafe { base.add(13) }, !15);
+    unsafe { masked.offset_from(base) as usize }
+};
+const KEEP_ALL: usize = {
+    let data = Aligned([0; 32]);
+    let base = &data.0 as *const u8;
+    unsafe { ptr_mask(base.add(21), usize::MAX).offset_from(base) as usize }
+};
+// Integer addresses are masked as-is.
+const INT_ADDR: usize =
+    unsafe { std::mem::transmute::<*const u8, usize>(ptr_mask(0x1234 as *const u8, !0xff)) };
+
+fn main() {
+    assert_eq!(ROUND_DOWN, 0);
+    assert_eq!(KEEP_ALL, 21);
+    assert_eq!(INT_ADDR, 0x1200);
+
+    let data = Aligned([0; 32]);
+    let base = &data.0 as *const u8;
+    assert_eq!(ptr_mask(unsafe { base.add(29) }, !15), unsafe { base.add(16) });
+}
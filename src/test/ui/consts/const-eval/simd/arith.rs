@@ -0,0 +1,64 @@
+// run-pass
+#![feature(repr_simd)]
+#![feature(platform_intrinsics)]
+#![feature(staged_api)]
+#![stable(feature = "foo", since = "1.3.37")]
+#![allow(non_camel_case_types)]
+
+#[repr(simd)] struct i32x4([i32; 4]);
+#[repr(simd)] struct u8x2(u8, u8);
+#[repr(simd)] struct f32x2([f32; 2]);
+
+extern "platform-intrinsic" {
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_add<T>(x: T, y: T) -> T;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_sub<T>(x: T, y: T) -> T;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_mul<T>(x: T, y: T) -> T;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_div<T>(x: T, y: T) -> T;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_rem<T>(x: T, y: T) -> T;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_and<T>(x: T, y: T) -> T;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_or<T>(x: T, y: T) -> T;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_xor<T>(x: T, y: T) -> T;
+}
+
+fn main() {
+    {
+        const A: i32x4 = i32x4([1, -2, 3, i32::MAX]);
+        const B: i32x4 = i32x4([4, 5, -6, 1]);
+        const ADD: i32x4 = unsafe { simd_add(A, B) };
+        const SUB: i32x4 = unsafe { simd_sub(A, B) };
+        const MUL: i32x4 = unsafe { simd_mul(A, B) };
+        const DIV: i32x4 = unsafe { simd_div(B, A) };
+        const REM: i32x4 = unsafe { simd_rem(B, A) };
+        assert_eq!(ADD.0, [5, 3, -3, i32::MIN]);
+        assert_eq!(SUB.0, [-3, -7, 9, i32::MAX - 1]);
+        assert_eq!(MUL.0, [4, -10, -18, i32::MAX]);
+        assert_eq!(DIV.0, [4, -2, -2, 0]);
+        assert_eq!(REM.0, [0, 1, 0, 1]);
+    }
+    {
+        const A: u8x2 = u8x2(0b1100, 0xff);
+        const B: u8x2 = u8x2(0b1010, 0x0f);
+        const AND: u8x2 = unsafe { simd_and(A, B) };
+        const OR: u8x2 = unsafe { simd_or(A, B) };
+        const XOR: u8x2 = unsafe { simd_xor(A, B) };
+        assert_eq!((AND.0, AND.1), (0b1000, 0x0f));
+        assert_eq!((OR.0, OR.1), (0b1110, 0xff));
+        assert_eq!((XOR.0, XOR.1), (0b0110, 0xf0));
+    }
+    {
+        const A: f32x2 = f32x2([1.5, -4.0]);
+        const B: f32x2 = f32x2([0.5, 2.0]);
+        const ADD: f32x2 = unsafe { simd_add(A, B) };
+        const DIV: f32x2 = unsafe { simd_div(A, B) };
+        assert_eq!(ADD.0, [2.0, -2.0]);
+        assert_eq!(DIV.0, [3.0, -2.0]);
+    }
+}
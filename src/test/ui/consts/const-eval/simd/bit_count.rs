@@ -0,0 +1,50 @@
+// run-pass
+#![feature(repr_simd)]
+#![feature(platform_intrinsics)]
+#![feature(staged_api)]
+#![stable(feature = "foo", since = "1.3.37")]
+#![allow(non_camel_case_types)]
+
+#[repr(simd)] #[derive(Copy, Clone)] struct u8x4([u8; 4]);
+#[repr(simd)] #[derive(Copy, Clone)] struct i32x2([i32; 2]);
+
+extern "platform-intrinsic" {
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_ctlz<T>(x: T) -> T;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_cttz<T>(x: T) -> T;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_ctpop<T>(x: T) -> T;
+}
+
+fn main() {
+    {
+        const A: u8x4 = u8x4([0, 1, 0b0110_0000, u8::MAX]);
+        const CTLZ: u8x4 = unsafe { simd_ctlz(A) };
+        const CTTZ: u8x4 = unsafe { simd_cttz(A) };
+        const CTPOP: u8x4 = unsafe { simd_ctpop(A) };
+        assert_eq!(CTLZ.0, [8, 7, 1, 0]);
+        assert_eq!(CTTZ.0, [8, 0, 5, 0]);
+        assert_eq!(CTPOP.0, [0, 1, 2, 8]);
+
+        // The results agree with the runtime implementations.
+        let a = A;
+        assert_eq!(unsafe { simd_ctlz(a) }.0, CTLZ.0);
+        assert_eq!(unsafe { simd_cttz(a) }.0, CTTZ.0);
+        assert_eq!(unsafe { simd_ctpop(a) }.0, CTPOP.0);
+    }
+    {
+        const A: i32x2 = i32x2([-1, 0x0001_0000]);
+        const CTLZ: i32x2 = unsafe { simd_ctlz(A) };
+        const CTTZ: i32x2 = unsafe { simd_cttz(A) };
+        const CTPOP: i32x2 = unsafe { simd_ctpop(A) };
+        assert_eq!(CTLZ.0, [0, 15]);
+        assert_eq!(CTTZ.0, [0, 16]);
+        assert_eq!(CTPOP.0, [32, 1]);
+
+        let a = A;
+        assert_eq!(unsafe { simd_ctlz(a) }.0, CTLZ.0);
+        assert_eq!(unsafe { simd_cttz(a) }.0, CTTZ.0);
+        assert_eq!(unsafe { simd_ctpop(a) }.0, CTPOP.0);
+    }
+}
@@ -0,0 +1,22 @@
+// normalize-stderr-test "alloc\d+" -> "allocN"
+#![feature(repr_simd)]
+#![feature(platform_intrinsics)]
+#![feature(staged_api)]
+#![stable(feature = "foo", since = "1.3.37")]
+#![allow(non_camel_case_types)]
+
+#[repr(simd)] struct i32x4([i32; 4]);
+
+extern "platform-intrinsic" {
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_masked_load<M, P, T>(mask: M, ptr: P, default: T) -> T;
+}
+
+const DATA: [i32; 4] = [0, 1, 2, 3];
+const MASK: i32x4 = i32x4([0, -1, 0, -1]);
+const DEFAULT: i32x4 = i32x4([0, 0, 0, 0]);
+// The last lane is active and reads one element past the end of `DATA`.
+const C: i32x4 = unsafe { simd_masked_load(MASK, DATA.as_ptr().add(1), DEFAULT) };
+//~^ ERROR evaluation of constant value failed
+
+fn main() {}
@@ -0,0 +1,57 @@
+// run-pass
+#![feature(repr_simd)]
+#![feature(platform_intrinsics)]
+#![feature(const_mut_refs)]
+#![feature(staged_api)]
+#![stable(feature = "foo", since = "1.3.37")]
+#![allow(non_camel_case_types)]
+
+#[repr(simd)] #[derive(Copy, Clone)] struct i32x4([i32; 4]);
+#[repr(simd)] #[derive(Copy, Clone)] struct x4<T>(T, T, T, T);
+
+extern "platform-intrinsic" {
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_gather<T, U, V>(passthru: T, ptrs: U, mask: V) -> T;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_scatter<T, U, V>(values: T, ptrs: U, mask: V);
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_masked_load<M, P, T>(mask: M, ptr: P, default: T) -> T;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_masked_store<M, P, T>(mask: M, ptr: P, values: T);
+}
+
+const MASK: i32x4 = i32x4([-1, 0, -1, -1]);
+const DEFAULT: i32x4 = i32x4([-3, -3, -3, -3]);
+const DATA: [i32; 6] = [0, 1, 2, 3, 4, 5];
+
+const GATHER: i32x4 = unsafe {
+    let p = DATA.as_ptr();
+    // The masked-off lane points past the end of `DATA`, but is never read.
+    simd_gather(DEFAULT, x4(p.add(5), p.wrapping_add(10), p, p.add(2)), MASK)
+};
+
+const SCATTER: [i32; 6] = unsafe {
+    let mut data = DATA;
+    let p = data.as_mut_ptr();
+    // Lanes are written in order, so the last lane writing to `p` wins.
+    simd_scatter(i32x4([10, 11, 12, 13]), x4(p, p.wrapping_add(10), p.add(3), p), MASK);
+    data
+};
+
+const MASKED_LOAD: i32x4 = unsafe {
+    // Only the active lanes have to be in bounds.
+    simd_masked_load(i32x4([-1, -1, 0, 0]), DATA.as_ptr().add(4), DEFAULT)
+};
+
+const MASKED_STORE: [i32; 6] = unsafe {
+    let mut data = DATA;
+    simd_masked_store(MASK, data.as_mut_ptr().add(1), i32x4([10, 11, 12, 13]));
+    data
+};
+
+fn main() {
+    assert_eq!(GATHER.0, [5, -3, 0, 2]);
+    assert_eq!(SCATTER, [13, 1, 2, 12, 4, 5]);
+    assert_eq!(MASKED_LOAD.0, [4, 5, -3, -3]);
+    assert_eq!(MASKED_STORE, [0, 10, 2, 12, 13, 5]);
+}
@@ -0,0 +1,70 @@
+// run-pass
+#![feature(repr_simd)]
+#![feature(platform_intrinsics)]
+#![feature(staged_api)]
+#![stable(feature = "foo", since = "1.3.37")]
+#![allow(non_camel_case_types)]
+
+#[repr(simd)] struct i32x4([i32; 4]);
+#[repr(simd)] struct f32x4([f32; 4]);
+
+extern "platform-intrinsic" {
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_reduce_add_ordered<T, U>(x: T, init: U) -> U;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_reduce_mul_ordered<T, U>(x: T, init: U) -> U;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_reduce_min<T, U>(x: T) -> U;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_reduce_max<T, U>(x: T) -> U;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_reduce_and<T, U>(x: T) -> U;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_reduce_or<T, U>(x: T) -> U;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_reduce_xor<T, U>(x: T) -> U;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_reduce_any<T>(x: T) -> bool;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_reduce_all<T>(x: T) -> bool;
+}
+
+fn main() {
+    {
+        const A: i32x4 = i32x4([1, -2, 12, 6]);
+        const SUM: i32 = unsafe { simd_reduce_add_ordered(A, 10) };
+        const PRODUCT: i32 = unsafe { simd_reduce_mul_ordered(A, 2) };
+        const MIN: i32 = unsafe { simd_reduce_min(A) };
+        const MAX: i32 = unsafe { simd_reduce_max(A) };
+        const AND: i32 = unsafe { simd_reduce_and(A) };
+        const OR: i32 = unsafe { simd_reduce_or(A) };
+        const XOR: i32 = unsafe { simd_reduce_xor(A) };
+        assert_eq!(SUM, 27);
+        assert_eq!(PRODUCT, -288);
+        assert_eq!(MIN, -2);
+        assert_eq!(MAX, 12);
+        assert_eq!(AND, 1 & -2 & 12 & 6);
+        assert_eq!(OR, 1 | -2 | 12 | 6);
+        assert_eq!(XOR, 1 ^ -2 ^ 12 ^ 6);
+    }
+    {
+        const A: f32x4 = f32x4([1.5, f32::NAN, -3.0, 0.5]);
+        const MIN: f32 = unsafe { simd_reduce_min(A) };
+        const MAX: f32 = unsafe { simd_reduce_max(A) };
+        assert_eq!(MIN, -3.0);
+        assert_eq!(MAX, 1.5);
+    }
+    {
+        const NONE: i32x4 = i32x4([0, 0, 0, 0]);
+        const SOME: i32x4 = i32x4([0, -1, 0, 0]);
+        const ALL: i32x4 = i32x4([-1, -1, -1, -1]);
+        const ANY_NONE: bool = unsafe { simd_reduce_any(NONE) };
+        const ANY_SOME: bool = unsafe { simd_reduce_any(SOME) };
+        const ALL_SOME: bool = unsafe { simd_reduce_all(SOME) };
+        const ALL_ALL: bool = unsafe { simd_reduce_all(ALL) };
+        assert!(!ANY_NONE);
+        assert!(ANY_SOME);
+        assert!(!ALL_SOME);
+        assert!(ALL_ALL);
+    }
+}
@@ -0,0 +1,43 @@
+// run-pass
+#![feature(repr_simd)]
+#![feature(platform_intrinsics)]
+#![feature(staged_api)]
+#![stable(feature = "foo", since = "1.3.37")]
+#![allow(non_camel_case_types)]
+
+#[repr(simd)] struct i32x4([i32; 4]);
+#[repr(simd)] struct u8x4([u8; 4]);
+
+extern "platform-intrinsic" {
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_select<M, T>(m: M, yes: T, no: T) -> T;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_select_bitmask<M, T>(m: M, yes: T, no: T) -> T;
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_bitmask<T, U>(x: T) -> U;
+}
+
+fn main() {
+    const MASK: i32x4 = i32x4([0, -1, -1, 0]);
+    const YES: u8x4 = u8x4([1, 2, 3, 4]);
+    const NO: u8x4 = u8x4([5, 6, 7, 8]);
+    {
+        const C: u8x4 = unsafe { simd_select(MASK, YES, NO) };
+        assert_eq!(C.0, [5, 2, 3, 8]);
+    }
+    {
+        const BITMASK: u8 = unsafe { simd_bitmask(MASK) };
+        // This mask is symmetric, so the result does not depend on endianness.
+        assert_eq!(BITMASK, 0b0110);
+
+        const C: u8x4 = unsafe { simd_select_bitmask(BITMASK, YES, NO) };
+        assert_eq!(C.0, [5, 2, 3, 8]);
+    }
+    {
+        const FIRST_LANE: u8 = unsafe { simd_bitmask(i32x4([-1, 0, 0, 0])) };
+        #[cfg(target_endian = "little")]
+        assert_eq!(FIRST_LANE, 0b0001);
+        #[cfg(target_endian = "big")]
+        assert_eq!(FIRST_LANE, 0b1000);
+    }
+}
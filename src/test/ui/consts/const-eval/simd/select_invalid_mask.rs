@@ -0,0 +1,17 @@
+#![feature(repr_simd)]
+#![feature(platform_intrinsics)]
+#![feature(staged_api)]
+#![stable(feature = "foo", since = "1.3.37")]
+#![allow(non_camel_case_types)]
+
+#[repr(simd)] struct i32x2([i32; 2]);
+
+extern "platform-intrinsic" {
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_select<M, T>(m: M, yes: T, no: T) -> T;
+}
+
+const C: i32x2 = unsafe { simd_select(i32x2([0, 1]), i32x2([1, 2]), i32x2([3, 4])) };
+//~^ ERROR evaluation of constant value failed
+
+fn main() {}
@@ -0,0 +1,29 @@
+// run-pass
+#![feature(repr_simd)]
+#![feature(platform_intrinsics)]
+#![feature(staged_api)]
+#![stable(feature = "foo", since = "1.3.37")]
+#![allow(non_camel_case_types)]
+
+#[repr(simd)] struct u16x2([u16; 2]);
+#[repr(simd)] struct u16x4([u16; 4]);
+
+extern "platform-intrinsic" {
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_shuffle<T, I, U>(x: T, y: T, idx: I) -> U;
+}
+
+fn main() {
+    const A: u16x2 = u16x2([1, 2]);
+    const B: u16x2 = u16x2([3, 4]);
+    {
+        const IDX: [u32; 4] = [3, 0, 2, 1];
+        const C: u16x4 = unsafe { simd_shuffle(A, B, IDX) };
+        assert_eq!(C.0, [4, 1, 3, 2]);
+    }
+    {
+        const IDX: [u32; 2] = [1, 1];
+        const C: u16x2 = unsafe { simd_shuffle(A, B, IDX) };
+        assert_eq!(C.0, [2, 2]);
+    }
+}
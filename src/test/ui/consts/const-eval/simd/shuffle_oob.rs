@@ -0,0 +1,19 @@
+#![feature(repr_simd)]
+#![feature(platform_intrinsics)]
+#![feature(staged_api)]
+#![stable(feature = "foo", since = "1.3.37")]
+#![allow(non_camel_case_types)]
+
+#[repr(simd)] struct u16x2([u16; 2]);
+
+extern "platform-intrinsic" {
+    #[rustc_const_stable(feature = "foo", since = "1.3.37")]
+    fn simd_shuffle<T, I, U>(x: T, y: T, idx: I) -> U;
+}
+
+const A: u16x2 = u16x2([1, 2]);
+const IDX: [u32; 2] = [0, 4];
+const C: u16x2 = unsafe { simd_shuffle(A, A, IDX) };
+//~^ ERROR evaluation of constant value failed
+
+fn main() {}
@@ -0,0 +1,39 @@
+// check-pass
+// compile-flags: -Zconst-eval-strict-provenance
+#![feature(strict_provenance)]
+
+const CAST: *const u8 = 4 as *const u8;
+//~^ WARN casting an integer to a pointer creates a pointer without provenance
+
+const TRANSMUTE: *const u8 = unsafe { std::mem::transmute(8usize) };
+//~^ WARN transmuting an integer to a pointer creates a pointer without provenance
+
+// `ptr::invalid` is how such pointers are meant to be created.
+const INVALID: *const u8 = std::ptr::invalid(4);
+
+// Arithmetic on such pointers is fine, only creating them from integers is not.
+const WRAPPING: *const u8 = std::ptr::invalid::<u8>(4).wrapping_add(1);
+
+// The cast is evaluated four times, but only reported once.
+const LOOP: *const u8 = {
+    let mut ptr = std::ptr::null();
+    let mut i = 1;
+    while i <= 4 {
+        ptr = i as *const u8;
+        //~^ WARN casting an integer to a pointer creates a pointer without provenance
+        i += 1;
+    }
+    ptr
+};
+
+#[allow(const_eval_strict_provenance)]
+const ALLOWED: *const u8 = 4 as *const u8;
+
+fn main() {
+    assert_eq!(CAST.addr(), 4);
+    assert_eq!(TRANSMUTE.addr(), 8);
+    assert_eq!(INVALID, CAST);
+    assert_eq!(WRAPPING.addr(), 5);
+    assert_eq!(LOOP, CAST);
+    assert_eq!(ALLOWED, CAST);
+}
@@ -0,0 +1,14 @@
+#![feature(core_intrinsics)]
+#![feature(const_mut_refs)]
+#![feature(const_swap)]
+
+use std::intrinsics::typed_swap_nonoverlapping;
+
+const OVERLAP: () = unsafe {
+    let mut bytes = [0_u8; 3];
+    let ptr = &mut bytes as *mut [u8; 3] as *mut u8;
+    typed_swap_nonoverlapping(ptr as *mut [u8; 2], ptr.add(1) as *mut [u8; 2]);
+    //~^ ERROR evaluation of constant value failed
+};
+
+fn main() {}
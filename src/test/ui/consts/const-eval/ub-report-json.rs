@@ -0,0 +1,6 @@
+// compile-flags: --error-format=json --json=diagnostic-short
+// Undefined behavior during const evaluation is also described in machine-readable form.
+
+const DANGLING: u8 = unsafe { *(16 as *const u8) };
+
+fn main() {}
@@ -0,0 +1,16 @@
+#![feature(core_intrinsics, const_size_of_val)]
+
+trait Foo {}
+trait Bar {}
+impl Foo for u8 {}
+impl Bar for u8 {}
+
+const _: usize = {
+    let foo: &dyn Foo = &0u8;
+    let bar: &dyn Bar = unsafe { std::mem::transmute(foo) };
+    unsafe { std::intrinsics::size_of_val(bar) }
+    //~^ ERROR evaluation of constant value failed
+    //~| using vtable for trait `Foo` but trait `Bar` was expected
+};
+
+fn main() {}
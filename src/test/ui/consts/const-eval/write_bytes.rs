@@ -0,0 +1,30 @@
+// run-pass
+#![feature(const_mut_refs)]
+#![feature(const_ptr_write)]
+
+// Large enough that filling it byte-by-byte would be noticeably slow.
+const LEN: usize = 1 << 20;
+
+const BIG: &[u8; LEN] = &unsafe {
+    let mut bytes = [0_u8; LEN];
+    (&mut bytes as *mut [u8; LEN]).write_bytes(0xAB, 1);
+    bytes
+};
+
+const PARTIAL: [u32; 4] = unsafe {
+    let mut words = [0_u32; 4];
+    (&mut words as *mut [u32; 4] as *mut u32).add(1).write_bytes(0x11, 2);
+    words
+};
+
+const EMPTY: [u16; 2] = unsafe {
+    let mut words = [7_u16; 2];
+    (&mut words as *mut [u16; 2] as *mut u16).write_bytes(0xFF, 0);
+    words
+};
+
+fn main() {
+    assert!(BIG.iter().all(|&b| b == 0xAB));
+    assert_eq!(PARTIAL, [0, 0x11111111, 0x11111111, 0]);
+    assert_eq!(EMPTY, [7, 7]);
+}
@@ -0,0 +1,20 @@
+#![feature(const_eval_limit)]
+#![const_eval_limit = "500"]
+
+// Allowing the lint does not let an infinite loop run forever, evaluation still stops at a
+// multiple of the limit.
+#[allow(const_eval_limit_exceeded)]
+const X: usize = {
+    let mut x = 0;
+    while x != 1000 {
+        //~^ ERROR any use of this value will cause an error
+        //~| WARN this was previously accepted by the compiler but is being phased out
+        x = 0;
+    }
+
+    x
+};
+
+fn main() {
+    assert_eq!(X, 1000);
+}
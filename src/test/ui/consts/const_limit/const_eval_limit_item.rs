@@ -0,0 +1,27 @@
+// check-pass
+#![feature(const_eval_limit)]
+#![const_eval_limit = "500"]
+
+// The crate-level limit is too low for these, but the items can raise it or opt out of it.
+#[const_eval_limit = "4000"]
+const RAISED: usize = {
+    let mut x = 0;
+    while x != 1000 {
+        x += 1;
+    }
+    x
+};
+
+#[allow(const_eval_limit_exceeded)]
+const ALLOWED: usize = {
+    let mut x = 0;
+    while x != 1000 {
+        x += 1;
+    }
+    x
+};
+
+fn main() {
+    assert_eq!(RAISED, 1000);
+    assert_eq!(ALLOWED, 1000);
+}
@@ -0,0 +1,32 @@
+#![feature(const_eval_limit)]
+#![const_eval_limit = "1000"]
+#![allow(dead_code)]
+
+// The limit is only read from the crate root and from items that are evaluated at compile time.
+
+#[const_eval_limit = "10"]
+const CONST: u32 = 0;
+
+#[const_eval_limit = "10"]
+static STATIC: u32 = 0;
+
+trait Trait {
+    #[const_eval_limit = "10"] //~ ERROR attribute should be applied to the crate root
+    const ASSOC: u32;
+}
+
+impl Trait for () {
+    #[const_eval_limit = "10"]
+    const ASSOC: u32 = 0;
+}
+
+#[const_eval_limit = "10"] //~ ERROR attribute should be applied to the crate root
+mod inner {}
+
+#[const_eval_limit = "10"] //~ ERROR attribute should be applied to the crate root
+const fn function() {}
+
+#[const_eval_limit = "10"] //~ ERROR attribute should be applied to the crate root
+struct Struct;
+
+fn main() {}
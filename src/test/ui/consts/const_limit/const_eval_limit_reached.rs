@@ -2,10 +2,9 @@
 #![const_eval_limit = "500"]
 
 const X: usize = {
+    //~^ ERROR constant evaluation exceeded the limit of 500 steps
     let mut x = 0;
     while x != 1000 {
-        //~^ ERROR any use of this value will cause an error
-        //~| WARN this was previously accepted by the compiler but is being phased out
         x += 1;
     }
 
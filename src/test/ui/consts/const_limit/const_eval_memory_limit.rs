@@ -0,0 +1,16 @@
+// compile-flags: -Zconst-eval-memory-limit=1024
+
+const SMALL: usize = {
+    let bytes = [0_u8; 256];
+    bytes.len()
+};
+
+const BIG: usize = {
+    let bytes = [0_u8; 2048];
+    //~^ ERROR evaluation of constant value failed
+    bytes.len()
+};
+
+fn main() {
+    assert_eq!(SMALL, 256);
+}
@@ -0,0 +1,8 @@
+#![feature(const_eval_limit)]
+#![const_eval_limit = "0"]
+#![deny(long_running_const_eval)]
+
+const X: () = loop {};
+//~^ ERROR constant evaluation has been running for 1048576 steps
+
+fn main() {}
@@ -0,0 +1,17 @@
+// check-pass
+#![feature(const_eval_limit)]
+#![const_eval_limit = "0"]
+#![allow(long_running_const_eval)]
+
+// Takes more than 2^20 steps, but finishes.
+const X: u32 = {
+    let mut x = 0;
+    while x < 1 << 20 {
+        x += 1;
+    }
+    x
+};
+
+fn main() {
+    let _ = X;
+}
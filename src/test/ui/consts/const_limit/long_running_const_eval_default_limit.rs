@@ -0,0 +1,7 @@
+#![deny(long_running_const_eval)]
+
+// With the default limit of 1000000 steps, the lint fires before the limit is reached.
+const X: () = loop {};
+//~^ ERROR constant evaluation has been running for 750000 steps
+
+fn main() {}
@@ -0,0 +1,23 @@
+// compile-flags: -Zunleash-the-miri-inside-of-you
+// run-pass
+
+#![feature(core_intrinsics)]
+#![feature(const_mut_refs)]
+
+use std::intrinsics;
+
+// Without other threads, atomic intrinsics behave like plain reads and writes.
+const RESULTS: (u32, u32, (u32, bool), (u32, bool), u32) = unsafe {
+    let mut x = 5_u32;
+    let ptr = &mut x as *mut u32;
+    let added = intrinsics::atomic_xadd_seqcst(ptr, 3);
+    let maxed = intrinsics::atomic_umax_relaxed(ptr, 2);
+    let failed = intrinsics::atomic_cxchg_seqcst_seqcst(ptr, 0, 1);
+    intrinsics::atomic_fence_seqcst();
+    let swapped = intrinsics::atomic_cxchgweak_acquire_relaxed(ptr, 8, 10);
+    (added, maxed, failed, swapped, intrinsics::atomic_load_seqcst(ptr))
+};
+
+fn main() {
+    assert_eq!(RESULTS, (5, 8, (8, false), (8, true), 10));
+}
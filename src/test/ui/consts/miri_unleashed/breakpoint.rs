@@ -0,0 +1,9 @@
+// compile-flags: -Zunleash-the-miri-inside-of-you
+#![feature(core_intrinsics)]
+#![allow(const_err)]
+
+static BREAK: () = unsafe { std::intrinsics::breakpoint() };
+//~^ ERROR could not evaluate static initializer
+//~| NOTE hitting a breakpoint is not supported
+
+fn main() {}
@@ -0,0 +1,12 @@
+// compile-flags: -Zunleash-the-miri-inside-of-you
+#![feature(core_intrinsics)]
+#![allow(const_err)]
+
+fn try_fn(_: *mut u8) {}
+fn catch_fn(_: *mut u8, _: *mut u8) {}
+
+static CAUGHT: i32 = unsafe { std::intrinsics::r#try(try_fn, std::ptr::null_mut(), catch_fn) };
+//~^ ERROR could not evaluate static initializer
+//~| NOTE panics cannot be caught in const contexts
+
+fn main() {}
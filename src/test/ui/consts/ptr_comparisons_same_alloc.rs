@@ -0,0 +1,19 @@
+// check-pass
+// compile-flags: --crate-type=lib
+
+#![feature(core_intrinsics, const_raw_ptr_comparison)]
+
+use std::intrinsics::ptr_guaranteed_cmp;
+
+const ARRAY: &[u8; 4] = &[1, 2, 3, 4];
+const OTHER: &u8 = &5;
+
+// Different in-bounds offsets into the same allocation are never equal.
+pub const _: () = assert!(ptr_guaranteed_cmp(&ARRAY[0] as *const u8, &ARRAY[2] as *const u8) == 0);
+
+// A one-past-the-end pointer may be equal to a pointer into another copy of the allocation.
+pub const _: () =
+    assert!(ptr_guaranteed_cmp(ARRAY.as_ptr(), unsafe { ARRAY.as_ptr().add(4) }) == 2);
+
+// Pointers into different allocations depend on where the allocations end up.
+pub const _: () = assert!(ptr_guaranteed_cmp(ARRAY.as_ptr(), OTHER as *const u8) == 2);
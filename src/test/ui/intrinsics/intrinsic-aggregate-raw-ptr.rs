@@ -0,0 +1,36 @@
+// run-pass
+
+#![feature(core_intrinsics)]
+#![feature(ptr_metadata)]
+
+use std::fmt::Debug;
+use std::intrinsics::{aggregate_raw_ptr, ptr_metadata};
+
+const ARRAY: [u16; 5] = [1, 2, 3, 4, 5];
+
+const THIN: *const u16 = aggregate_raw_ptr(&ARRAY as *const [u16; 5] as *const u16, ());
+const SLICE: *const [u16] = aggregate_raw_ptr(&ARRAY as *const [u16; 5] as *const u16, 3);
+const SLICE_FIRST: u16 = unsafe { (*SLICE)[0] };
+const SLICE_LAST: u16 = unsafe { (*SLICE)[2] };
+const STR: *const str = aggregate_raw_ptr("hello" as *const str as *const u8, 4);
+const STR_LEN: usize = ptr_metadata(STR);
+
+fn main() {
+    assert_eq!(unsafe { *THIN }, 1);
+    assert_eq!(SLICE_FIRST, 1);
+    assert_eq!(SLICE_LAST, 3);
+    assert_eq!(STR_LEN, 4);
+    assert_eq!(unsafe { &*STR }, "hell");
+
+    // The same pointers built at runtime.
+    let mut array = ARRAY;
+    let slice: *mut [u16] = aggregate_raw_ptr(&mut array as *mut [u16; 5] as *mut u16, 2);
+    unsafe { (*slice)[1] = 42 };
+    assert_eq!(array, [1, 42, 3, 4, 5]);
+
+    let value = 7_u64;
+    let dyn_ptr = &value as &dyn Debug as *const dyn Debug;
+    let rebuilt: *const dyn Debug =
+        aggregate_raw_ptr(dyn_ptr as *const (), ptr_metadata(dyn_ptr));
+    assert_eq!(format!("{:?}", unsafe { &*rebuilt }), "7");
+}
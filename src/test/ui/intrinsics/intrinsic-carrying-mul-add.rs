@@ -0,0 +1,34 @@
+// run-pass
+
+#![feature(core_intrinsics)]
+#![feature(const_carrying_mul_add)]
+
+use std::intrinsics::carrying_mul_add;
+
+const U8_MAX: (u8, u8) = carrying_mul_add(u8::MAX, u8::MAX, u8::MAX, u8::MAX);
+const U32: (u32, u32) = carrying_mul_add(0x1234_5678, 0x9abc_def0, 7, 11);
+const U64_MAX: (u64, u64) = carrying_mul_add(u64::MAX, u64::MAX, u64::MAX, u64::MAX);
+const U128: (u128, u128) = carrying_mul_add(1 << 100, 1 << 100, 3, 4);
+const U128_MAX: (u128, u128) = carrying_mul_add(u128::MAX, u128::MAX, u128::MAX, u128::MAX);
+const U128_CARRY: (u128, u128) = carrying_mul_add(u128::MAX, 1, u128::MAX, 2);
+
+fn main() {
+    // `MAX * MAX + MAX + MAX` is exactly the largest double-width value.
+    assert_eq!(U8_MAX, (u8::MAX, u8::MAX));
+    assert_eq!(U64_MAX, (u64::MAX, u64::MAX));
+    assert_eq!(U128_MAX, (u128::MAX, u128::MAX));
+
+    let wide = 0x1234_5678_u64 * 0x9abc_def0 + 7 + 11;
+    assert_eq!(U32, (wide as u32, (wide >> 32) as u32));
+    assert_eq!(U128, (7, 1 << 72));
+    // `MAX + MAX + 2` carries twice into the high half.
+    assert_eq!(U128_CARRY, (0, 2));
+
+    // The same values computed at runtime.
+    assert_eq!(carrying_mul_add(u8::MAX, u8::MAX, u8::MAX, u8::MAX), U8_MAX);
+    assert_eq!(carrying_mul_add(0x1234_5678_u32, 0x9abc_def0, 7, 11), U32);
+    assert_eq!(carrying_mul_add(u64::MAX, u64::MAX, u64::MAX, u64::MAX), U64_MAX);
+    assert_eq!(carrying_mul_add(1_u128 << 100, 1 << 100, 3, 4), U128);
+    assert_eq!(carrying_mul_add(u128::MAX, u128::MAX, u128::MAX, u128::MAX), U128_MAX);
+    assert_eq!(carrying_mul_add(u128::MAX, 1, u128::MAX, 2), U128_CARRY);
+}
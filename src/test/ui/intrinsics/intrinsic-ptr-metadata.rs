@@ -0,0 +1,31 @@
+// run-pass
+
+#![feature(core_intrinsics)]
+#![feature(ptr_metadata)]
+
+use std::fmt::Debug;
+use std::intrinsics::ptr_metadata;
+use std::ptr::DynMetadata;
+
+const ARRAY: [u16; 5] = [1, 2, 3, 4, 5];
+
+const THIN: () = ptr_metadata(&ARRAY as *const [u16; 5]);
+const SLICE_LEN: usize = ptr_metadata(&ARRAY as &[u16] as *const [u16]);
+const STR_LEN: usize = ptr_metadata("hello" as *const str);
+
+fn main() {
+    assert_eq!(THIN, ());
+    assert_eq!(SLICE_LEN, 5);
+    assert_eq!(STR_LEN, 5);
+
+    // The same values computed at runtime.
+    assert_eq!(ptr_metadata(&ARRAY as *const [u16; 5]), ());
+    assert_eq!(ptr_metadata(&ARRAY[..] as *const [u16]), 5);
+    assert_eq!(ptr_metadata("hello" as *const str), 5);
+
+    let value = 7_u64;
+    let dyn_ptr = &value as &dyn Debug as *const dyn Debug;
+    let meta: DynMetadata<dyn Debug> = ptr_metadata(dyn_ptr);
+    assert_eq!(meta.size_of(), 8);
+    assert_eq!(meta, std::ptr::metadata(dyn_ptr));
+}
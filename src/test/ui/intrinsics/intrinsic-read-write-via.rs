@@ -0,0 +1,39 @@
+// run-pass
+
+#![feature(core_intrinsics)]
+#![feature(const_ptr_read, const_ptr_write, const_mut_refs)]
+
+use std::intrinsics::{read_via_copy, write_via_move};
+
+#[derive(Debug, PartialEq)]
+struct NotCopy(u32, &'static str);
+
+const READ: NotCopy = {
+    let value = NotCopy(1, "one");
+    let copy = unsafe { read_via_copy(&value) };
+    std::mem::forget(value);
+    copy
+};
+
+const WRITE: [u16; 3] = {
+    let mut array = [1, 2, 3];
+    unsafe { write_via_move(&mut array[1] as *mut u16, 42) };
+    array
+};
+
+fn main() {
+    assert_eq!(READ, NotCopy(1, "one"));
+    assert_eq!(WRITE, [1, 42, 3]);
+
+    let mut slot = NotCopy(2, "two");
+    let old = unsafe { read_via_copy(&slot) };
+    unsafe { write_via_move(&mut slot, NotCopy(3, "three")) };
+    assert_eq!(old, NotCopy(2, "two"));
+    assert_eq!(slot, NotCopy(3, "three"));
+
+    let mut string = String::from("old");
+    let taken = unsafe { read_via_copy(&string) };
+    unsafe { write_via_move(&mut string, String::from("new")) };
+    assert_eq!(taken, "old");
+    assert_eq!(string, "new");
+}
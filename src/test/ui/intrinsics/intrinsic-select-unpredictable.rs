@@ -0,0 +1,30 @@
+// run-pass
+
+#![feature(core_intrinsics)]
+#![feature(const_select_unpredictable)]
+
+use std::intrinsics::select_unpredictable;
+
+#[derive(Debug, PartialEq)]
+struct Big([u64; 8]);
+
+const SCALAR: i32 = select_unpredictable(true, 1, 2);
+const PAIR: &str = select_unpredictable(false, "yes", "no");
+const BIG: Big = select_unpredictable(true, Big([1; 8]), Big([2; 8]));
+const UNIT: () = select_unpredictable(false, (), ());
+
+fn select<T>(b: bool, t: T, f: T) -> T {
+    select_unpredictable(std::hint::black_box(b), t, f)
+}
+
+fn main() {
+    assert_eq!(SCALAR, 1);
+    assert_eq!(PAIR, "no");
+    assert_eq!(BIG, Big([1; 8]));
+    assert_eq!(UNIT, ());
+
+    assert_eq!(select(false, 1_u8, 2), 2);
+    assert_eq!(select(true, 1.5_f64, 2.5), 1.5);
+    assert_eq!(select(true, "yes", "no"), "yes");
+    assert_eq!(select(false, Big([1; 8]), Big([2; 8])), Big([2; 8]));
+}
@@ -0,0 +1,39 @@
+// run-pass
+
+#![feature(core_intrinsics)]
+#![feature(const_mut_refs)]
+#![feature(const_swap)]
+
+use std::intrinsics::typed_swap_nonoverlapping;
+
+const fn swap<T: Copy>(mut a: T, mut b: T) -> (T, T) {
+    unsafe { typed_swap_nonoverlapping(&mut a, &mut b) };
+    (a, b)
+}
+
+const INTS: (u32, u32) = swap(1, 2);
+// Padding in the middle of the value.
+const PADDED: ((u8, u64), (u8, u64)) = swap((1, 2), (3, 4));
+// Pointers keep their provenance when moved around.
+const STRS: (&str, &str) = swap("left", "right");
+const ARRAYS: ([u16; 5], [u16; 5]) = swap([1, 2, 3, 4, 5], [6, 7, 8, 9, 10]);
+const UNIT: ((), ()) = swap((), ());
+
+fn main() {
+    assert_eq!(INTS, (2, 1));
+    assert_eq!(PADDED, ((3, 4), (1, 2)));
+    assert_eq!(STRS, ("right", "left"));
+    assert_eq!(ARRAYS, ([6, 7, 8, 9, 10], [1, 2, 3, 4, 5]));
+    assert_eq!(UNIT, ((), ()));
+
+    // The same swaps at runtime.
+    assert_eq!(swap(1_u32, 2), INTS);
+    assert_eq!(swap((1_u8, 2_u64), (3, 4)), PADDED);
+    assert_eq!(swap("left", "right"), STRS);
+    assert_eq!(swap([1_u16, 2, 3, 4, 5], [6, 7, 8, 9, 10]), ARRAYS);
+
+    let mut a = String::from("a");
+    let mut b = String::from("b");
+    unsafe { typed_swap_nonoverlapping(&mut a, &mut b) };
+    assert_eq!((a.as_str(), b.as_str()), ("b", "a"));
+}
@@ -0,0 +1,17 @@
+// run-pass
+// revisions: yes no
+//[yes] compile-flags: -Cdebug-assertions=yes
+//[no] compile-flags: -Cdebug-assertions=no
+
+#![feature(core_intrinsics)]
+#![feature(const_ub_checks)]
+
+use std::intrinsics::ub_checks;
+
+const UB_CHECKS: bool = ub_checks();
+
+fn main() {
+    // Both const evaluation and codegen follow the setting of the current crate.
+    assert_eq!(UB_CHECKS, cfg!(debug_assertions));
+    assert_eq!(ub_checks(), cfg!(debug_assertions));
+}
@@ -0,0 +1,49 @@
+// run-pass
+// ignore-emscripten
+
+// Test that the simd_masked_{load,store} intrinsics produce the correct results.
+
+#![feature(repr_simd, platform_intrinsics)]
+#![allow(non_camel_case_types)]
+
+#[repr(simd)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct x4<T>(pub T, pub T, pub T, pub T);
+
+extern "platform-intrinsic" {
+    fn simd_masked_load<M, P, T>(mask: M, pointer: P, values: T) -> T;
+    fn simd_masked_store<M, P, T>(mask: M, pointer: P, values: T) -> ();
+}
+
+fn main() {
+    let mut x = [0_f32, 1., 2., 3., 4., 5.];
+
+    let default = x4(-3_f32, -3., -3., -3.);
+    let mask = x4(-1_i32, 0, -1, -1);
+
+    // reading
+    unsafe {
+        let r = simd_masked_load(mask, x.as_ptr().add(1), default);
+        assert_eq!(r, x4(1_f32, -3., 3., 4.));
+    }
+
+    // the masked-off lanes are past the end of `x`, but are not read
+    unsafe {
+        let r = simd_masked_load(x4(-1_i32, -1, 0, 0), x.as_ptr().add(4), default);
+        assert_eq!(r, x4(4_f32, 5., -3., -3.));
+    }
+
+    // writing
+    unsafe {
+        let values = x4(10_f32, 11., 12., 13.);
+        simd_masked_store(mask, x.as_mut_ptr().add(1), values);
+        assert_eq!(x, [0_f32, 10., 2., 12., 13., 5.]);
+    }
+
+    // the masked-off lanes are past the end of `x`, but are not written
+    unsafe {
+        let values = x4(20_f32, 21., 22., 23.);
+        simd_masked_store(x4(-1_i32, -1, 0, 0), x.as_mut_ptr().add(4), values);
+        assert_eq!(x, [0_f32, 10., 2., 12., 20., 21.]);
+    }
+}
@@ -12,7 +12,9 @@
 use cfg::{CfgExpr, CfgOptions};
 use drop_bomb::DropBomb;
 use either::Either;
-use hir_expand::{hygiene::Hygiene, ExpandError, ExpandResult, HirFileId, InFile, MacroCallId};
+use hir_expand::{
+    hygiene::Hygiene, ExpandError, ExpandResult, HirFileId, InFile, LimitKind, MacroCallId,
+};
 use la_arena::{Arena, ArenaMap};
 use limit::Limit;
 use profile::Count;
@@ -91,11 +93,13 @@ pub fn enter_expand<T: ast::AstNode>(
         db: &dyn DefDatabase,
         macro_call: ast::MacroCall,
     ) -> Result<ExpandResult<Option<(Mark, T)>>, UnresolvedMacro> {
-        if self.recursion_limit(db).check(self.recursion_limit + 1).is_err() {
+        let limit = self.recursion_limit(db);
+        if limit.check(self.recursion_limit + 1).is_err() {
             cov_mark::hit!(your_stack_belongs_to_me);
-            return Ok(ExpandResult::only_err(ExpandError::Other(
-                "reached recursion limit during macro expansion".into(),
-            )));
+            return Ok(ExpandResult::only_err(ExpandError::LimitExceeded {
+                kind: LimitKind::Recursion,
+                limit: limit.inner(),
+            }));
         }
 
         let macro_call = InFile::new(self.current_file_id, &macro_call);
@@ -98,6 +98,35 @@ macro_rules! f {#0
     );
 }
 
+#[test]
+fn token_mapping_transcribed_tokens() {
+    // The parentheses around an `expr` fragment and the literal produced by `${index()}` map
+    // back to the macro definition.
+    check(
+        r#"
+// +tokenids
+macro_rules! f {
+    ($($e:expr),*) => { $(const _: (i32, usize) = ($e, ${index()});)* };
+}
+
+// +tokenids
+f!(x + y, z);
+"#,
+        expect![[r##"
+// call ids will be shifted by Shift(34)
+// +tokenids
+macro_rules! f {#0
+    (#1$#2(#3$#4e#5:#6expr#7)#3,#8*#9)#1 =#10>#11 {#12 $#13(#14const#15 _#16:#17 (#18i32#19,#20 usize#21)#18 =#22 (#23$#24e#25,#26 $#27{#28index#29(#30)#30}#28)#23;#31)#14*#32 }#12;#33
+}#0
+
+// // +tokenids
+// f!(x#1 +#2 y#3,#4 z#5);
+const#15 _#16:#17 (#18i32#19,#20 usize#21)#18 =#22 (#23(#25x#35+#36y#37)#25,#26 0#29)#23;#31
+const#15 _#16:#17 (#18i32#19,#20 usize#21)#18 =#22 (#23z#39,#26 1#29)#23;#31
+"##]],
+    );
+}
+
 #[test]
 fn mbe_smoke_test() {
     check(
@@ -155,6 +184,42 @@ macro_rules! m {
     );
 }
 
+#[test]
+fn mismatched_repetition_lengths() {
+    check(
+        r#"
+macro_rules! m {
+    ($($a:ident)*; $($b:ident)*) => (ok!($($a $b)*);)
+}
+m!{a b; c}
+"#,
+        expect![[r#"
+macro_rules! m {
+    ($($a:ident)*; $($b:ident)*) => (ok!($($a $b)*);)
+}
+/* error: meta-variable `a` repeats 2 times, but `b` repeats 1 time */ok!(a c);
+"#]],
+    );
+}
+
+#[test]
+fn mismatched_nested_repetition_lengths() {
+    check(
+        r#"
+macro_rules! m {
+    ($($($a:ident)* ; $($b:ident)*),*) => (ok!($($($a $b)*),*);)
+}
+m!{a b; c, d; e}
+"#,
+        expect![[r#"
+macro_rules! m {
+    ($($($a:ident)* ; $($b:ident)*),*) => (ok!($($($a $b)*),*);)
+}
+/* error: meta-variable `a` repeats 2 times, but `b` repeats 1 time */ok!(a c, d e);
+"#]],
+    );
+}
+
 #[test]
 fn match_by_first_token_literally() {
     check(
@@ -1053,6 +1118,30 @@ macro_rules! m {
     );
 }
 
+#[test]
+fn test_doc_comments_in_template() {
+    check(
+        r#"
+macro_rules! m {
+    ($name:ident) => {
+        /// Generated by `m!`.
+        struct $name;
+    };
+}
+m!(S);
+"#,
+        expect![[r##"
+macro_rules! m {
+    ($name:ident) => {
+        /// Generated by `m!`.
+        struct $name;
+    };
+}
+#[doc = " Generated by `m!`."] struct S;
+"##]],
+    );
+}
+
 #[test]
 fn test_meta_extended_key_value_attributes() {
     check(
@@ -1175,6 +1264,56 @@ fn test_tt_composite2() {
     );
 }
 
+#[test]
+fn test_tt_fragment_is_not_glued_to_template_punct() {
+    // `$op=` is `+` followed by `=`, not `+=`, so like in rustc this doesn't parse.
+    check(
+        r#"
+macro_rules! m {
+    ($op:tt) => { fn f() { a $op= b; } };
+}
+// +errors
+m!(+);
+"#,
+        expect![[r#"
+macro_rules! m {
+    ($op:tt) => { fn f() { a $op= b; } };
+}
+/* parse error: expected expression */
+/* parse error: expected SEMICOLON */
+fn f() {
+    a+ = b;
+}
+"#]],
+    );
+}
+
+#[test]
+fn test_tt_composite_puncts_round_trip() {
+    // Operators made of several puncts stay intact, both in the template and in fragments.
+    check(
+        r#"
+macro_rules! m {
+    ($($t:tt)*) => { $($t)* fn g() -> u8 { u8::MAX } };
+}
+m!(fn f() -> u8 { let mut x = u8::MAX; x -= 1; x });
+"#,
+        expect![[r#"
+macro_rules! m {
+    ($($t:tt)*) => { $($t)* fn g() -> u8 { u8::MAX } };
+}
+fn f() -> u8 {
+    let mut x = u8::MAX;
+    x-=1;
+    x
+}
+fn g() -> u8 {
+    u8::MAX
+}
+"#]],
+    );
+}
+
 #[test]
 fn test_tt_with_composite_without_space() {
     // Test macro input without any spaces
@@ -1382,6 +1521,25 @@ fn foo() {}
     );
 }
 
+#[test]
+fn test_vis_in_repetition() {
+    check(
+        r#"
+macro_rules! m {
+    ($($vis:vis fn $name:ident;)*) => { $($vis fn $name() {})* }
+}
+m!(fn foo; pub fn bar;);
+"#,
+        expect![[r#"
+macro_rules! m {
+    ($($vis:vis fn $name:ident;)*) => { $($vis fn $name() {})* }
+}
+fn foo() {}
+pub fn bar() {}
+"#]],
+    );
+}
+
 #[test]
 fn test_inner_macro_rules() {
     check(
@@ -1505,6 +1663,28 @@ macro_rules! foo {
     );
 }
 
+#[test]
+fn test_nested_optional_repetitions_are_not_exponential() {
+    // Every `a` can be matched by either of the `$(a)?`, so a naive matcher
+    // tracks 2^n states before it finds out that the first rule doesn't match.
+    check(
+        r#"
+macro_rules! m {
+    ($($(a)? $(a)? b)* c) => { err!(); };
+    ($($t:tt)*) => { ok!(); };
+}
+m!(a b a b a b a b a b a b a b a b a b a b a b a b a b a b a b a b a b a b a b a b a b a b a b a b d);
+"#,
+        expect![[r#"
+macro_rules! m {
+    ($($(a)? $(a)? b)* c) => { err!(); };
+    ($($t:tt)*) => { ok!(); };
+}
+ok!();
+"#]],
+    );
+}
+
 #[test]
 fn expr_interpolation() {
     check(
@@ -92,6 +92,48 @@ macro_rules! m2 { () => ( ${invalid()} ) }
     )
 }
 
+#[test]
+fn follow_set_violations() {
+    check(
+        r#"
+macro_rules! f1 { ($e:expr + $f:expr) => () }
+f1!(1 + 2);
+macro_rules! f2 { ($t:ty $u:ty) => () }
+f2!(u8 u8);
+macro_rules! f3 { ($($p:pat),* ==) => () }
+f3!(==);
+macro_rules! f4 { ($v:vis priv) => () }
+f4!(priv);
+macro_rules! f5 { ($($e:expr)? {}) => () }
+f5!({});
+
+macro_rules! ok {
+    ($e:expr => $t:ty where $($p:pat)|+ in $b:block) => (struct Ok;);
+    ($($e:expr),* ; $v:vis $i:ident $p:path {}) => ();
+}
+ok!(1 => u8 where a | b in {});
+"#,
+        expect![[r#"
+macro_rules! f1 { ($e:expr + $f:expr) => () }
+/* error: invalid macro definition: `$e:expr` is followed by `+`, which is not allowed for `expr` fragments */
+macro_rules! f2 { ($t:ty $u:ty) => () }
+/* error: invalid macro definition: `$t:ty` is followed by `$u:ty`, which is not allowed for `ty` fragments */
+macro_rules! f3 { ($($p:pat),* ==) => () }
+/* error: invalid macro definition: `$p:pat` may be followed by `==`, which is not allowed for `pat` fragments */
+macro_rules! f4 { ($v:vis priv) => () }
+/* error: invalid macro definition: `$v:vis` is followed by `priv`, which is not allowed for `vis` fragments */
+macro_rules! f5 { ($($e:expr)? {}) => () }
+/* error: invalid macro definition: `$e:expr` is followed by `{`, which is not allowed for `expr` fragments */
+
+macro_rules! ok {
+    ($e:expr => $t:ty where $($p:pat)|+ in $b:block) => (struct Ok;);
+    ($($e:expr),* ; $v:vis $i:ident $p:path {}) => ();
+}
+struct Ok;
+"#]],
+    );
+}
+
 #[test]
 fn test_rustc_issue_57597() {
     // <https://github.com/rust-lang/rust/blob/master/src/test/ui/issues/issue-57597.rs>
@@ -265,6 +265,8 @@ pub(crate) fn block_def_map_query(
 
         let mut def_map = DefMap::empty(krate, parent_map.edition, module_data);
         def_map.block = Some(BlockInfo { block: block_id, parent: block.module });
+        // Crate attributes are only visible in the crate root, so inherit the limit from there.
+        def_map.recursion_limit = parent_map.recursion_limit;
 
         let def_map = collector::collect_defs(db, def_map, tree_id);
         Some(Arc::new(def_map))
@@ -3,7 +3,7 @@
 //! `DefCollector::collect` contains the fixed-point iteration loop which
 //! resolves imports and expands macros.
 
-use std::{iter, mem};
+use std::{iter, mem, sync::Arc};
 
 use base_db::{CrateId, Edition, FileId};
 use cfg::{CfgExpr, CfgOptions};
@@ -15,7 +15,7 @@
     builtin_fn_macro::find_builtin_macro,
     name::{name, AsName, Name},
     proc_macro::ProcMacroExpander,
-    ExpandTo, HirFileId, InFile, MacroCallId, MacroCallKind, MacroCallLoc, MacroDefId,
+    ExpandTo, HirFileId, InFile, LimitKind, MacroCallId, MacroCallKind, MacroCallLoc, MacroDefId,
     MacroDefKind,
 };
 use itertools::{izip, Itertools};
@@ -53,7 +53,6 @@
 };
 
 static GLOB_RECURSION_LIMIT: Limit = Limit::new(100);
-static EXPANSION_DEPTH_LIMIT: Limit = Limit::new(128);
 static FIXED_POINT_LIMIT: Limit = Limit::new(8192);
 
 pub(super) fn collect_defs(db: &dyn DefDatabase, mut def_map: DefMap, tree_id: TreeId) -> DefMap {
@@ -1314,6 +1313,13 @@ fn resolve_macros(&mut self) -> ReachedFixedPoint {
         res
     }
 
+    /// Collects the items of a macro expansion, and of all the legacy macro calls in it.
+    ///
+    /// A legacy macro call is expanded before the items after it are collected, so that macros
+    /// defined by the expansion are in scope for them. Instead of recursing for each nested
+    /// call, this keeps the expansions whose items are still being collected on a stack, so
+    /// that how deeply calls can be nested is limited by `#![recursion_limit]` and not by the
+    /// size of the native stack.
     fn collect_macro_expansion(
         &mut self,
         module_id: LocalModuleId,
@@ -1321,17 +1327,70 @@ fn collect_macro_expansion(
         depth: usize,
         container: ItemContainerId,
     ) {
-        if EXPANSION_DEPTH_LIMIT.check(depth).is_err() {
+        let mut stack: Vec<MacroExpansionItems> = Vec::new();
+        stack.extend(self.enter_macro_expansion(module_id, macro_call_id, depth, container));
+        while let Some(expansion) = stack.last_mut() {
+            let item = match expansion.item_tree.top_level_items().get(expansion.next_item) {
+                Some(&item) => item,
+                None => {
+                    stack.pop();
+                    continue;
+                }
+            };
+            expansion.next_item += 1;
+
+            let MacroExpansionItems { module_id, depth, container, tree_id, .. } = *expansion;
+            let item_tree = expansion.item_tree.clone();
+            let mod_dir = expansion.mod_dir.clone();
+            let nested = ModCollector {
+                def_collector: &mut *self,
+                macro_depth: depth,
+                module_id,
+                tree_id,
+                item_tree: &item_tree,
+                mod_dir,
+            }
+            .collect_item(item, container);
+            if let Some(macro_call_id) = nested {
+                stack.extend(self.enter_macro_expansion(
+                    module_id,
+                    macro_call_id,
+                    depth + 1,
+                    container,
+                ));
+            }
+        }
+    }
+
+    /// Checks a macro expansion and prepares the collection of its items. Returns `None` if the
+    /// expansion is nested too deeply.
+    fn enter_macro_expansion(
+        &mut self,
+        module_id: LocalModuleId,
+        macro_call_id: MacroCallId,
+        depth: usize,
+        container: ItemContainerId,
+    ) -> Option<MacroExpansionItems> {
+        let loc: MacroCallLoc = self.db.lookup_intern_macro_call(macro_call_id);
+        let limit = self.expansion_depth_limit();
+        if limit.check(depth).is_err() {
             cov_mark::hit!(macro_expansion_overflow);
-            tracing::warn!("macro expansion is too deep");
-            return;
+            let err = hir_expand::ExpandError::LimitExceeded {
+                kind: LimitKind::Recursion,
+                limit: limit.inner(),
+            };
+            self.def_map.diagnostics.push(DefDiagnostic::macro_error(
+                module_id,
+                loc.kind,
+                err.to_string(),
+            ));
+            return None;
         }
         let file_id = macro_call_id.as_file();
 
         // First, fetch the raw expansion result for purposes of error reporting. This goes through
         // `macro_expand_error` to avoid depending on the full expansion result (to improve
         // incrementality).
-        let loc: MacroCallLoc = self.db.lookup_intern_macro_call(macro_call_id);
         let err = self.db.macro_expand_error(macro_call_id);
         if let Some(err) = err {
             let diag = match err {
@@ -1347,17 +1406,33 @@ fn collect_macro_expansion(
         }
 
         // Then, fetch and process the item tree. This will reuse the expansion result from above.
+        let tree_id = TreeId::new(file_id, None);
         let item_tree = self.db.file_item_tree(file_id);
         let mod_dir = self.mod_dirs[&module_id].clone();
         ModCollector {
             def_collector: &mut *self,
             macro_depth: depth,
-            tree_id: TreeId::new(file_id, None),
+            tree_id,
             module_id,
             item_tree: &item_tree,
-            mod_dir,
+            mod_dir: mod_dir.clone(),
         }
-        .collect(item_tree.top_level_items(), container);
+        .collect_hoisted(item_tree.top_level_items());
+        Some(MacroExpansionItems {
+            module_id,
+            depth,
+            container,
+            tree_id,
+            item_tree,
+            mod_dir,
+            next_item: 0,
+        })
+    }
+
+    /// How deeply macro calls may be nested, as set by the crate's `#![recursion_limit]`.
+    fn expansion_depth_limit(&self) -> Limit {
+        // 128 is the default in rustc.
+        Limit::new(self.def_map.recursion_limit.unwrap_or(128) as usize)
     }
 
     fn finish(mut self) -> DefMap {
@@ -1455,6 +1530,19 @@ fn finish(mut self) -> DefMap {
     }
 }
 
+/// A macro expansion whose items are being collected, see
+/// `DefCollector::collect_macro_expansion`.
+struct MacroExpansionItems {
+    module_id: LocalModuleId,
+    depth: usize,
+    container: ItemContainerId,
+    tree_id: TreeId,
+    item_tree: Arc<ItemTree>,
+    mod_dir: ModDir,
+    /// The index of the next top-level item to collect.
+    next_item: usize,
+}
+
 /// Walks a single module, populating defs, imports and macros
 struct ModCollector<'a, 'b> {
     def_collector: &'a mut DefCollector<'b>,
@@ -1472,6 +1560,21 @@ fn collect_in_top_module(&mut self, items: &[ModItem]) {
     }
 
     fn collect(&mut self, items: &[ModItem], container: ItemContainerId) {
+        self.collect_hoisted(items);
+        for &item in items {
+            if let Some(macro_call_id) = self.collect_item(item, container) {
+                self.def_collector.collect_macro_expansion(
+                    self.module_id,
+                    macro_call_id,
+                    self.macro_depth + 1,
+                    container,
+                );
+            }
+        }
+    }
+
+    /// Does the work that has to happen before any of `items` are collected.
+    fn collect_hoisted(&mut self, items: &[ModItem]) {
         let krate = self.def_collector.def_map.krate;
 
         // Note: don't assert that inserted value is fresh: it's simply not true
@@ -1505,215 +1608,215 @@ fn collect(&mut self, items: &[ModItem], container: ItemContainerId) {
                 }
             }
         }
+    }
 
-        for &item in items {
-            let attrs = self.item_tree.attrs(self.def_collector.db, krate, item.into());
-            if let Some(cfg) = attrs.cfg() {
-                if !self.is_cfg_enabled(&cfg) {
-                    self.emit_unconfigured_diagnostic(item, &cfg);
-                    continue;
-                }
+    /// Collects a single item. If it is a call of a legacy macro, this returns the call, whose
+    /// expansion has to be collected before the next item.
+    fn collect_item(&mut self, item: ModItem, container: ItemContainerId) -> Option<MacroCallId> {
+        let krate = self.def_collector.def_map.krate;
+        let attrs = self.item_tree.attrs(self.def_collector.db, krate, item.into());
+        if let Some(cfg) = attrs.cfg() {
+            if !self.is_cfg_enabled(&cfg) {
+                self.emit_unconfigured_diagnostic(item, &cfg);
+                return None;
             }
+        }
 
-            if let Err(()) = self.resolve_attributes(&attrs, item, container) {
-                // Do not process the item. It has at least one non-builtin attribute, so the
-                // fixed-point algorithm is required to resolve the rest of them.
-                continue;
-            }
+        if let Err(()) = self.resolve_attributes(&attrs, item, container) {
+            // Do not process the item. It has at least one non-builtin attribute, so the
+            // fixed-point algorithm is required to resolve the rest of them.
+            return None;
+        }
 
-            let db = self.def_collector.db;
-            let module = self.def_collector.def_map.module_id(self.module_id);
-            let def_map = &mut self.def_collector.def_map;
-            let update_def =
-                |def_collector: &mut DefCollector<'_>, id, name: &Name, vis, has_constructor| {
-                    def_collector.def_map.modules[self.module_id].scope.declare(id);
-                    def_collector.update(
-                        self.module_id,
-                        &[(Some(name.clone()), PerNs::from_def(id, vis, has_constructor))],
-                        vis,
-                        ImportType::Named,
-                    )
-                };
-            let resolve_vis = |def_map: &DefMap, visibility| {
-                def_map
-                    .resolve_visibility(db, self.module_id, visibility)
-                    .unwrap_or(Visibility::Public)
+        let db = self.def_collector.db;
+        let module = self.def_collector.def_map.module_id(self.module_id);
+        let def_map = &mut self.def_collector.def_map;
+        let update_def =
+            |def_collector: &mut DefCollector<'_>, id, name: &Name, vis, has_constructor| {
+                def_collector.def_map.modules[self.module_id].scope.declare(id);
+                def_collector.update(
+                    self.module_id,
+                    &[(Some(name.clone()), PerNs::from_def(id, vis, has_constructor))],
+                    vis,
+                    ImportType::Named,
+                )
             };
+        let resolve_vis = |def_map: &DefMap, visibility| {
+            def_map.resolve_visibility(db, self.module_id, visibility).unwrap_or(Visibility::Public)
+        };
 
-            match item {
-                ModItem::Mod(m) => self.collect_module(m, &attrs),
-                ModItem::Import(import_id) => {
-                    let imports = Import::from_use(
+        match item {
+            ModItem::Mod(m) => self.collect_module(m, &attrs),
+            ModItem::Import(import_id) => {
+                let imports = Import::from_use(
+                    db,
+                    krate,
+                    self.item_tree,
+                    ItemTreeId::new(self.tree_id, import_id),
+                );
+                self.def_collector.unresolved_imports.extend(imports.into_iter().map(|import| {
+                    ImportDirective {
+                        module_id: self.module_id,
+                        import,
+                        status: PartialResolvedImport::Unresolved,
+                    }
+                }));
+            }
+            ModItem::ExternCrate(import_id) => {
+                self.def_collector.unresolved_imports.push(ImportDirective {
+                    module_id: self.module_id,
+                    import: Import::from_extern_crate(
                         db,
                         krate,
                         self.item_tree,
                         ItemTreeId::new(self.tree_id, import_id),
-                    );
-                    self.def_collector.unresolved_imports.extend(imports.into_iter().map(
-                        |import| ImportDirective {
-                            module_id: self.module_id,
-                            import,
-                            status: PartialResolvedImport::Unresolved,
-                        },
-                    ));
-                }
-                ModItem::ExternCrate(import_id) => {
-                    self.def_collector.unresolved_imports.push(ImportDirective {
-                        module_id: self.module_id,
-                        import: Import::from_extern_crate(
-                            db,
-                            krate,
-                            self.item_tree,
-                            ItemTreeId::new(self.tree_id, import_id),
-                        ),
-                        status: PartialResolvedImport::Unresolved,
-                    })
-                }
-                ModItem::ExternBlock(block) => self.collect(
-                    &self.item_tree[block].children,
-                    ItemContainerId::ExternBlockId(
-                        ExternBlockLoc {
-                            container: module,
-                            id: ItemTreeId::new(self.tree_id, block),
-                        }
-                        .intern(db),
                     ),
+                    status: PartialResolvedImport::Unresolved,
+                })
+            }
+            ModItem::ExternBlock(block) => self.collect(
+                &self.item_tree[block].children,
+                ItemContainerId::ExternBlockId(
+                    ExternBlockLoc { container: module, id: ItemTreeId::new(self.tree_id, block) }
+                        .intern(db),
                 ),
-                ModItem::MacroCall(mac) => self.collect_macro_call(&self.item_tree[mac], container),
-                ModItem::MacroRules(id) => self.collect_macro_rules(id, module),
-                ModItem::MacroDef(id) => self.collect_macro_def(id, module),
-                ModItem::Impl(imp) => {
-                    let impl_id =
-                        ImplLoc { container: module, id: ItemTreeId::new(self.tree_id, imp) }
-                            .intern(db);
-                    self.def_collector.def_map.modules[self.module_id].scope.define_impl(impl_id)
-                }
-                ModItem::Function(id) => {
-                    let it = &self.item_tree[id];
-                    let fn_id =
-                        FunctionLoc { container, id: ItemTreeId::new(self.tree_id, id) }.intern(db);
-
-                    let vis = resolve_vis(def_map, &self.item_tree[it.visibility]);
-                    if self.def_collector.is_proc_macro {
-                        if self.module_id == def_map.root {
-                            if let Some(proc_macro) = attrs.parse_proc_macro_decl(&it.name) {
-                                let crate_root = def_map.module_id(def_map.root);
-                                self.def_collector.export_proc_macro(
-                                    proc_macro,
-                                    ItemTreeId::new(self.tree_id, id),
-                                    fn_id,
-                                    crate_root,
-                                );
-                            }
+            ),
+            ModItem::MacroCall(mac) => {
+                return self.collect_macro_call(&self.item_tree[mac], container);
+            }
+            ModItem::MacroRules(id) => self.collect_macro_rules(id, module),
+            ModItem::MacroDef(id) => self.collect_macro_def(id, module),
+            ModItem::Impl(imp) => {
+                let impl_id = ImplLoc { container: module, id: ItemTreeId::new(self.tree_id, imp) }
+                    .intern(db);
+                self.def_collector.def_map.modules[self.module_id].scope.define_impl(impl_id)
+            }
+            ModItem::Function(id) => {
+                let it = &self.item_tree[id];
+                let fn_id =
+                    FunctionLoc { container, id: ItemTreeId::new(self.tree_id, id) }.intern(db);
+
+                let vis = resolve_vis(def_map, &self.item_tree[it.visibility]);
+                if self.def_collector.is_proc_macro {
+                    if self.module_id == def_map.root {
+                        if let Some(proc_macro) = attrs.parse_proc_macro_decl(&it.name) {
+                            let crate_root = def_map.module_id(def_map.root);
+                            self.def_collector.export_proc_macro(
+                                proc_macro,
+                                ItemTreeId::new(self.tree_id, id),
+                                fn_id,
+                                crate_root,
+                            );
                         }
                     }
-
-                    update_def(self.def_collector, fn_id.into(), &it.name, vis, false);
-                }
-                ModItem::Struct(id) => {
-                    let it = &self.item_tree[id];
-
-                    let vis = resolve_vis(def_map, &self.item_tree[it.visibility]);
-                    update_def(
-                        self.def_collector,
-                        StructLoc { container: module, id: ItemTreeId::new(self.tree_id, id) }
-                            .intern(db)
-                            .into(),
-                        &it.name,
-                        vis,
-                        !matches!(it.fields, Fields::Record(_)),
-                    );
-                }
-                ModItem::Union(id) => {
-                    let it = &self.item_tree[id];
-
-                    let vis = resolve_vis(def_map, &self.item_tree[it.visibility]);
-                    update_def(
-                        self.def_collector,
-                        UnionLoc { container: module, id: ItemTreeId::new(self.tree_id, id) }
-                            .intern(db)
-                            .into(),
-                        &it.name,
-                        vis,
-                        false,
-                    );
-                }
-                ModItem::Enum(id) => {
-                    let it = &self.item_tree[id];
-
-                    let vis = resolve_vis(def_map, &self.item_tree[it.visibility]);
-                    update_def(
-                        self.def_collector,
-                        EnumLoc { container: module, id: ItemTreeId::new(self.tree_id, id) }
-                            .intern(db)
-                            .into(),
-                        &it.name,
-                        vis,
-                        false,
-                    );
                 }
-                ModItem::Const(id) => {
-                    let it = &self.item_tree[id];
-                    let const_id =
-                        ConstLoc { container, id: ItemTreeId::new(self.tree_id, id) }.intern(db);
-
-                    match &it.name {
-                        Some(name) => {
-                            let vis = resolve_vis(def_map, &self.item_tree[it.visibility]);
-                            update_def(self.def_collector, const_id.into(), name, vis, false);
-                        }
-                        None => {
-                            // const _: T = ...;
-                            self.def_collector.def_map.modules[self.module_id]
-                                .scope
-                                .define_unnamed_const(const_id);
-                        }
+
+                update_def(self.def_collector, fn_id.into(), &it.name, vis, false);
+            }
+            ModItem::Struct(id) => {
+                let it = &self.item_tree[id];
+
+                let vis = resolve_vis(def_map, &self.item_tree[it.visibility]);
+                update_def(
+                    self.def_collector,
+                    StructLoc { container: module, id: ItemTreeId::new(self.tree_id, id) }
+                        .intern(db)
+                        .into(),
+                    &it.name,
+                    vis,
+                    !matches!(it.fields, Fields::Record(_)),
+                );
+            }
+            ModItem::Union(id) => {
+                let it = &self.item_tree[id];
+
+                let vis = resolve_vis(def_map, &self.item_tree[it.visibility]);
+                update_def(
+                    self.def_collector,
+                    UnionLoc { container: module, id: ItemTreeId::new(self.tree_id, id) }
+                        .intern(db)
+                        .into(),
+                    &it.name,
+                    vis,
+                    false,
+                );
+            }
+            ModItem::Enum(id) => {
+                let it = &self.item_tree[id];
+
+                let vis = resolve_vis(def_map, &self.item_tree[it.visibility]);
+                update_def(
+                    self.def_collector,
+                    EnumLoc { container: module, id: ItemTreeId::new(self.tree_id, id) }
+                        .intern(db)
+                        .into(),
+                    &it.name,
+                    vis,
+                    false,
+                );
+            }
+            ModItem::Const(id) => {
+                let it = &self.item_tree[id];
+                let const_id =
+                    ConstLoc { container, id: ItemTreeId::new(self.tree_id, id) }.intern(db);
+
+                match &it.name {
+                    Some(name) => {
+                        let vis = resolve_vis(def_map, &self.item_tree[it.visibility]);
+                        update_def(self.def_collector, const_id.into(), name, vis, false);
+                    }
+                    None => {
+                        // const _: T = ...;
+                        self.def_collector.def_map.modules[self.module_id]
+                            .scope
+                            .define_unnamed_const(const_id);
                     }
                 }
-                ModItem::Static(id) => {
-                    let it = &self.item_tree[id];
-
-                    let vis = resolve_vis(def_map, &self.item_tree[it.visibility]);
-                    update_def(
-                        self.def_collector,
-                        StaticLoc { container, id: ItemTreeId::new(self.tree_id, id) }
-                            .intern(db)
-                            .into(),
-                        &it.name,
-                        vis,
-                        false,
-                    );
-                }
-                ModItem::Trait(id) => {
-                    let it = &self.item_tree[id];
-
-                    let vis = resolve_vis(def_map, &self.item_tree[it.visibility]);
-                    update_def(
-                        self.def_collector,
-                        TraitLoc { container: module, id: ItemTreeId::new(self.tree_id, id) }
-                            .intern(db)
-                            .into(),
-                        &it.name,
-                        vis,
-                        false,
-                    );
-                }
-                ModItem::TypeAlias(id) => {
-                    let it = &self.item_tree[id];
-
-                    let vis = resolve_vis(def_map, &self.item_tree[it.visibility]);
-                    update_def(
-                        self.def_collector,
-                        TypeAliasLoc { container, id: ItemTreeId::new(self.tree_id, id) }
-                            .intern(db)
-                            .into(),
-                        &it.name,
-                        vis,
-                        false,
-                    );
-                }
+            }
+            ModItem::Static(id) => {
+                let it = &self.item_tree[id];
+
+                let vis = resolve_vis(def_map, &self.item_tree[it.visibility]);
+                update_def(
+                    self.def_collector,
+                    StaticLoc { container, id: ItemTreeId::new(self.tree_id, id) }
+                        .intern(db)
+                        .into(),
+                    &it.name,
+                    vis,
+                    false,
+                );
+            }
+            ModItem::Trait(id) => {
+                let it = &self.item_tree[id];
+
+                let vis = resolve_vis(def_map, &self.item_tree[it.visibility]);
+                update_def(
+                    self.def_collector,
+                    TraitLoc { container: module, id: ItemTreeId::new(self.tree_id, id) }
+                        .intern(db)
+                        .into(),
+                    &it.name,
+                    vis,
+                    false,
+                );
+            }
+            ModItem::TypeAlias(id) => {
+                let it = &self.item_tree[id];
+
+                let vis = resolve_vis(def_map, &self.item_tree[it.visibility]);
+                update_def(
+                    self.def_collector,
+                    TypeAliasLoc { container, id: ItemTreeId::new(self.tree_id, id) }
+                        .intern(db)
+                        .into(),
+                    &it.name,
+                    vis,
+                    false,
+                );
             }
         }
+        None
     }
 
     fn collect_module(&mut self, module_id: FileItemTreeId<Mod>, attrs: &Attrs) {
@@ -2034,7 +2137,12 @@ fn collect_macro_def(&mut self, id: FileItemTreeId<MacroDef>, module: ModuleId)
         );
     }
 
-    fn collect_macro_call(&mut self, mac: &MacroCall, container: ItemContainerId) {
+    /// Collects a macro call. Calls of legacy macros are returned, see `collect_item`.
+    fn collect_macro_call(
+        &mut self,
+        mac: &MacroCall,
+        container: ItemContainerId,
+    ) -> Option<MacroCallId> {
         let ast_id = AstIdWithPath::new(self.file_id(), mac.ast_id, ModPath::clone(&mac.path));
 
         // Case 1: try to resolve in legacy scope and expand macro_rules
@@ -2064,15 +2172,6 @@ fn collect_macro_call(&mut self, mac: &MacroCall, container: ItemContainerId) {
             },
         ) {
             Ok(Ok(macro_call_id)) => {
-                // Legacy macros need to be expanded immediately, so that any macros they produce
-                // are in scope.
-                self.def_collector.collect_macro_expansion(
-                    self.module_id,
-                    macro_call_id,
-                    self.macro_depth + 1,
-                    container,
-                );
-
                 if let Some(err) = error {
                     self.def_collector.def_map.diagnostics.push(DefDiagnostic::macro_error(
                         self.module_id,
@@ -2081,7 +2180,9 @@ fn collect_macro_call(&mut self, mac: &MacroCall, container: ItemContainerId) {
                     ));
                 }
 
-                return;
+                // Legacy macros need to be expanded immediately, so that any macros they produce
+                // are in scope. The caller does that.
+                return Some(macro_call_id);
             }
             Ok(Err(_)) => {
                 // Built-in macro failed eager expansion.
@@ -2091,7 +2192,7 @@ fn collect_macro_call(&mut self, mac: &MacroCall, container: ItemContainerId) {
                     MacroCallKind::FnLike { ast_id: ast_id.ast_id, expand_to: mac.expand_to },
                     error.unwrap().to_string(),
                 ));
-                return;
+                return None;
             }
             Err(UnresolvedMacro { .. }) => (),
         }
@@ -2103,6 +2204,7 @@ fn collect_macro_call(&mut self, mac: &MacroCall, container: ItemContainerId) {
             kind: MacroDirectiveKind::FnLike { ast_id, expand_to: mac.expand_to },
             container,
         });
+        None
     }
 
     fn import_all_legacy_macros(&mut self, module_id: LocalModuleId) {
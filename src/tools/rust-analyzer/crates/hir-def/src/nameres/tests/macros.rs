@@ -864,6 +864,58 @@ macro_rules! b {
     );
 }
 
+#[test]
+fn macro_expansion_respects_recursion_limit() {
+    cov_mark::check!(macro_expansion_overflow);
+    check(
+        r#"
+#![recursion_limit = "2"]
+macro_rules! m {
+    ($name:ident $($rest:ident)*) => {
+        struct $name;
+        m!($($rest)*);
+    };
+    () => {};
+}
+
+m!(A B C D);
+"#,
+        expect![[r#"
+            crate
+            A: t v
+            B: t v
+        "#]],
+    );
+}
+
+#[test]
+fn macro_expansion_allows_deep_nesting_with_high_recursion_limit() {
+    // Collecting nested expansions doesn't recurse, so this doesn't overflow the stack.
+    let fixture = format!(
+        r#"
+#![recursion_limit = "256"]
+macro_rules! m {{
+    ($first:ident $($rest:ident)*) => {{
+        m!($($rest)*);
+    }};
+    () => {{
+        struct Done;
+    }};
+}}
+
+m!({});
+"#,
+        "x ".repeat(250)
+    );
+    check(
+        &fixture,
+        expect![[r#"
+            crate
+            Done: t v
+        "#]],
+    );
+}
+
 #[test]
 fn macros_defining_macros() {
     check(
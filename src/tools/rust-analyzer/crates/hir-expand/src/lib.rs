@@ -47,9 +47,22 @@
 pub enum ExpandError {
     UnresolvedProcMacro(CrateId),
     Mbe(mbe::ExpandError),
+    /// Expansion was cut short because it went over one of the expansion limits.
+    LimitExceeded {
+        kind: LimitKind,
+        limit: usize,
+    },
     Other(Box<str>),
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LimitKind {
+    /// Macro calls were nested deeper than the crate's `recursion_limit`.
+    Recursion,
+    /// A single macro call produced too many tokens.
+    Tokens { produced: usize },
+}
+
 impl From<mbe::ExpandError> for ExpandError {
     fn from(mbe: mbe::ExpandError) -> Self {
         Self::Mbe(mbe)
@@ -61,6 +74,14 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ExpandError::UnresolvedProcMacro(_) => f.write_str("unresolved proc-macro"),
             ExpandError::Mbe(it) => it.fmt(f),
+            ExpandError::LimitExceeded { kind: LimitKind::Recursion, limit } => {
+                write!(f, "reached recursion limit of {} during macro expansion", limit)
+            }
+            ExpandError::LimitExceeded { kind: LimitKind::Tokens { produced }, limit } => write!(
+                f,
+                "macro invocation exceeds token limit: produced {} tokens, limit is {}",
+                produced, limit
+            ),
             ExpandError::Other(it) => f.write_str(it),
         }
     }
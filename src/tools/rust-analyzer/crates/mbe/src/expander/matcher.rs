@@ -332,6 +332,46 @@ struct MatchState<'t> {
 
     /// Is error occuried in this state, will `poised` to "parent"
     is_error: bool,
+
+    /// Whether another state reached the same position, through a different path. Such states
+    /// are merged, see [`dedup_items`].
+    is_ambiguous: bool,
+}
+
+impl MatchState<'_> {
+    fn is_same_position(&self, other: &Self) -> bool {
+        fn same_dot(a: &OpDelimitedIter<'_>, b: &OpDelimitedIter<'_>) -> bool {
+            std::ptr::eq(a.inner, b.inner) && a.idx == b.idx
+        }
+
+        same_dot(&self.dot, &other.dot)
+            && self.stack.len() == other.stack.len()
+            && self.stack.iter().zip(&other.stack).all(|(a, b)| same_dot(a, b))
+            && self.sep_parsed == other.sep_parsed
+            && match (&self.up, &other.up) {
+                (Some(a), Some(b)) => a.is_same_position(b),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+/// Merges the states that are at the same position, keeping the first one.
+///
+/// Such states match the rest of the input in exactly the same way, so there is no point in
+/// tracking them separately. Without this, nested optional repetitions can make the number of
+/// states grow exponentially with the length of the input. The surviving state is marked as
+/// ambiguous, as it would have been an error for it to reach the end of the input together with
+/// the others.
+fn dedup_items<'t>(items: &mut Vec<MatchState<'t>>) {
+    let mut deduped: Vec<MatchState<'t>> = Vec::with_capacity(items.len());
+    for item in items.drain(..) {
+        match deduped.iter_mut().find(|it| it.is_same_position(&item)) {
+            Some(it) => it.is_ambiguous = true,
+            None => deduped.push(item),
+        }
+    }
+    *items = deduped;
 }
 
 /// Process the matcher positions of `cur_items` until it is empty. In the process, this will
@@ -397,6 +437,7 @@ macro_rules! try_push {
                         // Move the "dot" past the repetition in `up`
                         new_pos.dot.next();
                         new_pos.is_error = new_pos.is_error || item.is_error;
+                        new_pos.is_ambiguous = new_pos.is_ambiguous || item.is_ambiguous;
                         cur_items.push(new_pos);
                     }
 
@@ -445,6 +486,7 @@ macro_rules! try_push {
                     );
                     cur_items.push(new_item);
                 }
+                let is_ambiguous = item.is_ambiguous;
                 cur_items.push(MatchState {
                     dot: tokens.iter_delimited(None),
                     stack: Default::default(),
@@ -455,6 +497,7 @@ macro_rules! try_push {
                     bindings: bindings_builder.alloc(),
                     meta_result: None,
                     is_error: false,
+                    is_ambiguous,
                 })
             }
             OpDelimited::Op(Op::Subtree { tokens, delimiter }) => {
@@ -537,6 +580,7 @@ fn match_loop(pattern: &MetaTemplate, src: &tt::Subtree) -> Match {
         sep_parsed: None,
         bindings: bindings_builder.alloc(),
         is_error: false,
+        is_ambiguous: false,
         meta_result: None,
     }];
 
@@ -572,7 +616,7 @@ fn match_loop(pattern: &MetaTemplate, src: &tt::Subtree) -> Match {
         // If we reached the EOF, check that there is EXACTLY ONE possible matcher. Otherwise,
         // either the parse is ambiguous (which should never happen) or there is a syntax error.
         if src.peek_n(0).is_none() && stack.is_empty() {
-            if let [state] = &*eof_items {
+            if let [state @ MatchState { is_ambiguous: false, .. }] = &*eof_items {
                 // remove all errors, because it is the correct answer !
                 res = Match::default();
                 res.bindings = bindings_builder.build(&state.bindings);
@@ -593,7 +637,8 @@ fn match_loop(pattern: &MetaTemplate, src: &tt::Subtree) -> Match {
         // (black-box) parser. However, if there is not EXACTLY ONE of these, something is wrong.
         let has_leftover_tokens = (bb_items.is_empty() && next_items.is_empty())
             || !(bb_items.is_empty() || next_items.is_empty())
-            || bb_items.len() > 1;
+            || bb_items.len() > 1
+            || bb_items.iter().any(|it| it.is_ambiguous);
         if has_leftover_tokens {
             res.unmatched_tts += src.len();
             while let Some(it) = stack.pop() {
@@ -610,6 +655,7 @@ fn match_loop(pattern: &MetaTemplate, src: &tt::Subtree) -> Match {
         // Dump all possible `next_items` into `cur_items` for the next iteration.
         else if !next_items.is_empty() {
             // Now process the next token
+            dedup_items(&mut next_items);
             cur_items.extend(next_items.drain(..));
 
             match src.next() {
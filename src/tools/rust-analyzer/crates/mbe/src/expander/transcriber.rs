@@ -2,12 +2,12 @@
 //! `$ident => foo`, interpolates variables in the template, to get `fn foo() {}`
 
 use syntax::SmolStr;
-use tt::{Delimiter, Subtree};
+use tt::Delimiter;
 
 use crate::{
     expander::{Binding, Bindings, Fragment},
     parser::{Op, RepeatKind, Separator},
-    ExpandError, ExpandResult, MetaTemplate,
+    ExpandError, ExpandResult, MetaTemplate, NestedBindingLenMismatch,
 };
 
 impl Bindings {
@@ -15,37 +15,50 @@ fn contains(&self, name: &str) -> bool {
         self.inner.contains_key(name)
     }
 
-    fn get(&self, name: &str, nesting: &mut [NestingState]) -> Result<&Fragment, ExpandError> {
-        macro_rules! binding_err {
-            ($($arg:tt)*) => { ExpandError::binding_error(format!($($arg)*)) };
-        }
+    fn get(
+        &self,
+        name: &str,
+        span: tt::TokenId,
+        nesting: &[NestingState],
+    ) -> Result<&Fragment, ExpandError> {
+        let unresolved = || ExpandError::UnresolvedBinding(Box::new((name.into(), span)));
 
-        let mut b: &Binding =
-            self.inner.get(name).ok_or_else(|| binding_err!("could not find binding `{name}`"))?;
-        for nesting_state in nesting.iter_mut() {
-            nesting_state.hit = true;
+        let mut b: &Binding = self.inner.get(name).ok_or_else(unresolved)?;
+        for nesting_state in nesting {
             b = match b {
                 Binding::Fragment(_) => break,
-                Binding::Nested(bs) => bs.get(nesting_state.idx).ok_or_else(|| {
-                    nesting_state.at_end = true;
-                    binding_err!("could not find nested binding `{name}`")
-                })?,
-                Binding::Empty => {
-                    nesting_state.at_end = true;
-                    return Err(binding_err!("could not find empty binding `{name}`"));
-                }
+                Binding::Nested(bs) => bs.get(nesting_state.idx).ok_or_else(unresolved)?,
+                Binding::Empty => return Err(unresolved()),
             };
         }
         match b {
             Binding::Fragment(it) => Ok(it),
-            Binding::Nested(_) => {
-                Err(binding_err!("expected simple binding, found nested binding `{name}`"))
-            }
-            Binding::Empty => {
-                Err(binding_err!("expected simple binding, found empty binding `{name}`"))
+            Binding::Nested(_) | Binding::Empty => {
+                Err(ExpandError::BindingDepthMismatch(Box::new((name.into(), span))))
             }
         }
     }
+
+    /// Returns how many times the binding for `name` repeats inside the repetitions given by
+    /// `nesting`, or `None` if it is bound outside of them and so doesn't repeat there.
+    fn repeat_len(&self, name: &str, nesting: &[NestingState]) -> Option<usize> {
+        let mut b = self.inner.get(name)?;
+        for nesting_state in nesting {
+            b = match b {
+                Binding::Fragment(_) => return None,
+                Binding::Nested(bs) => match bs.get(nesting_state.idx) {
+                    Some(b) => b,
+                    None => return Some(0),
+                },
+                Binding::Empty => return Some(0),
+            };
+        }
+        match b {
+            Binding::Fragment(_) => None,
+            Binding::Nested(bs) => Some(bs.len()),
+            Binding::Empty => Some(0),
+        }
+    }
 }
 
 pub(super) fn transcribe(
@@ -60,12 +73,6 @@ pub(super) fn transcribe(
 #[derive(Debug)]
 struct NestingState {
     idx: usize,
-    /// `hit` is currently necessary to tell `expand_repeat` if it should stop
-    /// because there is no variable in use by the current repetition
-    hit: bool,
-    /// `at_end` is currently necessary to tell `expand_repeat` if it should stop
-    /// because there is no more value available for the current repetition
-    at_end: bool,
 }
 
 #[derive(Debug)]
@@ -95,29 +102,26 @@ fn expand_subtree(
             Op::Var { name, id, .. } => {
                 let ExpandResult { value: fragment, err: e } = expand_var(ctx, name, *id);
                 err = err.or(e);
-                push_fragment(arena, fragment);
+                push_fragment_separated(arena, start_elements, fragment);
             }
             Op::Repeat { tokens: subtree, kind, separator } => {
                 let ExpandResult { value: fragment, err: e } =
                     expand_repeat(ctx, subtree, *kind, separator, arena);
                 err = err.or(e);
-                push_fragment(arena, fragment)
+                push_fragment_separated(arena, start_elements, fragment)
             }
             Op::Ignore { name, id } => {
                 // Expand the variable, but ignore the result. This registers the repetition count.
                 expand_var(ctx, name, *id);
             }
-            Op::Index { depth } => {
+            Op::Index { depth, id } => {
                 let index = ctx
                     .nesting
                     .get(ctx.nesting.len() - 1 - (*depth as usize))
                     .map_or(0, |nest| nest.idx);
                 arena.push(
-                    tt::Leaf::Literal(tt::Literal {
-                        text: index.to_string().into(),
-                        id: tt::TokenId::unspecified(),
-                    })
-                    .into(),
+                    tt::Leaf::Literal(tt::Literal { text: index.to_string().into(), id: *id })
+                        .into(),
                 );
             }
         }
@@ -155,9 +159,20 @@ fn expand_var(ctx: &mut ExpandCtx<'_>, v: &SmolStr, id: tt::TokenId) -> ExpandRe
         .into();
         ExpandResult::ok(Fragment::Tokens(tt))
     } else {
-        ctx.bindings.get(v, &mut ctx.nesting).map_or_else(
+        ctx.bindings.get(v, id, &ctx.nesting).map_or_else(
             |e| ExpandResult { value: Fragment::Tokens(tt::TokenTree::empty()), err: Some(e) },
-            |b| ExpandResult::ok(b.clone()),
+            |b| {
+                let mut fragment = b.clone();
+                // An expression is wrapped in parentheses to keep its precedence. They don't exist
+                // in the source, so map them to the variable in the macro definition.
+                if let Fragment::Expr(tt::TokenTree::Subtree(tt)) = &mut fragment {
+                    if tt.delimiter.is_none() {
+                        tt.delimiter =
+                            Some(tt::Delimiter { id, kind: tt::DelimiterKind::Parenthesis });
+                    }
+                }
+                ExpandResult::ok(fragment)
+            },
         )
     }
 }
@@ -170,43 +185,25 @@ fn expand_repeat(
     arena: &mut Vec<tt::TokenTree>,
 ) -> ExpandResult<Fragment> {
     let mut buf: Vec<tt::TokenTree> = Vec::new();
-    ctx.nesting.push(NestingState { idx: 0, at_end: false, hit: false });
-    // Dirty hack to make macro-expansion terminate.
-    // This should be replaced by a proper macro-by-example implementation
-    let limit = 65536;
+    let ExpandResult { value: mut count, mut err } = repeat_count(ctx, template);
+    if RepeatKind::ZeroOrOne == kind {
+        count = count.min(1);
+    }
     let mut has_seps = 0;
-    let mut counter = 0;
 
-    loop {
+    ctx.nesting.push(NestingState { idx: 0 });
+    for idx in 0..count {
+        ctx.nesting.last_mut().unwrap().idx = idx;
         let ExpandResult { value: mut t, err: e } = expand_subtree(ctx, template, None, arena);
-        let nesting_state = ctx.nesting.last_mut().unwrap();
-        if nesting_state.at_end || !nesting_state.hit {
-            break;
-        }
-        nesting_state.idx += 1;
-        nesting_state.hit = false;
-
-        counter += 1;
-        if counter == limit {
-            tracing::warn!(
-                "expand_tt in repeat pattern exceed limit => {:#?}\n{:#?}",
-                template,
-                ctx
-            );
-            return ExpandResult {
-                value: Fragment::Tokens(Subtree::default().into()),
-                err: Some(ExpandError::LimitExceeded),
-            };
-        }
-
-        if e.is_some() {
-            continue;
-        }
+        // Keep what could be expanded, like for the outermost repetition, but don't lose the
+        // error, e.g. a length mismatch between the variables of a nested repetition.
+        err = err.or(e);
 
         t.delimiter = None;
         push_subtree(&mut buf, t);
 
         if let Some(sep) = separator {
+            set_alone(&mut buf);
             has_seps = match sep {
                 Separator::Ident(ident) => {
                     buf.push(tt::Leaf::from(ident.clone()).into());
@@ -220,14 +217,13 @@ fn expand_repeat(
                     for &punct in puncts {
                         buf.push(tt::Leaf::from(punct).into());
                     }
+                    // The separator is a single token, its last punct is only joint to the
+                    // repetition operator in the macro definition.
+                    set_alone(&mut buf);
                     puncts.len()
                 }
             };
         }
-
-        if RepeatKind::ZeroOrOne == kind {
-            break;
-        }
     }
 
     ctx.nesting.pop().unwrap();
@@ -239,31 +235,84 @@ fn expand_repeat(
     // e.g {Delimiter:None> ['>'] /Delimiter:None>}
     let tt = tt::Subtree { delimiter: None, token_trees: buf }.into();
 
-    if RepeatKind::OneOrMore == kind && counter == 0 {
+    if RepeatKind::OneOrMore == kind && count == 0 {
         return ExpandResult {
             value: Fragment::Tokens(tt),
             err: Some(ExpandError::UnexpectedToken),
         };
     }
-    ExpandResult::ok(Fragment::Tokens(tt))
+    ExpandResult { value: Fragment::Tokens(tt), err }
+}
+
+/// Returns how many times the repetition `template` repeats, which is how many times the
+/// variables used in it repeat at this depth. Variables bound outside of the repetition don't
+/// count, and if there are no other variables, it repeats zero times.
+fn repeat_count(ctx: &ExpandCtx<'_>, template: &MetaTemplate) -> ExpandResult<usize> {
+    fn collect_lens<'t>(
+        ctx: &ExpandCtx<'_>,
+        template: &'t MetaTemplate,
+        lens: &mut Vec<(&'t SmolStr, tt::TokenId, usize)>,
+    ) {
+        for op in template.iter() {
+            match op {
+                Op::Var { name, id, .. } | Op::Ignore { name, id } => {
+                    if let Some(len) = ctx.bindings.repeat_len(name, &ctx.nesting) {
+                        lens.push((name, *id, len));
+                    }
+                }
+                Op::Repeat { tokens, .. } | Op::Subtree { tokens, .. } => {
+                    collect_lens(ctx, tokens, lens)
+                }
+                Op::Leaf(_) | Op::Index { .. } => {}
+            }
+        }
+    }
+
+    let mut lens = Vec::new();
+    collect_lens(ctx, template, &mut lens);
+    let count = lens.iter().map(|&(_, _, len)| len).min().unwrap_or(0);
+    // rustc rejects variables that repeat a different number of times, but expanding as many
+    // times as all of them can still gives useful output.
+    let err = lens.first().and_then(|&(name, _, len)| {
+        let &(other, span, other_len) = lens.iter().find(|&&(_, _, other_len)| other_len != len)?;
+        Some(ExpandError::NestedBindingLenMismatch(Box::new(NestedBindingLenMismatch {
+            name: name.clone(),
+            len,
+            other: other.clone(),
+            other_len,
+            span,
+        })))
+    });
+    ExpandResult { value: count, err }
 }
 
 fn push_fragment(buf: &mut Vec<tt::TokenTree>, fragment: Fragment) {
     match fragment {
         Fragment::Tokens(tt::TokenTree::Subtree(tt)) => push_subtree(buf, tt),
-        Fragment::Expr(tt::TokenTree::Subtree(mut tt)) => {
-            if tt.delimiter.is_none() {
-                tt.delimiter = Some(tt::Delimiter {
-                    id: tt::TokenId::unspecified(),
-                    kind: tt::DelimiterKind::Parenthesis,
-                })
-            }
-            buf.push(tt.into())
-        }
         Fragment::Tokens(tt) | Fragment::Expr(tt) => buf.push(tt),
     }
 }
 
+/// Pushes a fragment that is substituted for an op of the template. Puncts keep the spacing
+/// they have in the template or the macro input, except at the edges of the fragment: those
+/// are separate tokens, so e.g. `$op=` with `$op` bound to `+` must not turn into `+=`.
+fn push_fragment_separated(buf: &mut Vec<tt::TokenTree>, start: usize, fragment: Fragment) {
+    set_alone(&mut buf[start..]);
+    push_fragment(buf, fragment);
+    set_alone(&mut buf[start..]);
+}
+
+/// Makes sure the last token of `tts` isn't glued to the token that follows it.
+fn set_alone(tts: &mut [tt::TokenTree]) {
+    match tts.last_mut() {
+        Some(tt::TokenTree::Leaf(tt::Leaf::Punct(punct))) => punct.spacing = tt::Spacing::Alone,
+        Some(tt::TokenTree::Subtree(tt::Subtree { delimiter: None, token_trees })) => {
+            set_alone(token_trees)
+        }
+        _ => {}
+    }
+}
+
 fn push_subtree(buf: &mut Vec<tt::TokenTree>, tt: tt::Subtree) {
     match tt.delimiter {
         None => buf.extend(tt.token_trees),
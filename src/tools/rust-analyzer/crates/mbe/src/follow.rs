@@ -0,0 +1,292 @@
+//! FOLLOW-set checking for `macro_rules` matchers.
+//!
+//! Fragments like `expr` or `ty` may swallow an unbounded number of tokens, so
+//! rustc only allows them to be followed by a fixed set of tokens. Macros that
+//! violate this are rejected at the definition site; we do the same, instead of
+//! picking some arbitrary parse when the macro is used.
+//!
+//! This is a port of `check_matcher` from rustc:
+//! https://github.com/rust-lang/rust/blob/4596f4f8b565bdd02d3b99d1ab12ff09146a93de/compiler/rustc_expand/src/mbe/macro_rules.rs#L684
+
+use std::fmt;
+
+use syntax::SmolStr;
+
+use crate::{
+    parser::{MetaTemplate, Op, RepeatKind, Separator},
+    ParseError,
+};
+
+pub(crate) fn check_follow_sets(pattern: &MetaTemplate) -> Result<(), ParseError> {
+    check(&pattern.0, &TokenSet::empty()).map(drop)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token<'a> {
+    /// A punctuation token, with joint puncts glued the same way rustc's lexer does.
+    Punct(SmolStr),
+    Ident(&'a SmolStr),
+    Literal(&'a SmolStr),
+    Open(&'static str),
+    Close,
+    Fragment {
+        name: &'a SmolStr,
+        kind: &'a SmolStr,
+    },
+}
+
+impl fmt::Display for Token<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Punct(text) => f.write_str(text),
+            Token::Ident(text) | Token::Literal(text) => f.write_str(text),
+            Token::Open(text) => f.write_str(text),
+            Token::Close => Ok(()),
+            Token::Fragment { name, kind } => write!(f, "${}:{}", name, kind),
+        }
+    }
+}
+
+/// The set of tokens that can start (or end) a sequence of matcher ops.
+#[derive(Clone, Debug)]
+struct TokenSet<'a> {
+    tokens: Vec<Token<'a>>,
+    /// Whether the sequence can match no tokens at all.
+    maybe_empty: bool,
+}
+
+impl<'a> TokenSet<'a> {
+    fn empty() -> Self {
+        TokenSet { tokens: Vec::new(), maybe_empty: true }
+    }
+
+    fn singleton(token: Token<'a>) -> Self {
+        TokenSet { tokens: vec![token], maybe_empty: false }
+    }
+
+    fn replace_with(&mut self, token: Token<'a>) {
+        *self = TokenSet::singleton(token);
+    }
+
+    /// Forgets the tokens tracked so far, for tokens that can be followed by anything.
+    fn replace_with_irrelevant(&mut self) {
+        self.tokens.clear();
+        self.maybe_empty = false;
+    }
+
+    /// Adds `token` without touching `maybe_empty`.
+    fn add_one_maybe(&mut self, token: Token<'a>) {
+        if !self.tokens.contains(&token) {
+            self.tokens.push(token);
+        }
+    }
+
+    fn add_all(&mut self, other: &TokenSet<'a>) {
+        for token in &other.tokens {
+            self.add_one_maybe(token.clone());
+        }
+        if !other.maybe_empty {
+            self.maybe_empty = false;
+        }
+    }
+}
+
+/// Computes the set of tokens `ops` may start with.
+fn first(ops: &[Op]) -> TokenSet<'_> {
+    let mut res = TokenSet::empty();
+    for (i, op) in ops.iter().enumerate() {
+        let token = match op {
+            Op::Repeat { tokens, kind, separator } => {
+                let subfirst = first(&tokens.0);
+                let maybe_empty = subfirst.maybe_empty;
+                // If the repetition can match nothing, it can start with its separator.
+                if let (Some(separator), true) = (separator, maybe_empty) {
+                    res.add_one_maybe(separator_token(separator));
+                }
+                res.add_all(&TokenSet { maybe_empty: true, ..subfirst });
+                if maybe_empty || *kind != RepeatKind::OneOrMore {
+                    continue;
+                }
+                res.maybe_empty = false;
+                return res;
+            }
+            Op::Subtree { delimiter, .. } => Token::Open(open_text(delimiter)),
+            _ => match single_token(&ops[i..]) {
+                Some(token) => token,
+                None => continue,
+            },
+        };
+        res.add_one_maybe(token);
+        res.maybe_empty = false;
+        return res;
+    }
+    res
+}
+
+/// Checks that every fragment in `ops` is only followed by tokens from its
+/// FOLLOW set, assuming `ops` itself is followed by a token from `follow`.
+///
+/// Returns the set of fragments that may end `ops`.
+fn check<'a>(ops: &'a [Op], follow: &TokenSet<'a>) -> Result<TokenSet<'a>, ParseError> {
+    let mut last = TokenSet::empty();
+
+    for (i, op) in ops.iter().enumerate() {
+        let suffix_first = || {
+            let mut suffix_first = first(&ops[i + 1..]);
+            if suffix_first.maybe_empty {
+                suffix_first.add_all(follow);
+            }
+            suffix_first
+        };
+
+        let suffix_first = match op {
+            Op::Var { name, kind: Some(kind), .. } if !can_be_followed_by_any(kind) => {
+                last.replace_with(Token::Fragment { name, kind });
+                suffix_first()
+            }
+            Op::Subtree { tokens, .. } => {
+                check(&tokens.0, &TokenSet::singleton(Token::Close))?;
+                last.replace_with_irrelevant();
+                continue;
+            }
+            Op::Repeat { tokens, separator, .. } => {
+                // The separator may follow the last fragment of the repetition,
+                // but doesn't have to.
+                let mut suffix_first = suffix_first();
+                if let Some(separator) = separator {
+                    suffix_first.add_one_maybe(separator_token(separator));
+                }
+                let next = check(&tokens.0, &suffix_first)?;
+                if next.maybe_empty {
+                    last.add_all(&next);
+                } else {
+                    last = next;
+                }
+                continue;
+            }
+            _ => {
+                last.replace_with_irrelevant();
+                continue;
+            }
+        };
+
+        for token in &last.tokens {
+            let (name, kind) = match token {
+                Token::Fragment { name, kind } => (name, kind),
+                _ => continue,
+            };
+            for next in &suffix_first.tokens {
+                if !is_in_follow(next, kind) {
+                    let may_be = if last.tokens.len() == 1 && suffix_first.tokens.len() == 1 {
+                        "is"
+                    } else {
+                        "may be"
+                    };
+                    return Err(ParseError::UnexpectedToken(
+                        format!(
+                            "`${}:{}` {} followed by `{}`, which is not allowed for `{}` fragments",
+                            name, kind, may_be, next, kind
+                        )
+                        .into(),
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(last)
+}
+
+/// Fragments which match at most one token tree (or are always terminated by
+/// one, like items) can be followed by anything.
+fn can_be_followed_by_any(kind: &str) -> bool {
+    matches!(kind, "item" | "block" | "ident" | "literal" | "meta" | "lifetime" | "tt")
+}
+
+fn is_in_follow(token: &Token<'_>, kind: &str) -> bool {
+    let text = match token {
+        // Closing a token tree can never be matched by any fragment.
+        Token::Close => return true,
+        Token::Punct(text) => text.as_str(),
+        Token::Ident(text) => text.as_str(),
+        Token::Open(text) => *text,
+        Token::Literal(_) | Token::Fragment { .. } => "",
+    };
+    match kind {
+        "expr" | "stmt" => matches!(text, "=>" | "," | ";"),
+        // We don't know the edition here, so `pat` is treated like the
+        // pre-2021 `pat`, which may be followed by `|`.
+        "pat" | "pat_param" => matches!(text, "=>" | "," | "=" | "|" | "if" | "in"),
+        "path" | "ty" => match token {
+            Token::Fragment { kind, .. } => *kind == "block",
+            _ => matches!(
+                text,
+                "{" | "[" | "=>" | "," | ">" | ">>" | "=" | ":" | ";" | "|" | "as" | "where"
+            ),
+        },
+        "vis" => match token {
+            // `priv` is explicitly disallowed, on the off chance it comes back.
+            Token::Ident(text) => *text != "priv",
+            // `,`, or anything that can start a type.
+            Token::Punct(_) | Token::Open(_) => matches!(
+                text,
+                "," | "(" | "[" | "!" | "*" | "&" | "&&" | "?" | "<" | "<<" | "::" | "'"
+            ),
+            Token::Fragment { kind, .. } => matches!(kind.as_str(), "ident" | "ty" | "path"),
+            _ => false,
+        },
+        _ => true,
+    }
+}
+
+/// Returns the token at the start of `ops`, gluing joint puncts together.
+fn single_token(ops: &[Op]) -> Option<Token<'_>> {
+    let token = match ops.first()? {
+        Op::Var { name, kind: Some(kind), .. } => Token::Fragment { name, kind },
+        Op::Var { name, kind: None, .. } => Token::Ident(name),
+        Op::Leaf(tt::Leaf::Ident(ident)) => Token::Ident(&ident.text),
+        Op::Leaf(tt::Leaf::Literal(lit)) => Token::Literal(&lit.text),
+        Op::Leaf(tt::Leaf::Punct(_)) => {
+            let mut puncts = String::new();
+            for op in ops.iter().take(3) {
+                match op {
+                    Op::Leaf(tt::Leaf::Punct(punct)) => {
+                        puncts.push(punct.char);
+                        if punct.spacing == tt::Spacing::Alone {
+                            break;
+                        }
+                    }
+                    _ => break,
+                }
+            }
+            Token::Punct(glue(&puncts).into())
+        }
+        _ => return None,
+    };
+    Some(token)
+}
+
+fn separator_token(separator: &Separator) -> Token<'_> {
+    match separator {
+        Separator::Ident(ident) => Token::Ident(&ident.text),
+        Separator::Literal(lit) => Token::Literal(&lit.text),
+        Separator::Puncts(puncts) => Token::Punct(puncts.iter().map(|it| it.char).collect()),
+    }
+}
+
+fn open_text(delimiter: &Option<tt::Delimiter>) -> &'static str {
+    match delimiter.map(|it| it.kind) {
+        Some(tt::DelimiterKind::Brace) => "{",
+        Some(tt::DelimiterKind::Bracket) => "[",
+        Some(tt::DelimiterKind::Parenthesis) | None => "(",
+    }
+}
+
+/// Returns the longest prefix of `puncts` that rustc's lexer treats as a single token.
+fn glue(puncts: &str) -> &str {
+    const GLUED: &[&str] = &[
+        "<<=", ">>=", "...", "..=", "==", "!=", "<=", ">=", "&&", "||", "+=", "-=", "*=", "/=",
+        "%=", "^=", "&=", "|=", "<<", ">>", "..", "::", "->", "=>",
+    ];
+    GLUED.iter().find(|it| puncts.starts_with(**it)).map_or(&puncts[..1], |it| *it)
+}
@@ -10,6 +10,7 @@
 
 mod parser;
 mod expander;
+mod follow;
 mod syntax_bridge;
 mod tt_iter;
 mod to_parser_input;
@@ -20,6 +21,8 @@
 
 use std::fmt;
 
+use syntax::SmolStr;
+
 use crate::{
     parser::{MetaTemplate, Op},
     tt_iter::TtIter,
@@ -70,13 +73,31 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum ExpandError {
     BindingError(Box<Box<str>>),
+    /// A meta-variable used in a transcriber has no binding at the current repetition.
+    ///
+    /// Holds the name of the meta-variable and the token id of its use in the macro definition.
+    UnresolvedBinding(Box<(SmolStr, tt::TokenId)>),
+    /// A meta-variable is bound inside of a repetition, but used outside of it.
+    BindingDepthMismatch(Box<(SmolStr, tt::TokenId)>),
+    /// Two meta-variables used in the same repetition of a transcriber repeat a different number
+    /// of times.
+    NestedBindingLenMismatch(Box<NestedBindingLenMismatch>),
     LeftoverTokens,
     ConversionError,
-    LimitExceeded,
     NoMatchingRule,
     UnexpectedToken,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct NestedBindingLenMismatch {
+    pub name: SmolStr,
+    pub len: usize,
+    pub other: SmolStr,
+    pub other_len: usize,
+    /// The token id of `other` in the macro definition.
+    pub span: tt::TokenId,
+}
+
 impl ExpandError {
     fn binding_error(e: impl Into<Box<str>>) -> ExpandError {
         ExpandError::BindingError(Box::new(e.into()))
@@ -89,8 +110,21 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             ExpandError::NoMatchingRule => f.write_str("no rule matches input tokens"),
             ExpandError::UnexpectedToken => f.write_str("unexpected token in input"),
             ExpandError::BindingError(e) => f.write_str(e),
+            ExpandError::UnresolvedBinding(it) => write!(f, "could not find binding `{}`", it.0),
+            ExpandError::BindingDepthMismatch(it) => {
+                write!(f, "expected simple binding, found nested binding `{}`", it.0)
+            }
+            ExpandError::NestedBindingLenMismatch(it) => {
+                let NestedBindingLenMismatch { name, len, other, other_len, .. } = &**it;
+                let times = |len: usize| if len == 1 { "time" } else { "times" };
+                write!(
+                    f,
+                    "meta-variable `{name}` repeats {len} {}, but `{other}` repeats {other_len} {}",
+                    times(*len),
+                    times(*other_len),
+                )
+            }
             ExpandError::ConversionError => f.write_str("could not convert tokens"),
-            ExpandError::LimitExceeded => f.write_str("Expand exceed limit"),
             ExpandError::LeftoverTokens => f.write_str("leftover tokens"),
         }
     }
@@ -206,6 +240,7 @@ pub fn parse_macro_rules(tt: &tt::Subtree) -> Result<DeclarativeMacro, ParseErro
 
         for Rule { lhs, .. } in &rules {
             validate(lhs)?;
+            follow::check_follow_sets(lhs)?;
         }
 
         Ok(DeclarativeMacro { rules, shift: Shift::new(tt) })
@@ -239,6 +274,7 @@ pub fn parse_macro2(tt: &tt::Subtree) -> Result<DeclarativeMacro, ParseError> {
 
         for Rule { lhs, .. } in &rules {
             validate(lhs)?;
+            follow::check_follow_sets(lhs)?;
         }
 
         Ok(DeclarativeMacro { rules, shift: Shift::new(tt) })
@@ -10,7 +10,7 @@
 ///
 /// ```
 /// macro_rules! an_macro {
-///     ($x:expr + $y:expr) => ($y * $x)
+///     ($x:expr, $y:expr) => ($y * $x)
 /// }
 /// ```
 ///
@@ -52,7 +52,7 @@ fn parse(tt: &tt::Subtree, mode: Mode) -> Result<MetaTemplate, ParseError> {
 pub(crate) enum Op {
     Var { name: SmolStr, kind: Option<SmolStr>, id: tt::TokenId },
     Ignore { name: SmolStr, id: tt::TokenId },
-    Index { depth: u32 },
+    Index { depth: u32, id: tt::TokenId },
     Repeat { tokens: MetaTemplate, kind: RepeatKind, separator: Option<Separator> },
     Leaf(tt::Leaf),
     Subtree { tokens: MetaTemplate, delimiter: Option<tt::Delimiter> },
@@ -248,7 +248,7 @@ fn parse_metavar_expr(src: &mut TtIter<'_>) -> Result<Op, ()> {
         }
         "index" => {
             let depth = if args.len() == 0 { 0 } else { args.expect_u32_literal()? };
-            Op::Index { depth }
+            Op::Index { depth, id: func.id }
         }
         _ => return Err(()),
     };